@@ -0,0 +1,73 @@
+//! Benchmarks for Algorithm A on a large cohort
+//!
+//! Compares `calculate_algorithm_a`, which streams over the view with a single
+//! scratch buffer, against the previous path through the detailed result, which
+//! copied the input to a Vec and materialized the weights and residuals. A
+//! counting allocator reports the bytes allocated per call by each path.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use ndarray::{Array1, ArrayView1};
+use pt_cli_rust::estimators::{calculate_algorithm_a, calculate_algorithm_a_detailed, AlgorithmAResult};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+const N: usize = 50_000;
+
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static ALLOCATED_BYTES: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        ALLOCATED_BYTES.fetch_add(layout.size(), Ordering::Relaxed);
+        System.alloc(layout)
+    }
+    
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+/// Previous Algorithm A entry point, kept as the benchmark baseline
+fn legacy_algorithm_a(results: ArrayView1<f64>) -> AlgorithmAResult {
+    calculate_algorithm_a_detailed(results, 1e-6, 100).unwrap().result
+}
+
+/// Number of allocations and bytes allocated by one call of `f`
+fn count_allocations<T>(f: impl FnOnce() -> T) -> (usize, usize) {
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let bytes = ALLOCATED_BYTES.load(Ordering::Relaxed);
+    black_box(f());
+    (
+        ALLOCATIONS.load(Ordering::Relaxed) - allocations,
+        ALLOCATED_BYTES.load(Ordering::Relaxed) - bytes,
+    )
+}
+
+fn bench_algorithm_a(c: &mut Criterion) {
+    let results: Array1<f64> = (0..N).map(|i| 10.0 + ((i * 7919) % 1000) as f64 / 3000.0).collect();
+    
+    let (legacy_count, legacy_bytes) = count_allocations(|| legacy_algorithm_a(results.view()));
+    let (fast_count, fast_bytes) = count_allocations(|| {
+        calculate_algorithm_a(results.view(), 1e-6, 100).unwrap()
+    });
+    println!("algorithm_a_5e4 legacy: {} allocations, {} bytes", legacy_count, legacy_bytes);
+    println!("algorithm_a_5e4 fast:   {} allocations, {} bytes", fast_count, fast_bytes);
+    
+    let mut group = c.benchmark_group("algorithm_a_5e4");
+    group.bench_function("legacy", |b| {
+        b.iter(|| legacy_algorithm_a(black_box(results.view())))
+    });
+    group.bench_function("fast", |b| {
+        b.iter(|| calculate_algorithm_a(black_box(results.view()), 1e-6, 100).unwrap())
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_algorithm_a);
+criterion_main!(benches);
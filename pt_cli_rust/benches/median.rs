@@ -0,0 +1,47 @@
+//! Benchmarks for the median on a large array
+//!
+//! Compares `median`, which finds the middle element by selection, against the
+//! previous implementation, which sorted the whole slice.
+
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, Criterion};
+use pt_cli_rust::utils::{median, median_with_scratch, SplitMix64};
+
+const N: usize = 1_000_000;
+
+/// Previous sort-based median, kept as the benchmark baseline
+fn sort_median(data: &mut [f64]) -> Option<f64> {
+    if data.is_empty() {
+        return None;
+    }
+    
+    data.sort_by(|a, b| a.total_cmp(b));
+    let len = data.len();
+    
+    if len.is_multiple_of(2) {
+        Some((data[len / 2 - 1] + data[len / 2]) / 2.0)
+    } else {
+        Some(data[len / 2])
+    }
+}
+
+fn bench_median(c: &mut Criterion) {
+    let mut rng = SplitMix64::new(1);
+    let data: Vec<f64> = (0..N).map(|_| 10.0 + rng.next_normal()).collect();
+    
+    let mut group = c.benchmark_group("median_1e6");
+    group.bench_function("sort", |b| {
+        b.iter_batched_ref(|| data.clone(), |data| sort_median(black_box(data)), BatchSize::LargeInput)
+    });
+    group.bench_function("select", |b| {
+        b.iter_batched_ref(|| data.clone(), |data| median(black_box(data)), BatchSize::LargeInput)
+    });
+    
+    let mut scratch = Vec::with_capacity(N);
+    group.bench_function("select_with_scratch", |b| {
+        b.iter(|| median_with_scratch(black_box(&data), &mut scratch))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_median);
+criterion_main!(benches);
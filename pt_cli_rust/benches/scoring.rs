@@ -0,0 +1,74 @@
+//! Benchmarks for the scoring fast path
+//!
+//! Compares the ArrayView-based (parallel above the threshold) z/zeta scoring
+//! against the previous implementation, which copied the inputs into Vecs and
+//! collected the scores element by element.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use ndarray::{Array1, ArrayView1};
+use pt_cli_rust::scoring::{calculate_z_prime_scores, calculate_z_scores};
+use pt_cli_rust::utils::validate_floats;
+
+const N: usize = 10_000_000;
+
+/// Previous z-score implementation, kept as the benchmark baseline
+fn legacy_z_scores(results: ArrayView1<f64>, x_pt: f64, sigma_pt: f64) -> Array1<f64> {
+    let data = results.to_vec();
+    validate_floats(&data, "participant results").unwrap();
+    let z_scores: Vec<f64> = data.iter()
+        .map(|&x_i| (x_i - x_pt) / sigma_pt)
+        .collect();
+    Array1::from(z_scores)
+}
+
+/// Previous zeta-score implementation, kept as the benchmark baseline
+fn legacy_z_prime_scores(
+    results: ArrayView1<f64>,
+    u_results: ArrayView1<f64>,
+    x_pt: f64,
+    u_x_pt: f64,
+) -> Array1<f64> {
+    let data = results.to_vec();
+    let uncertainties = u_results.to_vec();
+    validate_floats(&data, "participant results").unwrap();
+    validate_floats(&uncertainties, "participant uncertainties").unwrap();
+    
+    let mut z_prime_scores = Vec::with_capacity(data.len());
+    for (&x_i, &u_i) in data.iter().zip(uncertainties.iter()) {
+        let combined_uncertainty_squared = u_i.powi(2) + u_x_pt.powi(2);
+        assert!(combined_uncertainty_squared > 0.0);
+        z_prime_scores.push((x_i - x_pt) / combined_uncertainty_squared.sqrt());
+    }
+    Array1::from(z_prime_scores)
+}
+
+fn bench_scoring(c: &mut Criterion) {
+    let results: Array1<f64> = (0..N).map(|i| 10.0 + ((i * 7919) % 1000) as f64 / 3000.0).collect();
+    let u_results: Array1<f64> = (0..N).map(|i| 0.01 + (i % 17) as f64 / 1000.0).collect();
+    
+    let mut group = c.benchmark_group("z_scores_1e7");
+    group.sample_size(10);
+    group.bench_function("legacy", |b| {
+        b.iter(|| legacy_z_scores(black_box(results.view()), 10.1, 0.07))
+    });
+    group.bench_function("fast", |b| {
+        b.iter(|| calculate_z_scores(black_box(results.view()), 10.1, 0.07).unwrap())
+    });
+    group.finish();
+    
+    let mut group = c.benchmark_group("z_prime_scores_1e7");
+    group.sample_size(10);
+    group.bench_function("legacy", |b| {
+        b.iter(|| legacy_z_prime_scores(black_box(results.view()), black_box(u_results.view()), 10.1, 0.02))
+    });
+    group.bench_function("fast", |b| {
+        b.iter(|| {
+            calculate_z_prime_scores(black_box(results.view()), black_box(u_results.view()), 10.1, 0.02)
+                .unwrap()
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_scoring);
+criterion_main!(benches);
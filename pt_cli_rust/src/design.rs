@@ -0,0 +1,203 @@
+//! Scheme design module
+//!
+//! This module implements calculations that help scheme designers choose
+//! sigma_pt and the assigned value method before a round is run.
+
+use crate::estimators::{calculate_algorithm_a, AlgorithmAResult};
+use crate::utils::{CalculationError, is_valid_float, normal_quantile};
+use ndarray::ArrayView1;
+
+/// Consensus of each level of a multi-level round and the precision profile across levels
+#[derive(Debug, Clone)]
+pub struct MultilevelAnalysis {
+    /// Algorithm A result of each level, in input order
+    pub levels: Vec<AlgorithmAResult>,
+    /// Robust coefficient of variation s* / |x*| of each level (NaN if x* is zero)
+    pub cv: Vec<f64>,
+    /// Exponent b of the fitted profile s* = a·|x*|^b, or None if it cannot be fitted
+    pub profile_exponent: Option<f64>,
+}
+
+/// Smallest true bias that the z-score scheme detects with the requested power
+/// 
+/// A result is signalled when |z| > z_(1-α/2), i.e. |x - x_pt| > z_(1-α/2)·sigma_pt.
+/// For a participant with bias δ and dispersion sigma_pt, x - x_pt has standard
+/// deviation sqrt(sigma_pt^2 + u(x_pt)^2), so the detectable bias is
+/// 
+/// δ = z_(1-α/2)·sigma_pt + z_power·sqrt(sigma_pt^2 + u(x_pt)^2)
+/// 
+/// The probability of a signal in the opposite tail is neglected.
+/// 
+/// # Arguments
+/// * `sigma_pt` - Standard deviation for proficiency assessment
+/// * `u_x_pt` - Standard uncertainty of the assigned value
+/// * `power` - Required probability of detecting the bias, in (0, 1)
+/// * `alpha` - Two-sided false signal probability for an unbiased participant, in (0, 1)
+/// 
+/// # Returns
+/// * `Ok(f64)` - The minimum detectable bias in measurement units
+/// * `Err(CalculationError)` - If sigma_pt is not positive, u(x_pt) is negative or a probability is invalid
+pub fn minimum_detectable_bias(
+    sigma_pt: f64,
+    u_x_pt: f64,
+    power: f64,
+    alpha: f64,
+) -> Result<f64, CalculationError> {
+    if !is_valid_float(sigma_pt) || sigma_pt <= 0.0 {
+        return Err(CalculationError::InvalidInput {
+            message: format!("Invalid or non-positive sigma_pt: {}", sigma_pt),
+        });
+    }
+
+    if !is_valid_float(u_x_pt) || u_x_pt < 0.0 {
+        return Err(CalculationError::InvalidInput {
+            message: format!("Invalid assigned value uncertainty: {}", u_x_pt),
+        });
+    }
+
+    if !(power > 0.0 && power < 1.0) {
+        return Err(CalculationError::InvalidInput {
+            message: format!("Power must be in (0, 1), got {}", power),
+        });
+    }
+
+    if !(alpha > 0.0 && alpha < 1.0) {
+        return Err(CalculationError::InvalidInput {
+            message: format!("Significance level alpha must be in (0, 1), got {}", alpha),
+        });
+    }
+
+    let z_alpha = normal_quantile(1.0 - alpha / 2.0)?;
+    let z_power = normal_quantile(power)?;
+
+    Ok(z_alpha * sigma_pt + z_power * sigma_pt.hypot(u_x_pt))
+}
+
+/// Run Algorithm A on each material of a multi-level round and profile the precision
+/// 
+/// Rounds that distribute several materials of the same analyte at different
+/// levels are assessed per level. The profile exponent b is the least-squares
+/// slope of ln s* on ln |x*| across levels: b ≈ 1 means a constant CV (sigma_pt
+/// should be set relative to the level), b ≈ 0 a constant standard deviation.
+/// Levels with x* = 0 or a clamped s* are left out of the fit, which needs at
+/// least two levels with different x*.
+/// 
+/// # Arguments
+/// * `levels` - Participant results of each level
+/// * `tolerance` - Convergence tolerance for Algorithm A
+/// * `max_iterations` - Maximum number of Algorithm A iterations
+/// 
+/// # Returns
+/// * `Ok(MultilevelAnalysis)` - Per-level results, CVs and the profile exponent
+/// * `Err(CalculationError)` - If fewer than 2 levels are given or Algorithm A fails on any level
+pub fn analyze_multilevel(
+    levels: Vec<ArrayView1<f64>>,
+    tolerance: f64,
+    max_iterations: usize,
+) -> Result<MultilevelAnalysis, CalculationError> {
+    if levels.len() < 2 {
+        return Err(CalculationError::InsufficientData { required: 2, actual: levels.len() });
+    }
+
+    let levels = levels.into_iter()
+        .map(|results| calculate_algorithm_a(results, tolerance, max_iterations))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let cv = levels.iter()
+        .map(|level| if level.x_pt == 0.0 { f64::NAN } else { level.s_star / level.x_pt.abs() })
+        .collect();
+
+    let points: Vec<(f64, f64)> = levels.iter()
+        .filter(|level| level.x_pt != 0.0 && !level.scale_clamped)
+        .map(|level| (level.x_pt.abs().ln(), level.s_star.ln()))
+        .collect();
+    let n = points.len() as f64;
+    let mean_x = points.iter().map(|&(x, _)| x).sum::<f64>() / n;
+    let mean_y = points.iter().map(|&(_, y)| y).sum::<f64>() / n;
+    let sxx: f64 = points.iter().map(|&(x, _)| (x - mean_x).powi(2)).sum();
+    let sxy: f64 = points.iter().map(|&(x, y)| (x - mean_x) * (y - mean_y)).sum();
+    let profile_exponent = (points.len() >= 2 && sxx > 0.0).then(|| sxy / sxx);
+
+    Ok(MultilevelAnalysis { levels, cv, profile_exponent })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::constants::{DEFAULT_MAX_ITERATIONS, DEFAULT_TOLERANCE};
+    use approx::assert_abs_diff_eq;
+    use ndarray::{array, Array1};
+
+    #[test]
+    fn test_minimum_detectable_bias() {
+        // At 50 % power the bias sits exactly on the action limit
+        assert_abs_diff_eq!(minimum_detectable_bias(1.0, 0.0, 0.5, 0.05).unwrap(), 1.959963985, epsilon = 1e-8);
+
+        // 1.96 + 0.8416 for 80 % power, scaling with sigma_pt
+        assert_abs_diff_eq!(minimum_detectable_bias(2.0, 0.0, 0.8, 0.05).unwrap(), 2.0 * 2.801585218, epsilon = 1e-7);
+
+        // u(x_pt) makes small biases harder to detect
+        let with_u = minimum_detectable_bias(1.0, 0.3, 0.8, 0.05).unwrap();
+        assert_abs_diff_eq!(with_u, 1.959963985 + 0.841621234 * 1.09_f64.sqrt(), epsilon = 1e-7);
+    }
+
+    #[test]
+    fn test_minimum_detectable_bias_invalid() {
+        assert!(minimum_detectable_bias(0.0, 0.1, 0.8, 0.05).is_err());
+        assert!(minimum_detectable_bias(1.0, -0.1, 0.8, 0.05).is_err());
+        assert!(minimum_detectable_bias(1.0, 0.1, 1.0, 0.05).is_err());
+        assert!(minimum_detectable_bias(1.0, 0.1, 0.8, 0.0).is_err());
+    }
+
+    #[test]
+    fn test_analyze_multilevel_constant_cv() {
+        let base = array![0.98, 1.0, 1.02, 0.99, 1.01, 0.97, 1.03, 1.005, 0.995, 1.015];
+        let scaled: Vec<Array1<f64>> = [1.0, 10.0, 100.0].iter().map(|&k| &base * k).collect();
+        let analysis = analyze_multilevel(
+            scaled.iter().map(|level| level.view()).collect(),
+            1e-9,
+            DEFAULT_MAX_ITERATIONS,
+        ).unwrap();
+
+        assert_eq!(analysis.levels.len(), 3);
+        assert_abs_diff_eq!(analysis.levels[2].x_pt, 100.0 * analysis.levels[0].x_pt, epsilon = 1e-6);
+        assert_abs_diff_eq!(analysis.cv[0], analysis.cv[1], epsilon = 1e-8);
+        assert_abs_diff_eq!(analysis.cv[0], analysis.cv[2], epsilon = 1e-8);
+        assert_abs_diff_eq!(analysis.profile_exponent.unwrap(), 1.0, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn test_analyze_multilevel_constant_sd() {
+        let base = array![-0.2, 0.0, 0.2, -0.1, 0.1, -0.3, 0.3, 0.05, -0.05, 0.15];
+        let shifted: Vec<Array1<f64>> = [5.0, 50.0, 500.0].iter().map(|&level| &base + level).collect();
+        let analysis = analyze_multilevel(
+            shifted.iter().map(|level| level.view()).collect(),
+            1e-9,
+            DEFAULT_MAX_ITERATIONS,
+        ).unwrap();
+
+        assert_abs_diff_eq!(analysis.profile_exponent.unwrap(), 0.0, epsilon = 1e-6);
+        assert!(analysis.cv[0] > analysis.cv[1] && analysis.cv[1] > analysis.cv[2]);
+    }
+
+    #[test]
+    fn test_analyze_multilevel_without_profile() {
+        // Identical levels give no spread of x* to fit against
+        let level = array![9.8, 10.0, 10.2, 9.9, 10.1];
+        let analysis = analyze_multilevel(vec![level.view(), level.view()], DEFAULT_TOLERANCE, DEFAULT_MAX_ITERATIONS).unwrap();
+        assert_eq!(analysis.profile_exponent, None);
+
+        // A level with identical results has a clamped s* and is left out of the fit
+        let identical = array![5.0, 5.0, 5.0, 5.0, 5.0];
+        let analysis = analyze_multilevel(vec![identical.view(), level.view()], DEFAULT_TOLERANCE, DEFAULT_MAX_ITERATIONS).unwrap();
+        assert!(analysis.levels[0].scale_clamped);
+        assert_eq!(analysis.profile_exponent, None);
+    }
+
+    #[test]
+    fn test_analyze_multilevel_invalid() {
+        let level = array![9.8, 10.0, 10.2, 9.9, 10.1];
+        assert!(analyze_multilevel(vec![level.view()], DEFAULT_TOLERANCE, DEFAULT_MAX_ITERATIONS).is_err());
+        assert!(analyze_multilevel(vec![level.view(), array![1.0, 2.0].view()], DEFAULT_TOLERANCE, DEFAULT_MAX_ITERATIONS).is_err());
+    }
+}
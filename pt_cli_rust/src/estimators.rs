@@ -3,16 +3,34 @@
 //! This module implements the core logic for calculating the assigned value (x_pt)
 //! according to the methods specified in ISO 13528:2022.
 
-use crate::utils::{CalculationError, constants::*, median, mad, huber_psi, validate_floats};
+use crate::utils::{CalculationError, CalculationWarning, constants::*, median, median_of, median_with_scratch, median_and_mad_in_place, mad_with_scratch, huber_weight, bisquare_weight, hampel_psi, validate_floats,
+                   validate_array_dimensions, validate_mad_consistency, bootstrap_std_dev};
+use crate::scoring::standardized_residuals;
 use ndarray::{Array1, ArrayView1};
+use serde::Serialize;
+use std::str::FromStr;
 
 /// Result of Algorithm A calculation
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct AlgorithmAResult {
     pub x_pt: f64,
     pub s_star: f64,
     pub participants_used: usize,
     pub iterations: usize,
+    /// Whether the converged s* was clamped to the minimum scale of 1e-10
+    pub scale_clamped: bool,
+    /// Non-fatal conditions noticed during the calculation
+    pub warnings: Vec<CalculationWarning>,
+}
+
+/// Detailed result of Algorithm A calculation including per-participant diagnostics
+#[derive(Debug, Clone)]
+pub struct AlgorithmADetailedResult {
+    pub result: AlgorithmAResult,
+    /// Final Huber weight of each participant (1.0 = not down-weighted)
+    pub weights: Array1<f64>,
+    /// Robust standardized residuals (x_i - x*) / s*
+    pub standardized_residuals: Array1<f64>,
 }
 
 /// Calculate assigned value using Algorithm A (robust statistics)
@@ -28,13 +46,163 @@ pub struct AlgorithmAResult {
 /// # Returns
 /// * `Ok(AlgorithmAResult)` - Result containing x_pt, s*, participants used, and iterations
 /// * `Err(CalculationError)` - If calculation fails
+/// 
+/// Works directly on the view with a single scratch buffer for the initial
+/// median and MAD, so large cohorts are not copied or materialized per
+/// iteration. Use `calculate_algorithm_a_detailed` when per-participant
+/// weights and residuals are needed.
 pub fn calculate_algorithm_a(
     results: ArrayView1<f64>,
     tolerance: f64,
     max_iterations: usize,
 ) -> Result<AlgorithmAResult, CalculationError> {
-    let data = results.to_vec();
+    calculate_algorithm_a_with_mad_consistency(results, tolerance, max_iterations, MAD_TO_SIGMA)
+}
+
+/// Calculate Algorithm A with a custom MAD consistency constant
+/// 
+/// Same as `calculate_algorithm_a`, with the initial s* taken as the MAD scaled
+/// by `mad_consistency` instead of MAD_TO_SIGMA (e.g. for a different
+/// reference distribution).
+/// 
+/// # Arguments
+/// * `results` - Array view of participant results
+/// * `tolerance` - Convergence tolerance for iteration
+/// * `max_iterations` - Maximum number of iterations
+/// * `mad_consistency` - Factor converting the MAD to the MADe (positive and finite)
+///
+/// # Returns
+/// * `Ok(AlgorithmAResult)` - Result containing x_pt, s*, participants used, and iterations
+/// * `Err(CalculationError)` - If calculation fails or `mad_consistency` is invalid
+pub fn calculate_algorithm_a_with_mad_consistency(
+    results: ArrayView1<f64>,
+    tolerance: f64,
+    max_iterations: usize,
+    mad_consistency: f64,
+) -> Result<AlgorithmAResult, CalculationError> {
+    let mut scratch = Vec::new();
+    let state = iterate_algorithm_a(results, tolerance, max_iterations, mad_consistency, &mut scratch)?;
+    
+    if !state.converged {
+        return Err(CalculationError::NonConvergence { max_iterations });
+    }
+    
+    let warnings = algorithm_a_warnings(results, &state, max_iterations);
+    let AlgorithmAIteration { x_star, s_star, iterations, scale_clamped, .. } = state;
+    
+    // Count participants used (those not heavily down-weighted)
+    let participants_used = results.iter()
+        .filter(|&&value| huber_weight((value - x_star) / s_star, HUBER_C) > 0.1)
+        .count();
+    
+    Ok(AlgorithmAResult {
+        x_pt: x_star,
+        s_star,
+        participants_used,
+        iterations,
+        scale_clamped,
+        warnings,
+    })
+}
+
+/// Calculate Algorithm A and return the per-participant weights and standardized residuals
+/// 
+/// Same calculation as `calculate_algorithm_a`, additionally returning the final
+/// weights and the residuals (x_i - x*) / s* computed with the converged s*, so
+/// diagnostic plots use exactly the same robust scale as the assigned value.
+/// 
+/// # Arguments
+/// * `results` - Array view of participant results
+/// * `tolerance` - Convergence tolerance for iteration
+/// * `max_iterations` - Maximum number of iterations
+///
+/// # Returns
+/// * `Ok(AlgorithmADetailedResult)` - Algorithm A result with weights and residuals
+/// * `Err(CalculationError)` - If calculation fails
+pub fn calculate_algorithm_a_detailed(
+    results: ArrayView1<f64>,
+    tolerance: f64,
+    max_iterations: usize,
+) -> Result<AlgorithmADetailedResult, CalculationError> {
+    calculate_algorithm_a_detailed_with_mad_consistency(results, tolerance, max_iterations, MAD_TO_SIGMA)
+}
+
+/// Calculate detailed Algorithm A with a custom MAD consistency constant
+/// 
+/// Same as `calculate_algorithm_a_detailed`, with the initial s* taken as the
+/// MAD scaled by `mad_consistency`.
+/// 
+/// # Returns
+/// * `Ok(AlgorithmADetailedResult)` - Algorithm A result with weights and residuals
+/// * `Err(CalculationError)` - If calculation fails or `mad_consistency` is invalid
+pub fn calculate_algorithm_a_detailed_with_mad_consistency(
+    results: ArrayView1<f64>,
+    tolerance: f64,
+    max_iterations: usize,
+    mad_consistency: f64,
+) -> Result<AlgorithmADetailedResult, CalculationError> {
+    let mut scratch = Vec::new();
+    let state = iterate_algorithm_a(results, tolerance, max_iterations, mad_consistency, &mut scratch)?;
+    
+    if !state.converged {
+        return Err(CalculationError::NonConvergence { max_iterations });
+    }
+    
+    let warnings = algorithm_a_warnings(results, &state, max_iterations);
+    let AlgorithmAIteration { x_star, s_star, iterations, scale_clamped, .. } = state;
     
+    // Final weights at the converged estimates
+    let weights: Array1<f64> = results.mapv(|value| huber_weight((value - x_star) / s_star, HUBER_C));
+    
+    // Count participants used (those not heavily down-weighted)
+    let participants_used = weights.iter().filter(|&&weight| weight > 0.1).count();
+    
+    let standardized_residuals = standardized_residuals(results, x_star, s_star)?;
+    
+    Ok(AlgorithmADetailedResult {
+        result: AlgorithmAResult {
+            x_pt: x_star,
+            s_star,
+            participants_used,
+            iterations,
+            scale_clamped,
+            warnings,
+        },
+        weights,
+        standardized_residuals,
+    })
+}
+
+/// Huber's c parameter for Algorithm A
+pub(crate) const HUBER_C: f64 = 1.5;
+
+/// Estimates reached by the Algorithm A iteration
+struct AlgorithmAIteration {
+    x_star: f64,
+    s_star: f64,
+    iterations: usize,
+    /// False if `max_iterations` was reached before convergence
+    converged: bool,
+    scale_clamped: bool,
+    /// Number of results equal to the initial median
+    tied_at_median: usize,
+}
+
+/// Validate the input and run the Algorithm A iteration
+/// 
+/// Stops after `max_iterations` without an error, reporting `converged = false`
+/// with the last estimates, so callers decide how to treat non-convergence.
+/// `scratch` is cleared and reused for the initial median and MAD; the
+/// iterations themselves stream over `data` without allocating. The initial
+/// s* is the MAD scaled by `mad_consistency` (MAD_TO_SIGMA for normal data).
+/// Ties at the median are counted from the deviations left in `scratch`.
+fn iterate_algorithm_a(
+    data: ArrayView1<f64>,
+    tolerance: f64,
+    max_iterations: usize,
+    mad_consistency: f64,
+    scratch: &mut Vec<f64>,
+) -> Result<AlgorithmAIteration, CalculationError> {
     // Validate input
     if data.len() < MIN_PARTICIPANTS_ALGORITHM_A {
         return Err(CalculationError::InsufficientData {
@@ -43,7 +211,7 @@ pub fn calculate_algorithm_a(
         });
     }
     
-    validate_floats(&data, "participant results")?;
+    validate_floats(data, "participant results")?;
     
     if tolerance <= 0.0 || !tolerance.is_finite() {
         return Err(CalculationError::InvalidInput {
@@ -51,27 +219,32 @@ pub fn calculate_algorithm_a(
         });
     }
     
-    // Step 1: Calculate initial estimates
-    let mut working_data = data.clone();
-    let initial_median = median(&mut working_data).unwrap();
-    let initial_mad = mad(&data, initial_median)?;
+    validate_mad_consistency(mad_consistency)?;
+    
+    // Step 1: Calculate initial estimates, leaving `data` untouched
+    scratch.clear();
+    scratch.extend(data.iter());
+    let (initial_median, initial_mad) = median_and_mad_in_place(scratch).unwrap();
+    let tied_at_median = scratch.iter().filter(|&&deviation| deviation == 0.0).count();
     
     // Initial robust standard deviation estimate
-    let mut s_star = initial_mad * MAD_TO_SIGMA;
+    let mut s_star = initial_mad * mad_consistency;
     let mut x_star = initial_median;
     
     // If s* is too small, use a minimal value to avoid division issues
-    if s_star < 1e-10 {
-        s_star = 1e-10;
+    let mut scale_clamped = s_star < MIN_ROBUST_SCALE;
+    if scale_clamped {
+        s_star = MIN_ROBUST_SCALE;
     }
     
     // Algorithm A iteration
     let mut iteration = 0;
-    let c = 1.5; // Huber's c parameter
+    let mut converged = true;
     
     loop {
         if iteration >= max_iterations {
-            return Err(CalculationError::NonConvergence { max_iterations });
+            converged = false;
+            break;
         }
         
         let x_star_old = x_star;
@@ -82,14 +255,8 @@ pub fn calculate_algorithm_a(
         let mut sum_weighted_values = 0.0;
         let mut sum_weighted_squared_residuals = 0.0;
         
-        for &value in &data {
-            let standardized_residual = (value - x_star) / s_star;
-            let psi_val = huber_psi(standardized_residual, c);
-            let weight = if standardized_residual.abs() < 1e-10 {
-                1.0
-            } else {
-                psi_val / standardized_residual
-            };
+        for &value in data.iter() {
+            let weight = huber_weight((value - x_star) / s_star, HUBER_C);
             
             sum_weights += weight;
             sum_weighted_values += weight * value;
@@ -107,8 +274,9 @@ pub fn calculate_algorithm_a(
         s_star = (sum_weighted_squared_residuals / sum_weights).sqrt();
         
         // Ensure s_star doesn't become too small
-        if s_star < 1e-10 {
-            s_star = 1e-10;
+        scale_clamped = s_star < MIN_ROBUST_SCALE;
+        if scale_clamped {
+            s_star = MIN_ROBUST_SCALE;
         }
         
         // Check for convergence
@@ -122,28 +290,448 @@ pub fn calculate_algorithm_a(
         iteration += 1;
     }
     
-    // Count participants used (those not heavily down-weighted)
-    let participants_used = data.iter()
-        .map(|&value| {
-            let standardized_residual = (value - x_star) / s_star;
-            let weight = if standardized_residual.abs() < 1e-10 {
-                1.0
-            } else {
-                let psi_val = huber_psi(standardized_residual, c);
-                psi_val / standardized_residual
-            };
-            if weight > 0.1 { 1 } else { 0 } // Count if weight > 0.1
-        })
-        .sum();
-    
-    Ok(AlgorithmAResult {
-        x_pt: x_star,
+    Ok(AlgorithmAIteration {
+        x_star,
         s_star,
-        participants_used,
         iterations: iteration,
+        converged,
+        scale_clamped,
+        tied_at_median,
+    })
+}
+
+/// Collect the non-fatal warnings for a converged Algorithm A iteration
+/// 
+/// Flags a floored s*, a large share of results tied at the median (which
+/// collapses the initial MAD), convergence that used most of `max_iterations`,
+/// and an unusually large share of results down-weighted at the converged
+/// estimates.
+fn algorithm_a_warnings(
+    data: ArrayView1<f64>,
+    state: &AlgorithmAIteration,
+    max_iterations: usize,
+) -> Vec<CalculationWarning> {
+    let total = data.len();
+    let mut warnings = Vec::new();
+    
+    if state.scale_clamped {
+        warnings.push(CalculationWarning::ScaleFloored { minimum: MIN_ROBUST_SCALE });
+    }
+    
+    let tied = state.tied_at_median;
+    if tied > 1 && tied as f64 > HEAVY_TIES_FRACTION * total as f64 {
+        warnings.push(CalculationWarning::HeavyTies { tied, total });
+    }
+    
+    if state.iterations as f64 > SLOW_CONVERGENCE_FRACTION * max_iterations as f64 {
+        warnings.push(CalculationWarning::SlowConvergence {
+            iterations: state.iterations,
+            max_iterations,
+        });
+    }
+    
+    let down_weighted = data.iter()
+        .filter(|&&value| huber_weight((value - state.x_star) / state.s_star, HUBER_C) < 1.0)
+        .count();
+    if down_weighted as f64 > MANY_DOWN_WEIGHTED_FRACTION * total as f64 {
+        warnings.push(CalculationWarning::ManyDownWeighted { down_weighted, total });
+    }
+    
+    warnings
+}
+
+/// Robustness diagnostics of a converged Algorithm A result
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct BreakdownAnalysis {
+    /// Number of results down-weighted (winsorized) at convergence
+    pub winsorized: usize,
+    /// Fraction of results down-weighted at convergence
+    pub winsorized_fraction: f64,
+    /// Largest change in x* when a single result is moved far from the others
+    pub sensitivity: f64,
+    /// Index of the result whose perturbation changes x* the most
+    pub most_influential: usize,
+}
+
+/// Analyse how close a converged Algorithm A result is to breaking down
+/// 
+/// Reports the fraction of results winsorized (final weight below 1, i.e.
+/// |x_i - x*| > 1.5·s*) and an empirical sensitivity: each result in turn is
+/// moved to x* + 10·s*, well beyond the Huber cut-off, and Algorithm A is
+/// recomputed. A large winsorized fraction or a sensitivity that is a large
+/// share of s* indicates that few additional bad results could corrupt x*.
+/// 
+/// # Arguments
+/// * `results` - Array view of the participant results used for `detailed`
+/// * `detailed` - Converged Algorithm A result with weights
+/// * `tolerance` - Convergence tolerance for the perturbed recalculations
+/// * `max_iterations` - Maximum number of iterations for the perturbed recalculations
+/// 
+/// # Returns
+/// * `Ok(BreakdownAnalysis)` - Winsorized fraction and empirical sensitivity
+/// * `Err(CalculationError)` - If the inputs do not match or a recalculation fails
+pub fn breakdown_analysis(
+    results: ArrayView1<f64>,
+    detailed: &AlgorithmADetailedResult,
+    tolerance: f64,
+    max_iterations: usize,
+) -> Result<BreakdownAnalysis, CalculationError> {
+    validate_array_dimensions(results.len(), detailed.weights.len(), "results", "weights")?;
+    
+    let x_star = detailed.result.x_pt;
+    let perturbed_value = x_star + 10.0 * detailed.result.s_star;
+    let winsorized = detailed.weights.iter().filter(|&&weight| weight < 1.0).count();
+    
+    let mut sensitivity = 0.0;
+    let mut most_influential = 0;
+    let mut perturbed = results.to_owned();
+    
+    for i in 0..results.len() {
+        perturbed[i] = perturbed_value;
+        let shifted = calculate_algorithm_a(perturbed.view(), tolerance, max_iterations)?;
+        perturbed[i] = results[i];
+        
+        let change = (shifted.x_pt - x_star).abs();
+        if change > sensitivity {
+            sensitivity = change;
+            most_influential = i;
+        }
+    }
+    
+    Ok(BreakdownAnalysis {
+        winsorized,
+        winsorized_fraction: winsorized as f64 / results.len() as f64,
+        sensitivity,
+        most_influential,
+    })
+}
+
+/// Robust estimator of location and scale selectable at runtime
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RobustMethod {
+    /// ISO 13528 Algorithm A (Huber M-estimator of location and scale)
+    AlgorithmA,
+    /// Hampel redescending M-estimator of location with knots 1.5, 3.0 and 4.5
+    Hampel,
+    /// Tukey biweight M-estimator of location with c = 4.685
+    Biweight,
+    /// Median with the scaled median absolute deviation (MADe)
+    Median,
+}
+
+impl FromStr for RobustMethod {
+    type Err = CalculationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "algorithm_a" => Ok(RobustMethod::AlgorithmA),
+            "hampel" => Ok(RobustMethod::Hampel),
+            "biweight" => Ok(RobustMethod::Biweight),
+            "median" => Ok(RobustMethod::Median),
+            _ => Err(CalculationError::InvalidInput {
+                message: format!(
+                    "Unknown robust method '{}': expected 'algorithm_a', 'hampel', 'biweight' or 'median'",
+                    s
+                ),
+            }),
+        }
+    }
+}
+
+/// Whether a round has enough participants for a robust estimator
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ParticipantAdequacy {
+    /// Enough participants for a reliable robust location and scale
+    Adequate,
+    /// The estimator runs, but its robust scale is unreliable; report with caution
+    Marginal,
+    /// Too few participants; the estimator refuses to run
+    Inadequate,
+}
+
+impl ParticipantAdequacy {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ParticipantAdequacy::Adequate => "adequate",
+            ParticipantAdequacy::Marginal => "marginal",
+            ParticipantAdequacy::Inadequate => "inadequate",
+        }
+    }
+}
+
+/// Assess whether `n` participants are enough for a robust estimator
+/// 
+/// Every method needs MIN_PARTICIPANTS_ALGORITHM_A (5) results to run at all.
+/// Algorithm A and the median are marginal up to 10 participants. The
+/// redescending Hampel and biweight estimators reject distant results
+/// outright, so with few participants the location can jump between
+/// clusters; they stay marginal up to 14.
+/// 
+/// # Arguments
+/// * `n` - Number of participants with valid results
+/// * `method` - Robust estimator that will be used
+/// 
+/// # Returns
+/// * The adequacy category, so callers can warn before computing
+pub fn assess_participant_adequacy(n: usize, method: RobustMethod) -> ParticipantAdequacy {
+    let marginal_up_to = match method {
+        RobustMethod::AlgorithmA | RobustMethod::Median => 10,
+        RobustMethod::Hampel | RobustMethod::Biweight => 14,
+    };
+    
+    if n < MIN_PARTICIPANTS_ALGORITHM_A {
+        ParticipantAdequacy::Inadequate
+    } else if n <= marginal_up_to {
+        ParticipantAdequacy::Marginal
+    } else {
+        ParticipantAdequacy::Adequate
+    }
+}
+
+/// Iteration settings shared by the robust estimators
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RobustConfig {
+    /// Convergence tolerance on the change in location (and scale for Algorithm A)
+    pub tolerance: f64,
+    /// Maximum number of iterations
+    pub max_iterations: usize,
+    /// Factor converting the MAD to a standard deviation (MADe and the initial s*)
+    pub mad_consistency: f64,
+}
+
+impl Default for RobustConfig {
+    fn default() -> Self {
+        RobustConfig {
+            tolerance: DEFAULT_TOLERANCE,
+            max_iterations: DEFAULT_MAX_ITERATIONS,
+            mad_consistency: MAD_TO_SIGMA,
+        }
+    }
+}
+
+/// Uniform result of the robust estimators
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct RobustEstimate {
+    pub location: f64,
+    pub scale: f64,
+    /// Number of iterations performed (0 for the median)
+    pub iterations: usize,
+    /// False if `max_iterations` was reached; location and scale are then the last iterate
+    pub converged: bool,
+}
+
+/// Hampel knots (a, b, c) in units of the scale, as used by the Q/Hampel method
+const HAMPEL_KNOTS: (f64, f64, f64) = (1.5, 3.0, 4.5);
+
+/// Tukey biweight tuning constant (95 % efficiency at the normal distribution)
+const BIWEIGHT_C: f64 = 4.685;
+
+/// IRLS weight psi(u) / u of the Hampel three-part redescending function
+fn hampel_weight(u: f64) -> f64 {
+    let (a, b, c) = HAMPEL_KNOTS;
+    
+    // psi(u) = u on [-a, a], which also covers u = 0
+    if u.abs() <= a {
+        1.0
+    } else {
+        hampel_psi(u, a, b, c) / u
+    }
+}
+
+/// IRLS weight psi(u) / u of the Tukey biweight function
+fn biweight_weight(u: f64) -> f64 {
+    bisquare_weight(u, BIWEIGHT_C)
+}
+
+/// Iterate a location M-estimator at a fixed scale, starting from the median
+fn iterate_location(
+    data: &[f64],
+    initial_location: f64,
+    scale: f64,
+    weight: fn(f64) -> f64,
+    config: &RobustConfig,
+) -> Result<RobustEstimate, CalculationError> {
+    let mut location = initial_location;
+    
+    for iteration in 0..config.max_iterations {
+        let (sum_weights, sum_weighted_values) = data.iter()
+            .fold((0.0, 0.0), |(sum_w, sum_wx), &value| {
+                let w = weight((value - location) / scale);
+                (sum_w + w, sum_wx + w * value)
+            });
+        
+        if sum_weights <= 0.0 {
+            return Err(CalculationError::MathematicalError {
+                message: "Sum of weights is zero or negative".to_string(),
+            });
+        }
+        
+        let previous = location;
+        location = sum_weighted_values / sum_weights;
+        
+        if (location - previous).abs() < config.tolerance {
+            return Ok(RobustEstimate { location, scale, iterations: iteration, converged: true });
+        }
+    }
+    
+    Ok(RobustEstimate {
+        location,
+        scale,
+        iterations: config.max_iterations,
+        converged: false,
     })
 }
 
+/// Variance-stabilizing transform applied before robust estimation
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Transform {
+    /// Natural logarithm, for analytes spanning orders of magnitude
+    Log,
+    /// Square root, for count-like data
+    Sqrt,
+}
+
+impl FromStr for Transform {
+    type Err = CalculationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "log" => Ok(Transform::Log),
+            "sqrt" => Ok(Transform::Sqrt),
+            _ => Err(CalculationError::InvalidInput {
+                message: format!("Unknown transform '{}': expected 'log' or 'sqrt'", s),
+            }),
+        }
+    }
+}
+
+impl Transform {
+    fn apply(self, x: f64) -> f64 {
+        match self {
+            Transform::Log => x.ln(),
+            Transform::Sqrt => x.sqrt(),
+        }
+    }
+    
+    /// Back-transform a location and scale, using the delta method for the scale
+    fn back_transform(self, location: f64, scale: f64) -> (f64, f64) {
+        match self {
+            // d/dy exp(y) = exp(y)
+            Transform::Log => (location.exp(), location.exp() * scale),
+            // d/dy y^2 = 2y
+            Transform::Sqrt => (location * location, 2.0 * location.abs() * scale),
+        }
+    }
+}
+
+/// Calculate a robust location and scale with the selected method
+/// 
+/// Lets callers switch estimator without changing how the result is handled.
+/// Algorithm A estimates location and scale jointly. Hampel and biweight
+/// iterate the location from the median with the scale fixed at the MADe,
+/// which is reported as the scale; the median reports the MADe without
+/// iterating. As in Algorithm A, a MADe below 1e-10 (e.g. mostly identical
+/// results) is clamped to 1e-10. All methods require at least 5 results.
+/// 
+/// With a `transform` the estimator runs on the transformed results; the
+/// location is back-transformed and the scale is converted to the original
+/// scale with the delta method (s·exp(m) for log, 2·m·s for sqrt). All
+/// results must then be positive, and the tolerance applies on the
+/// transformed scale.
+/// 
+/// Unlike `calculate_algorithm_a`, reaching `max_iterations` is not an error:
+/// the last iterate is returned with `converged = false`.
+/// 
+/// # Arguments
+/// * `results` - Array view of participant results
+/// * `method` - Robust estimator to use
+/// * `config` - Convergence tolerance and maximum number of iterations
+/// * `transform` - Optional transform applied before estimation
+/// 
+/// # Returns
+/// * `Ok(RobustEstimate)` - Location, scale, iterations and convergence flag on the original scale
+/// * `Err(CalculationError)` - If the input or configuration is invalid
+pub fn calculate_robust(
+    results: ArrayView1<f64>,
+    method: RobustMethod,
+    config: &RobustConfig,
+    transform: Option<Transform>,
+) -> Result<RobustEstimate, CalculationError> {
+    let Some(transform) = transform else {
+        return estimate_robust(results, method, config);
+    };
+    
+    validate_floats(results, "participant results")?;
+    
+    if let Some((i, &x_i)) = results.iter().enumerate().find(|(_, &x_i)| x_i <= 0.0) {
+        return Err(CalculationError::InvalidInput {
+            message: format!(
+                "The {:?} transform requires positive results, got {} at index {}",
+                transform, x_i, i
+            ),
+        });
+    }
+    
+    let transformed = results.mapv(|x_i| transform.apply(x_i));
+    let estimate = estimate_robust(transformed.view(), method, config)?;
+    let (location, scale) = transform.back_transform(estimate.location, estimate.scale);
+    
+    Ok(RobustEstimate { location, scale, ..estimate })
+}
+
+/// Run the selected robust estimator on untransformed results
+fn estimate_robust(
+    results: ArrayView1<f64>,
+    method: RobustMethod,
+    config: &RobustConfig,
+) -> Result<RobustEstimate, CalculationError> {
+    if method == RobustMethod::AlgorithmA {
+        let state = iterate_algorithm_a(
+            results,
+            config.tolerance,
+            config.max_iterations,
+            config.mad_consistency,
+            &mut Vec::new(),
+        )?;
+        return Ok(RobustEstimate {
+            location: state.x_star,
+            scale: state.s_star,
+            iterations: state.iterations,
+            converged: state.converged,
+        });
+    }
+    
+    let data = results.to_vec();
+    
+    if data.len() < MIN_PARTICIPANTS_ALGORITHM_A {
+        return Err(CalculationError::InsufficientData {
+            required: MIN_PARTICIPANTS_ALGORITHM_A,
+            actual: data.len(),
+        });
+    }
+    
+    validate_floats(&data, "participant results")?;
+    
+    if config.tolerance <= 0.0 || !config.tolerance.is_finite() {
+        return Err(CalculationError::InvalidInput {
+            message: format!("Invalid tolerance: {}", config.tolerance),
+        });
+    }
+    
+    validate_mad_consistency(config.mad_consistency)?;
+    
+    let mut scratch = Vec::with_capacity(data.len());
+    let location = median_with_scratch(&data, &mut scratch).unwrap();
+    let scale = (mad_with_scratch(&data, location, &mut scratch)? * config.mad_consistency).max(1e-10);
+    
+    match method {
+        RobustMethod::Hampel => iterate_location(&data, location, scale, hampel_weight, config),
+        RobustMethod::Biweight => iterate_location(&data, location, scale, biweight_weight, config),
+        _ => Ok(RobustEstimate { location, scale, iterations: 0, converged: true }),
+    }
+}
+
 /// Calculate assigned value from Certified Reference Material (CRM)
 /// 
 /// # Arguments
@@ -195,10 +783,239 @@ pub fn calculate_from_expert_consensus(expert_value: f64) -> Result<f64, Calcula
     Ok(expert_value)
 }
 
+/// Calculate assigned value from a reference laboratory measurement
+/// 
+/// The uncertainty combines the reference laboratory's measurement uncertainty
+/// with the uncertainty of transferring the value to the proficiency test items:
+/// u(x_pt) = sqrt(u_measurement^2 + u_transfer^2).
+/// 
+/// # Arguments
+/// * `value` - The value measured by the reference laboratory
+/// * `u_measurement` - Standard uncertainty of the reference measurement
+/// * `u_transfer` - Standard uncertainty of the transfer to the test items
+/// 
+/// # Returns
+/// * `Ok((f64, f64))` - The value as x_pt and its combined standard uncertainty
+/// * `Err(CalculationError)` - If any input is invalid
+pub fn calculate_from_reference_lab(
+    value: f64,
+    u_measurement: f64,
+    u_transfer: f64,
+) -> Result<(f64, f64), CalculationError> {
+    if !value.is_finite() {
+        return Err(CalculationError::InvalidInput {
+            message: format!("Invalid reference laboratory value: {}", value),
+        });
+    }
+    
+    if !u_measurement.is_finite() || u_measurement < 0.0 {
+        return Err(CalculationError::InvalidInput {
+            message: format!("Invalid reference measurement uncertainty: {}", u_measurement),
+        });
+    }
+    
+    if !u_transfer.is_finite() || u_transfer < 0.0 {
+        return Err(CalculationError::InvalidInput {
+            message: format!("Invalid transfer uncertainty: {}", u_transfer),
+        });
+    }
+    
+    Ok((value, u_measurement.hypot(u_transfer)))
+}
+
+/// Calculate assigned value and uncertainty from a gravimetric preparation
+/// 
+/// The mass fraction is w = Σ(m_i · p_i) / M, where m_i are the masses of the
+/// components, p_i their purities (as mass fractions) and M the total mass.
+/// Its standard uncertainty follows from first-order propagation:
+/// u(w)^2 = Σ[(p_i / M)^2 · u(m_i)^2 + (m_i / M)^2 · u(p_i)^2] + (w / M)^2 · u(M)^2
+/// 
+/// # Arguments
+/// * `masses` - Masses of the analyte-bearing components
+/// * `u_masses` - Standard uncertainties of the masses
+/// * `purities` - Purities of the components as mass fractions (0 to 1)
+/// * `u_purities` - Standard uncertainties of the purities
+/// * `total_mass` - Total mass of the preparation
+/// * `u_total` - Standard uncertainty of the total mass
+/// 
+/// # Returns
+/// * `Ok((f64, f64))` - The mass fraction as x_pt and its standard uncertainty
+/// * `Err(CalculationError)` - If the inputs are invalid or have different lengths
+pub fn calculate_from_gravimetric(
+    masses: ArrayView1<f64>,
+    u_masses: ArrayView1<f64>,
+    purities: ArrayView1<f64>,
+    u_purities: ArrayView1<f64>,
+    total_mass: f64,
+    u_total: f64,
+) -> Result<(f64, f64), CalculationError> {
+    if masses.is_empty() {
+        return Err(CalculationError::InsufficientData {
+            required: 1,
+            actual: 0,
+        });
+    }
+    
+    validate_array_dimensions(masses.len(), u_masses.len(), "masses", "u_masses")?;
+    validate_array_dimensions(masses.len(), purities.len(), "masses", "purities")?;
+    validate_array_dimensions(masses.len(), u_purities.len(), "masses", "u_purities")?;
+    
+    for (values, name) in [
+        (masses.view(), "masses"),
+        (u_masses.view(), "mass uncertainties"),
+        (purities.view(), "purities"),
+        (u_purities.view(), "purity uncertainties"),
+    ] {
+        validate_floats(values, name)?;
+        if let Some((i, &value)) = values.iter().enumerate().find(|(_, &value)| value < 0.0) {
+            return Err(CalculationError::InvalidInput {
+                message: format!("{} contains negative value at index {}: {}", name, i, value),
+            });
+        }
+    }
+    
+    if let Some((i, &purity)) = purities.iter().enumerate().find(|(_, &purity)| purity > 1.0) {
+        return Err(CalculationError::InvalidInput {
+            message: format!("Purity must be a mass fraction not exceeding 1, got {} at index {}", purity, i),
+        });
+    }
+    
+    if !total_mass.is_finite() || total_mass <= 0.0 {
+        return Err(CalculationError::InvalidInput {
+            message: format!("Invalid total mass: {}", total_mass),
+        });
+    }
+    
+    if !u_total.is_finite() || u_total < 0.0 {
+        return Err(CalculationError::InvalidInput {
+            message: format!("Invalid total mass uncertainty: {}", u_total),
+        });
+    }
+    
+    let analyte_mass: f64 = masses.iter().zip(purities.iter()).map(|(&m, &p)| m * p).sum();
+    let mass_fraction = analyte_mass / total_mass;
+    
+    let component_variance: f64 = masses.iter()
+        .zip(u_masses.iter())
+        .zip(purities.iter().zip(u_purities.iter()))
+        .map(|((&m, &u_m), (&p, &u_p))| (p * u_m).powi(2) + (m * u_p).powi(2))
+        .sum();
+    let variance = component_variance / total_mass.powi(2)
+        + (mass_fraction * u_total / total_mass).powi(2);
+    
+    Ok((mass_fraction, variance.sqrt()))
+}
+
+/// Calculate the consensus median as assigned value with a bootstrap uncertainty
+/// 
+/// Draws `n_resamples` samples of the same size with replacement and returns
+/// the sample standard deviation of their medians as u(x_pt). The same seed
+/// always gives the same result.
+/// 
+/// # Arguments
+/// * `results` - Array view of participant results
+/// * `n_resamples` - Number of bootstrap resamples (at least 2)
+/// * `seed` - Seed of the pseudo-random number generator
+/// 
+/// # Returns
+/// * `Ok((f64, f64))` - The median as x_pt and its bootstrap standard uncertainty
+/// * `Err(CalculationError)` - If the input is invalid or has too few participants
+pub fn calculate_median_consensus(
+    results: ArrayView1<f64>,
+    n_resamples: usize,
+    seed: u64,
+) -> Result<(f64, f64), CalculationError> {
+    validate_floats(results, "participant results")?;
+    
+    if results.len() < MIN_PARTICIPANTS_ALGORITHM_A {
+        return Err(CalculationError::InsufficientData {
+            required: MIN_PARTICIPANTS_ALGORITHM_A,
+            actual: results.len(),
+        });
+    }
+    
+    let values = results.to_vec();
+    let median_failed = || CalculationError::InternalError {
+        message: "Failed to calculate median of participant results".to_string(),
+    };
+    let x_pt = median_of(&values).ok_or_else(median_failed)?;
+    let u_x_pt = bootstrap_std_dev(&values, n_resamples, seed, |resample| {
+        median(resample).ok_or_else(median_failed)
+    })?;
+    
+    Ok((x_pt, u_x_pt))
+}
+
+/// Estimate the mode of skewed data with the half-range mode (HRM)
+/// 
+/// Starting from the sorted data, the window of width half the current range
+/// that holds the most values is kept, and this repeats until at most two
+/// values remain, whose mean is the mode. Windows tied for the most values are
+/// merged, and when the merged window cannot shrink the set further (evenly
+/// spread values) the mean of the set is returned, which keeps the estimate
+/// deterministic and symmetric under reflection. It needs only sorting and
+/// linear scans, so it is a fast alternative to a kernel density mode.
+/// 
+/// # Arguments
+/// * `data` - Values whose mode is estimated
+/// 
+/// # Returns
+/// * `Ok(f64)` - The half-range mode
+/// * `Err(CalculationError)` - If there are fewer than 2 values or a value is not finite
+pub fn half_range_mode(data: &[f64]) -> Result<f64, CalculationError> {
+    if data.len() < 2 {
+        return Err(CalculationError::InsufficientData {
+            required: 2,
+            actual: data.len(),
+        });
+    }
+    
+    validate_floats(data, "data")?;
+    
+    let mut sorted = data.to_vec();
+    sorted.sort_by(|a, b| a.total_cmp(b));
+    let mut window: &[f64] = &sorted;
+    
+    while window.len() > 2 {
+        let half_range = (window[window.len() - 1] - window[0]) / 2.0;
+        if half_range == 0.0 {
+            return Ok(window[0]);
+        }
+        
+        // Densest windows [x_i, x_i + half_range]; ties extend the kept span
+        let mut best_count = 0;
+        let (mut first, mut last) = (0, 0);
+        let mut j = 0;
+        for i in 0..window.len() {
+            j = j.max(i);
+            while j + 1 < window.len() && window[j + 1] <= window[i] + half_range {
+                j += 1;
+            }
+            
+            let count = j - i + 1;
+            if count > best_count {
+                best_count = count;
+                first = i;
+                last = j;
+            } else if count == best_count {
+                last = j;
+            }
+        }
+        
+        if first == 0 && last == window.len() - 1 {
+            break;
+        }
+        window = &window[first..=last];
+    }
+    
+    Ok(window.iter().sum::<f64>() / window.len() as f64)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use approx::assert_abs_diff_eq;
+    use crate::utils::SplitMix64;
     use ndarray::array;
 
     #[test]
@@ -228,6 +1045,142 @@ mod tests {
         assert!(result.participants_used <= 5); // May down-weight the outlier
     }
 
+    #[test]
+    fn test_algorithm_a_detailed_matches_basic() {
+        let data = array![1.0, 2.0, 3.0, 4.0, 100.0];
+        let basic = calculate_algorithm_a(data.view(), 1e-6, 100).unwrap();
+        let detailed = calculate_algorithm_a_detailed(data.view(), 1e-6, 100).unwrap();
+        
+        assert_eq!(detailed.result.x_pt, basic.x_pt);
+        assert_eq!(detailed.result.s_star, basic.s_star);
+        assert_eq!(detailed.weights.len(), 5);
+        assert_eq!(detailed.standardized_residuals.len(), 5);
+        
+        // The outlier is the most down-weighted participant
+        assert!(detailed.weights[4] < detailed.weights[0]);
+        assert_abs_diff_eq!(
+            detailed.standardized_residuals[4],
+            (100.0 - basic.x_pt) / basic.s_star,
+            epsilon = 1e-10
+        );
+    }
+
+    #[test]
+    fn test_algorithm_a_strided_view_matches_contiguous() {
+        // Every other column of an interleaved buffer is a non-contiguous view
+        let interleaved: Array1<f64> = (0..2000)
+            .map(|i| if i % 2 == 0 { 10.0 + ((i * 7919) % 101) as f64 / 50.0 } else { f64::NAN })
+            .collect();
+        let strided = interleaved.slice(ndarray::s![..;2]);
+        let contiguous = strided.to_owned();
+        
+        let streamed = calculate_algorithm_a(strided, 1e-6, 100).unwrap();
+        let reference = calculate_algorithm_a_detailed(contiguous.view(), 1e-6, 100).unwrap().result;
+        
+        assert_eq!(streamed.x_pt, reference.x_pt);
+        assert_eq!(streamed.s_star, reference.s_star);
+        assert_eq!(streamed.participants_used, reference.participants_used);
+        assert_eq!(streamed.iterations, reference.iterations);
+        assert_eq!(streamed.scale_clamped, reference.scale_clamped);
+    }
+
+    #[test]
+    fn test_algorithm_a_scale_clamped() {
+        let identical = array![5.0, 5.0, 5.0, 5.0, 5.0];
+        let result = calculate_algorithm_a(identical.view(), 1e-6, 100).unwrap();
+        assert!(result.scale_clamped);
+        assert_eq!(result.s_star, 1e-10);
+        
+        let data = array![1.0, 2.0, 3.0, 4.0, 5.0];
+        let result = calculate_algorithm_a(data.view(), 1e-6, 100).unwrap();
+        assert!(!result.scale_clamped);
+    }
+
+    #[test]
+    fn test_algorithm_a_result_json_field_names() {
+        let data = array![1.0, 2.0, 3.0, 4.0, 5.0];
+        let result = calculate_algorithm_a(data.view(), 1e-6, 100).unwrap();
+        let json = serde_json::to_value(&result).unwrap();
+        
+        let mut keys: Vec<&String> = json.as_object().unwrap().keys().collect();
+        keys.sort();
+        assert_eq!(
+            keys,
+            vec!["iterations", "participants_used", "s_star", "scale_clamped", "warnings", "x_pt"]
+        );
+        assert_eq!(json["x_pt"], result.x_pt);
+    }
+
+    #[test]
+    fn test_algorithm_a_warnings() {
+        // Identical results floor s* and are all tied
+        let identical = array![5.0, 5.0, 5.0, 5.0, 5.0];
+        let result = calculate_algorithm_a(identical.view(), 1e-6, 100).unwrap();
+        assert_eq!(result.warnings, vec![
+            CalculationWarning::ScaleFloored { minimum: 1e-10 },
+            CalculationWarning::HeavyTies { tied: 5, total: 5 },
+        ]);
+        
+        // Three of ten results tied at the median
+        let tied = array![10.0, 10.0, 10.0, 9.8, 10.2, 9.9, 10.1, 9.7, 10.3, 10.4];
+        let result = calculate_algorithm_a(tied.view(), 1e-6, 100).unwrap();
+        assert!(result.warnings.contains(&CalculationWarning::HeavyTies { tied: 3, total: 10 }), "{:?}", result.warnings);
+        
+        // A tight core with a wide shoulder on either side leaves 4 of 10 in the Huber tails
+        let heavy_tailed = array![9.97, 9.98, 9.99, 10.01, 10.02, 10.03, 8.99, 9.01, 10.99, 11.01];
+        let result = calculate_algorithm_a(heavy_tailed.view(), 1e-6, 100).unwrap();
+        assert!(result.warnings.contains(&CalculationWarning::ManyDownWeighted {
+            down_weighted: 4,
+            total: 10,
+        }), "{:?}", result.warnings);
+        
+        // Converging on the last allowed iterations
+        let data: Array1<f64> = (0..50).map(|i| 10.0 + ((i * 37) % 50) as f64 / 25.0).chain([20.0, 25.0]).collect();
+        let iterations = calculate_algorithm_a(data.view(), 1e-12, 100).unwrap().iterations;
+        assert!(iterations >= 5, "{}", iterations);
+        let result = calculate_algorithm_a(data.view(), 1e-12, iterations + 1).unwrap();
+        assert!(result.warnings.contains(&CalculationWarning::SlowConvergence {
+            iterations,
+            max_iterations: iterations + 1,
+        }), "{:?}", result.warnings);
+        
+        // The detailed calculation reports the same warnings
+        let detailed = calculate_algorithm_a_detailed(data.view(), 1e-12, iterations + 1).unwrap();
+        assert_eq!(detailed.result.warnings, result.warnings);
+    }
+
+    #[test]
+    fn test_algorithm_a_no_warnings() {
+        let data = array![9.8, 10.0, 10.2, 9.9, 10.1, 9.7, 10.3, 10.05, 9.95, 10.15];
+        let result = calculate_algorithm_a(data.view(), 1e-6, 100).unwrap();
+        assert!(result.warnings.is_empty(), "{:?}", result.warnings);
+    }
+
+    #[test]
+    fn test_algorithm_a_huber_weight_unchanged() {
+        // Results of the earlier psi(u) / u weight with a 1e-10 cutoff, to the last bit
+        let cases = [
+            (array![1.0, 2.0, 3.0, 4.0, 5.0], 3.0, std::f64::consts::SQRT_2, 5, 1),
+            (array![1.0, 2.0, 3.0, 4.0, 100.0], 14.531775439983246, 32.08473518090653, 5, 26),
+            (
+                array![9.8, 10.0, 10.2, 9.9, 10.1, 9.7, 10.3, 10.05, 9.95, 10.15],
+                10.018750025462479, 0.16894084170006707, 10, 6,
+            ),
+            (
+                array![9.97, 9.98, 9.99, 10.01, 10.02, 10.03, 8.99, 9.01, 10.99, 11.01],
+                10.000000000000002, 0.6183541451215578, 10, 13,
+            ),
+        ];
+        
+        for (data, x_pt, s_star, participants_used, iterations) in cases {
+            let result = calculate_algorithm_a(data.view(), 1e-6, 100).unwrap();
+            assert_eq!(result.x_pt, x_pt);
+            assert_eq!(result.s_star, s_star);
+            assert_eq!(result.participants_used, participants_used);
+            assert_eq!(result.iterations, iterations);
+        }
+    }
+
     #[test]
     fn test_algorithm_a_insufficient_data() {
         let data = array![1.0, 2.0]; // Too few points
@@ -236,6 +1189,195 @@ mod tests {
         matches!(result.unwrap_err(), CalculationError::InsufficientData { .. });
     }
 
+    #[test]
+    fn test_breakdown_analysis() {
+        let data = array![9.8, 10.1, 10.0, 9.9, 10.2, 10.0, 10.3, 9.7, 10.05, 25.0];
+        let detailed = calculate_algorithm_a_detailed(data.view(), 1e-6, 100).unwrap();
+        let analysis = breakdown_analysis(data.view(), &detailed, 1e-6, 100).unwrap();
+        
+        let expected = detailed.weights.iter().filter(|&&w| w < 1.0).count();
+        assert_eq!(analysis.winsorized, expected);
+        assert!(analysis.winsorized >= 1);
+        assert_abs_diff_eq!(analysis.winsorized_fraction, expected as f64 / 10.0, epsilon = 1e-12);
+        
+        // Huber weighting bounds the influence of any single result
+        assert!(analysis.sensitivity > 0.0);
+        assert!(analysis.sensitivity < detailed.result.s_star);
+        
+        // Moving the lowest result to the far side shifts x* the most
+        assert_eq!(analysis.most_influential, 7);
+    }
+
+    #[test]
+    fn test_breakdown_analysis_dimension_mismatch() {
+        let data = array![1.0, 2.0, 3.0, 4.0, 5.0];
+        let detailed = calculate_algorithm_a_detailed(data.view(), 1e-6, 100).unwrap();
+        let other = array![1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+        assert!(matches!(
+            breakdown_analysis(other.view(), &detailed, 1e-6, 100),
+            Err(CalculationError::DimensionMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn test_calculate_robust_methods() {
+        let results = array![9.8, 10.0, 10.2, 9.9, 10.1, 10.05, 9.95, 15.0];
+        let config = RobustConfig::default();
+        
+        let algorithm_a = calculate_robust(results.view(), RobustMethod::AlgorithmA, &config, None).unwrap();
+        let reference = calculate_algorithm_a(results.view(), config.tolerance, config.max_iterations).unwrap();
+        assert_eq!(algorithm_a.location, reference.x_pt);
+        assert_eq!(algorithm_a.scale, reference.s_star);
+        assert_eq!(algorithm_a.iterations, reference.iterations);
+        assert!(algorithm_a.converged);
+        
+        let median = calculate_robust(results.view(), RobustMethod::Median, &config, None).unwrap();
+        assert_abs_diff_eq!(median.location, 10.025, epsilon = 1e-12);
+        assert_abs_diff_eq!(median.scale, 0.1 * MAD_TO_SIGMA, epsilon = 1e-12);
+        assert_eq!(median.iterations, 0);
+        assert!(median.converged);
+        
+        // The redescending estimators reject the outlier at 15.0 entirely
+        for method in [RobustMethod::Hampel, RobustMethod::Biweight] {
+            let estimate = calculate_robust(results.view(), method, &config, None).unwrap();
+            assert!(estimate.converged, "{:?}", method);
+            assert!(estimate.iterations > 0, "{:?}", method);
+            assert_eq!(estimate.scale, median.scale);
+            assert!((estimate.location - 10.0).abs() < 0.05, "{:?}: {}", method, estimate.location);
+        }
+    }
+
+    #[test]
+    fn test_assess_participant_adequacy() {
+        let cases = [
+            (0, RobustMethod::AlgorithmA, ParticipantAdequacy::Inadequate),
+            (4, RobustMethod::AlgorithmA, ParticipantAdequacy::Inadequate),
+            (5, RobustMethod::AlgorithmA, ParticipantAdequacy::Marginal),
+            (10, RobustMethod::AlgorithmA, ParticipantAdequacy::Marginal),
+            (11, RobustMethod::AlgorithmA, ParticipantAdequacy::Adequate),
+            (11, RobustMethod::Median, ParticipantAdequacy::Adequate),
+            (4, RobustMethod::Hampel, ParticipantAdequacy::Inadequate),
+            (14, RobustMethod::Biweight, ParticipantAdequacy::Marginal),
+            (15, RobustMethod::Hampel, ParticipantAdequacy::Adequate),
+        ];
+        
+        for (n, method, expected) in cases {
+            assert_eq!(assess_participant_adequacy(n, method), expected, "n = {}, {:?}", n, method);
+        }
+        
+        // Inadequate exactly where the estimators refuse to run
+        let four = array![1.0, 2.0, 3.0, 4.0];
+        assert!(calculate_robust(four.view(), RobustMethod::Median, &RobustConfig::default(), None).is_err());
+        assert_eq!(ParticipantAdequacy::Marginal.as_str(), "marginal");
+    }
+
+    #[test]
+    fn test_calculate_robust_mad_consistency() {
+        let results = array![9.8, 10.0, 10.2, 9.9, 10.1, 10.05, 9.95, 15.0];
+        let config = RobustConfig::default();
+        assert_eq!(config.mad_consistency, MAD_TO_SIGMA);
+        
+        // The default reproduces the results from before the constant was configurable
+        let estimate = calculate_robust(results.view(), RobustMethod::AlgorithmA, &config, None).unwrap();
+        assert_eq!(estimate.location, 10.222342012907651);
+        assert_eq!(estimate.scale, 1.0375967751471655);
+        let result = calculate_algorithm_a(results.view(), config.tolerance, config.max_iterations).unwrap();
+        assert_eq!((result.x_pt, result.s_star, result.iterations), (10.222342012907651, 1.0375967751471655, 20));
+        let detailed = calculate_algorithm_a_detailed(results.view(), config.tolerance, config.max_iterations).unwrap();
+        assert_eq!(detailed.weights[7], 0.3257652948213567);
+        
+        // With a loose tolerance Algorithm A stops after one step from the initial s*
+        let one_step = |mad_consistency| {
+            calculate_algorithm_a_with_mad_consistency(results.view(), 10.0, 100, mad_consistency).unwrap()
+        };
+        assert_eq!(one_step(MAD_TO_SIGMA).x_pt, calculate_algorithm_a(results.view(), 10.0, 100).unwrap().x_pt);
+        assert_ne!(one_step(1.0).x_pt, one_step(MAD_TO_SIGMA).x_pt);
+        let detailed_custom = calculate_algorithm_a_detailed_with_mad_consistency(results.view(), 10.0, 100, 1.0).unwrap();
+        assert_eq!(detailed_custom.result.x_pt, one_step(1.0).x_pt);
+        
+        for mad_consistency in [0.0, -1.4826, f64::NAN] {
+            assert!(calculate_algorithm_a_with_mad_consistency(results.view(), 1e-6, 100, mad_consistency).is_err());
+            assert!(calculate_algorithm_a_detailed_with_mad_consistency(results.view(), 1e-6, 100, mad_consistency).is_err());
+        }
+        
+        // MADe scales with the constant
+        let custom = RobustConfig { mad_consistency: 1.0, ..config };
+        let default_median = calculate_robust(results.view(), RobustMethod::Median, &config, None).unwrap();
+        let custom_median = calculate_robust(results.view(), RobustMethod::Median, &custom, None).unwrap();
+        assert_eq!(custom_median.location, default_median.location);
+        assert_abs_diff_eq!(custom_median.scale * MAD_TO_SIGMA, default_median.scale, epsilon = 1e-12);
+        
+        for mad_consistency in [0.0, -1.4826, f64::NAN] {
+            let invalid = RobustConfig { mad_consistency, ..config };
+            for method in [RobustMethod::AlgorithmA, RobustMethod::Median] {
+                assert!(calculate_robust(results.view(), method, &invalid, None).is_err());
+            }
+        }
+    }
+
+    #[test]
+    fn test_calculate_robust_non_convergence() {
+        let results = array![9.8, 10.0, 10.2, 9.9, 10.1, 12.0];
+        let config = RobustConfig { tolerance: 1e-12, max_iterations: 1, ..RobustConfig::default() };
+        
+        for method in [RobustMethod::AlgorithmA, RobustMethod::Hampel, RobustMethod::Biweight] {
+            let estimate = calculate_robust(results.view(), method, &config, None).unwrap();
+            assert!(!estimate.converged, "{:?}", method);
+            assert_eq!(estimate.iterations, 1);
+        }
+        
+        assert!(calculate_algorithm_a(results.view(), 1e-12, 1).is_err());
+    }
+
+    #[test]
+    fn test_calculate_robust_invalid() {
+        let config = RobustConfig::default();
+        assert!(calculate_robust(array![1.0, 2.0].view(), RobustMethod::Median, &config, None).is_err());
+        assert!(calculate_robust(array![1.0, 2.0, 3.0, 4.0, f64::NAN].view(), RobustMethod::Hampel, &config, None).is_err());
+        
+        let config = RobustConfig { tolerance: 0.0, max_iterations: 10, ..RobustConfig::default() };
+        assert!(calculate_robust(array![1.0, 2.0, 3.0, 4.0, 5.0].view(), RobustMethod::Biweight, &config, None).is_err());
+        
+        assert_eq!("biweight".parse::<RobustMethod>().unwrap(), RobustMethod::Biweight);
+        assert!("huber".parse::<RobustMethod>().is_err());
+    }
+
+    #[test]
+    fn test_calculate_robust_transform() {
+        let results = array![1.0, 10.0, 100.0, 1000.0, 10_000.0, 100.0];
+        let config = RobustConfig::default();
+        
+        // Median of the logs is ln(100)
+        let median = calculate_robust(results.view(), RobustMethod::Median, &config, Some(Transform::Log)).unwrap();
+        let log_median = calculate_robust(results.mapv(f64::ln).view(), RobustMethod::Median, &config, None).unwrap();
+        assert_abs_diff_eq!(median.location, 100.0, epsilon = 1e-9);
+        assert_abs_diff_eq!(median.scale, 100.0 * log_median.scale, epsilon = 1e-9);
+        
+        let results = array![4.0, 9.0, 16.0, 16.0, 25.0];
+        let sqrt = calculate_robust(results.view(), RobustMethod::Median, &config, Some(Transform::Sqrt)).unwrap();
+        assert_abs_diff_eq!(sqrt.location, 16.0, epsilon = 1e-12);
+        assert_abs_diff_eq!(sqrt.scale, 2.0 * 4.0 * MAD_TO_SIGMA, epsilon = 1e-12);
+        
+        let algorithm_a = calculate_robust(results.view(), RobustMethod::AlgorithmA, &config, Some(Transform::Log)).unwrap();
+        assert!(algorithm_a.converged);
+        assert!(algorithm_a.location > 4.0 && algorithm_a.location < 25.0);
+    }
+
+    #[test]
+    fn test_calculate_robust_transform_rejects_non_positive() {
+        let config = RobustConfig::default();
+        let results = array![1.0, 2.0, 0.0, 4.0, 5.0];
+        
+        for transform in [Transform::Log, Transform::Sqrt] {
+            let err = calculate_robust(results.view(), RobustMethod::Median, &config, Some(transform)).unwrap_err();
+            assert!(err.to_string().contains("requires positive results"), "{}", err);
+        }
+        
+        assert!(calculate_robust(array![1.0, -2.0, 3.0, 4.0, 5.0].view(), RobustMethod::Hampel, &config, Some(Transform::Log)).is_err());
+        assert_eq!("sqrt".parse::<Transform>().unwrap(), Transform::Sqrt);
+        assert!("exp".parse::<Transform>().is_err());
+    }
+
     #[test]
     fn test_crm_calculation() {
         let result = calculate_from_crm(10.5).unwrap();
@@ -262,4 +1404,116 @@ mod tests {
         let invalid_result = calculate_from_expert_consensus(f64::NEG_INFINITY);
         assert!(invalid_result.is_err());
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_reference_lab_calculation() {
+        let (x_pt, u_x_pt) = calculate_from_reference_lab(12.5, 0.3, 0.4).unwrap();
+        assert_eq!(x_pt, 12.5);
+        assert_abs_diff_eq!(u_x_pt, 0.5, epsilon = 1e-12);
+        
+        let (_, u_x_pt) = calculate_from_reference_lab(12.5, 0.3, 0.0).unwrap();
+        assert_abs_diff_eq!(u_x_pt, 0.3, epsilon = 1e-12);
+        
+        assert!(calculate_from_reference_lab(f64::NAN, 0.3, 0.4).is_err());
+        assert!(calculate_from_reference_lab(12.5, -0.3, 0.4).is_err());
+        assert!(calculate_from_reference_lab(12.5, 0.3, f64::INFINITY).is_err());
+    }
+
+    #[test]
+    fn test_gravimetric_calculation() {
+        let masses = array![0.5, 0.25];
+        let u_masses = array![0.001, 0.002];
+        let purities = array![0.99, 0.98];
+        let u_purities = array![0.005, 0.01];
+        
+        let (w, u_w) = calculate_from_gravimetric(
+            masses.view(), u_masses.view(), purities.view(), u_purities.view(), 100.0, 0.05,
+        ).unwrap();
+        
+        // w = (0.495 + 0.245) / 100
+        assert_abs_diff_eq!(w, 0.0074, epsilon = 1e-15);
+        
+        let expected_variance = ((0.99f64 * 0.001).powi(2) + (0.5f64 * 0.005).powi(2)
+            + (0.98f64 * 0.002).powi(2) + (0.25f64 * 0.01).powi(2)) / 100.0f64.powi(2)
+            + (0.0074f64 * 0.05 / 100.0).powi(2);
+        assert_abs_diff_eq!(u_w, expected_variance.sqrt(), epsilon = 1e-15);
+    }
+
+    #[test]
+    fn test_gravimetric_invalid_inputs() {
+        let ones = array![1.0, 1.0];
+        let small = array![0.01, 0.01];
+        
+        assert!(calculate_from_gravimetric(
+            ones.view(), small.view(), array![1.0].view(), small.view(), 10.0, 0.1,
+        ).is_err());
+        assert!(calculate_from_gravimetric(
+            ones.view(), array![0.01, -0.01].view(), ones.view(), small.view(), 10.0, 0.1,
+        ).is_err());
+        assert!(calculate_from_gravimetric(
+            ones.view(), small.view(), array![1.0, 1.2].view(), small.view(), 10.0, 0.1,
+        ).is_err());
+        assert!(calculate_from_gravimetric(
+            ones.view(), small.view(), ones.view(), small.view(), 0.0, 0.1,
+        ).is_err());
+        assert!(calculate_from_gravimetric(
+            ones.view(), small.view(), ones.view(), small.view(), 10.0, -0.1,
+        ).is_err());
+    }
+
+    #[test]
+    fn test_median_consensus_bootstrap() {
+        let mut rng = SplitMix64::new(11);
+        let results: Array1<f64> = (0..200).map(|_| 50.0 + 2.0 * rng.next_normal()).collect();
+        
+        let (x_pt, u_x_pt) = calculate_median_consensus(results.view(), 2000, 3).unwrap();
+        let mut sorted = results.to_vec();
+        assert_eq!(x_pt, median(&mut sorted).unwrap());
+        
+        // Close to sqrt(π/2) · σ / sqrt(p) for normal data
+        let analytic = MEDIAN_UNCERTAINTY_FACTOR * 2.0 / 200.0_f64.sqrt();
+        assert!((u_x_pt / analytic - 1.0).abs() < 0.3, "u = {}, analytic = {}", u_x_pt, analytic);
+        
+        // Same seed, same result
+        assert_eq!(calculate_median_consensus(results.view(), 2000, 3).unwrap(), (x_pt, u_x_pt));
+        
+        // Identical results have no resampling spread
+        let (x_pt, u_x_pt) = calculate_median_consensus(array![4.0, 4.0, 4.0, 4.0, 4.0].view(), 50, 0).unwrap();
+        assert_eq!((x_pt, u_x_pt), (4.0, 0.0));
+    }
+
+    #[test]
+    fn test_median_consensus_invalid() {
+        let results = array![1.0, 2.0, 3.0, 4.0, 5.0];
+        assert!(matches!(
+            calculate_median_consensus(results.slice(ndarray::s![..4]), 100, 0),
+            Err(CalculationError::InsufficientData { required: 5, actual: 4 })
+        ));
+        assert!(calculate_median_consensus(results.view(), 1, 0).is_err());
+        assert!(calculate_median_consensus(array![1.0, 2.0, f64::NAN, 4.0, 5.0].view(), 100, 0).is_err());
+    }
+
+    #[test]
+    fn test_half_range_mode() {
+        // Right-skewed data: the mode sits in the dense cluster, not near the mean
+        let data = [1.0, 2.0, 2.1, 2.2, 2.3, 5.0, 9.0, 20.0];
+        assert_abs_diff_eq!(half_range_mode(&data).unwrap(), 2.15, epsilon = 1e-12);
+        
+        // Independent of input order, and mirrored data gives the mirrored mode
+        let reversed: Vec<f64> = data.iter().rev().copied().collect();
+        assert_eq!(half_range_mode(&reversed).unwrap(), half_range_mode(&data).unwrap());
+        let mirrored: Vec<f64> = data.iter().map(|x| -x).collect();
+        assert_abs_diff_eq!(half_range_mode(&mirrored).unwrap(), -2.15, epsilon = 1e-12);
+        
+        // Evenly spread values tie everywhere and give the centre
+        assert_abs_diff_eq!(half_range_mode(&[1.0, 2.0, 3.0]).unwrap(), 2.0, epsilon = 1e-12);
+        assert_abs_diff_eq!(half_range_mode(&[1.0, 2.0, 3.0, 4.0, 5.0]).unwrap(), 3.0, epsilon = 1e-12);
+        
+        // Repeated values and the two-point case
+        assert_eq!(half_range_mode(&[3.0, 3.0, 3.0, 7.0]).unwrap(), 3.0);
+        assert_eq!(half_range_mode(&[4.0, 8.0]).unwrap(), 6.0);
+        
+        assert!(half_range_mode(&[1.0]).is_err());
+        assert!(half_range_mode(&[1.0, f64::NAN, 2.0]).is_err());
+    }
+}
@@ -4,20 +4,64 @@
 //! for the PT-CLI application using PyO3 for Python interoperability.
 
 use pyo3::prelude::*;
-use numpy::{PyReadonlyArray1, PyArray1};
+use pyo3::types::{PyDict, PyList, PyTuple};
+use numpy::{PyReadonlyArray1, PyReadonlyArray2, PyArray1, PyArray2};
+use ndarray::{Array1, ArrayView1};
+use std::collections::{BTreeMap, HashMap};
 
 pub mod utils;
 pub mod estimators;
 pub mod uncertainty;
 pub mod scoring;
+pub mod plots;
+pub mod outliers;
+pub mod sigma_pt;
+pub mod validation;
+pub mod design;
+pub mod stability;
+pub mod precision;
+pub mod round_data;
+pub mod multivariate;
 
 // Re-export main types for convenience
 pub use utils::CalculationError;
-use estimators::{calculate_algorithm_a, calculate_from_crm, calculate_from_formulation, calculate_from_expert_consensus};
-use uncertainty::{calculate_uncertainty_consensus, calculate_uncertainty_crm, 
-                  calculate_uncertainty_formulation, calculate_uncertainty_expert};
-use scoring::{calculate_z_scores, calculate_z_prime_scores, 
-              calculate_z_prime_scores_no_participant_uncertainties};
+use utils::{CalculationWarning, GrubbsAlternative};
+use estimators::{calculate_algorithm_a_detailed, calculate_algorithm_a_with_mad_consistency,
+                 calculate_algorithm_a_detailed_with_mad_consistency, calculate_from_crm, calculate_from_formulation, calculate_from_expert_consensus,
+                 calculate_from_reference_lab, calculate_from_gravimetric, calculate_median_consensus, half_range_mode, breakdown_analysis,
+                 calculate_robust, assess_participant_adequacy, RobustConfig, RobustMethod, Transform};
+use uncertainty::{calculate_uncertainty_consensus, calculate_uncertainty_consensus_weighted, uncertainty_of_s_star, relative_uncertainty,
+                  apply_recovery_correction,
+                  uncertainty_from_homogeneity, calculate_uncertainty_crm, 
+                  calculate_uncertainty_formulation, calculate_uncertainty_expert,
+                  calculate_uncertainty_expert_robust, calculate_expert_consensus_weighted, calculate_uncertainty_median, ScaleKind,
+                  coverage_interval_consensus, CoverageInterval, assigned_value_expanded,
+                  jackknife_uncertainty, bootstrap_uncertainty_consensus, robust_consensus, propagate_uncertainty,
+                  effective_degrees_of_freedom,
+                  check_uncertainty_criterion, screen_participant_uncertainties, check_assigned_value_agreement, combine_reference_and_consensus, dark_uncertainty, monte_carlo_uncertainty, combine_named_uncertainty_components,
+                  consensus_uncertainty_budget, crm_uncertainty_budget, formulation_uncertainty_budget,
+                  UncertaintyBudget};
+use scoring::{calculate_z_scores, calculate_z_scores_into, calculate_z_scores_grouped,
+              calculate_z_scores_from_interval, calculate_z_scores_with_spec, calculate_z_scores_trimmed, compare_assigned_values, estimate_systematic_bias,
+              calculate_z_scores_asymmetric, calculate_log_z_scores, log_sigma_acceptance_factors, apply_negative_policy, NegativePolicy,
+              calculate_z_prime_scores_into, calculate_z_prime_scores_relative, calculate_z_prime_scores_masked,
+              calculate_z_prime_scores_no_participant_uncertainties, standardized_residuals,
+              z_score_uncertainties, z_score_diagnostics, unsatisfactory_rate,
+              interpret_zeta_score_with_limits, interpret_zeta_scores_with_limits,
+              joint_interpretation, boundary_sensitivity, cross_check_scores, detect_identical_results,
+              detect_identical_results_multi, grubbs_flags, round_scores, rank_by_abs_score, RoundingMode,
+              ScoreDtype, interpret_z_score, score_by_group, calculate_z_scores_for_round, calculate_z_prime_scores_for_round,
+              GroupAssigned, ParticipantHistory, ScoreLimits, ScoreSet,
+              full_diagnostics, AssignedValue, ParticipantDiagnostics};
+use plots::{score_chart_data, ChartOrder};
+use outliers::detect_magnitude_errors;
+use sigma_pt::{sigma_pt_with_floor, sigma_pt_by_perception};
+use validation::check_significant_figures;
+use design::{analyze_multilevel, minimum_detectable_bias};
+use stability::drift_correct;
+use precision::robust_variance_components;
+use round_data::{RoundData, extract_participant_id};
+use multivariate::robust_correlation_matrix;
 
 /// Calculate assigned value using Algorithm A (robust statistics)
 /// 
@@ -27,140 +71,2020 @@ use scoring::{calculate_z_scores, calculate_z_prime_scores,
 /// * `results` - NumPy array of participant results
 /// * `tolerance` - Convergence tolerance (default: 1e-6)
 /// * `max_iterations` - Maximum iterations (default: 100)
+/// * `return_warnings` - Append the list of (code, message) warnings to the tuple (default: False)
+/// * `emit_warnings` - Also issue each warning through Python's `warnings.warn` (default: False)
+/// * `mad_consistency` - Factor converting the initial MAD to s* (default: 1.4826)
 /// 
 /// # Returns
-/// * Tuple of (x_pt, s_star, participants_used, iterations)
+/// * Tuple of (x_pt, s_star, participants_used, iterations), followed by the
+///   warnings list when `return_warnings=True`
 #[pyfunction]
 fn py_calculate_algorithm_a(
     py: Python,
     results: PyReadonlyArray1<f64>,
     tolerance: Option<f64>,
     max_iterations: Option<usize>,
-) -> PyResult<(f64, f64, usize, usize)> {
+    return_warnings: Option<bool>,
+    emit_warnings: Option<bool>,
+    mad_consistency: Option<f64>,
+) -> PyResult<PyObject> {
     let results_array = results.as_array();
     let tol = tolerance.unwrap_or(utils::constants::DEFAULT_TOLERANCE);
     let max_iter = max_iterations.unwrap_or(utils::constants::DEFAULT_MAX_ITERATIONS);
+    let mad_consistency = mad_consistency.unwrap_or(utils::constants::MAD_TO_SIGMA);
     
-    match calculate_algorithm_a(results_array, tol, max_iter) {
-        Ok(result) => Ok((result.x_pt, result.s_star, result.participants_used, result.iterations)),
+    let result = calculate_algorithm_a_with_mad_consistency(results_array, tol, max_iter, mad_consistency)?;
+    let warnings = warnings_to_py(py, &result.warnings, emit_warnings.unwrap_or(false))?;
+    
+    if return_warnings.unwrap_or(false) {
+        Ok((result.x_pt, result.s_star, result.participants_used, result.iterations, warnings).into_py(py))
+    } else {
+        Ok((result.x_pt, result.s_star, result.participants_used, result.iterations).into_py(py))
+    }
+}
+
+/// Convert calculation warnings to a list of (code, message) tuples
+/// 
+/// With `emit` set, each warning is also issued as a Python UserWarning whose
+/// text is "code: message", so it shows up in logs without changing call sites.
+fn warnings_to_py(
+    py: Python,
+    warnings: &[CalculationWarning],
+    emit: bool,
+) -> PyResult<Vec<(&'static str, String)>> {
+    warnings.iter()
+        .map(|warning| {
+            let message = warning.to_string();
+            if emit {
+                let category = py.get_type::<pyo3::exceptions::PyUserWarning>();
+                PyErr::warn(py, category, &format!("{}: {}", warning.code(), message), 1)?;
+            }
+            Ok((warning.code(), message))
+        })
+        .collect()
+}
+
+/// Python return type of the detailed Algorithm A calculation
+type AlgorithmADetailedTuple = (f64, f64, usize, usize, Py<PyArray1<f64>>, Py<PyArray1<f64>>);
+
+/// Calculate Algorithm A with per-participant diagnostics
+/// 
+/// `mad_consistency` converts the initial MAD to s* (default 1.4826).
+/// 
+/// # Returns
+/// * Tuple of (x_pt, s_star, participants_used, iterations, weights, standardized_residuals)
+#[pyfunction]
+fn py_calculate_algorithm_a_detailed(
+    py: Python,
+    results: PyReadonlyArray1<f64>,
+    tolerance: Option<f64>,
+    max_iterations: Option<usize>,
+    mad_consistency: Option<f64>,
+) -> PyResult<AlgorithmADetailedTuple> {
+    let results_array = results.as_array();
+    let tol = tolerance.unwrap_or(utils::constants::DEFAULT_TOLERANCE);
+    let max_iter = max_iterations.unwrap_or(utils::constants::DEFAULT_MAX_ITERATIONS);
+    let mad_consistency = mad_consistency.unwrap_or(utils::constants::MAD_TO_SIGMA);
+    
+    let detailed = calculate_algorithm_a_detailed_with_mad_consistency(results_array, tol, max_iter, mad_consistency)?;
+    let result = detailed.result;
+    Ok((
+        result.x_pt,
+        result.s_star,
+        result.participants_used,
+        result.iterations,
+        PyArray1::from_array(py, &detailed.weights).to_owned(),
+        PyArray1::from_array(py, &detailed.standardized_residuals).to_owned(),
+    ))
+}
+
+/// Analyse how close the Algorithm A result is to breaking down
+/// 
+/// # Returns
+/// * Dict with keys winsorized, winsorized_fraction, sensitivity and most_influential
+#[pyfunction]
+fn py_breakdown_analysis(
+    py: Python,
+    results: PyReadonlyArray1<f64>,
+    tolerance: Option<f64>,
+    max_iterations: Option<usize>,
+) -> PyResult<PyObject> {
+    let results_array = results.as_array();
+    let tol = tolerance.unwrap_or(utils::constants::DEFAULT_TOLERANCE);
+    let max_iter = max_iterations.unwrap_or(utils::constants::DEFAULT_MAX_ITERATIONS);
+    
+    let detailed = calculate_algorithm_a_detailed(results_array, tol, max_iter)?;
+    let analysis = breakdown_analysis(results_array, &detailed, tol, max_iter)?;
+    
+    let dict = PyDict::new(py);
+    dict.set_item("winsorized", analysis.winsorized)?;
+    dict.set_item("winsorized_fraction", analysis.winsorized_fraction)?;
+    dict.set_item("sensitivity", analysis.sensitivity)?;
+    dict.set_item("most_influential", analysis.most_influential)?;
+    Ok(dict.into())
+}
+
+/// Kish's effective sample size (Σw)² / Σw² of e.g. the Algorithm A weights
+#[pyfunction]
+fn py_effective_sample_size(weights: PyReadonlyArray1<f64>) -> PyResult<f64> {
+    let weights = weights.as_array().to_vec();
+    Ok(utils::effective_sample_size(&weights)?)
+}
+
+/// Check whether s* is quantized by the reporting resolution
+/// 
+/// Pass either `resolution` or the `results`, from which the resolution is
+/// inferred; an explicit resolution takes precedence.
+/// 
+/// # Returns
+/// * Tuple of (resolution, s* / resolution, resolution_limited)
+#[pyfunction]
+fn py_scale_vs_resolution(
+    s_star: f64,
+    resolution: Option<f64>,
+    results: Option<PyReadonlyArray1<f64>>,
+) -> PyResult<(f64, f64, bool)> {
+    let resolution = match (resolution, results) {
+        (Some(resolution), _) => resolution,
+        (None, Some(results)) => utils::rounding_resolution(&results.as_array().to_vec())?,
+        (None, None) => {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "Either resolution or results must be given",
+            ))
+        }
+    };
+    
+    let (ratio, resolution_limited) = utils::scale_vs_resolution(s_star, resolution)?;
+    Ok((resolution, ratio, resolution_limited))
+}
+
+/// Weighted median of `values`, with the midpoint taken when the cumulative weight is exactly half
+#[pyfunction]
+fn py_weighted_median(values: PyReadonlyArray1<f64>, weights: PyReadonlyArray1<f64>) -> PyResult<f64> {
+    let values = values.as_array().to_vec();
+    let weights = weights.as_array().to_vec();
+    Ok(utils::weighted_median(&values, &weights)?)
+}
+
+/// Rousseeuw-Croux Sn scale estimate (location-free, 50 % breakdown point)
+#[pyfunction]
+fn py_sn_scale(data: PyReadonlyArray1<f64>) -> PyResult<f64> {
+    let data = data.as_array().to_vec();
+    Ok(utils::sn_scale(&data)?)
+}
+
+/// Quantile of `data` at probability `q` in [0, 1]
+/// 
+/// `method` is "linear" (default), "lower", "higher" or "midpoint", matching
+/// NumPy's `method=` option of the same name.
+#[pyfunction]
+fn py_quantile(data: PyReadonlyArray1<f64>, q: f64, method: Option<&str>) -> PyResult<f64> {
+    let method: utils::QuantileMethod = method.unwrap_or("linear").parse()?;
+    let data = data.as_array().to_vec();
+    Ok(utils::quantile(&data, q, method)?)
+}
+
+/// Terminal digit frequencies of results reported to `decimals` decimal places
+/// 
+/// # Returns
+/// * Tuple of (counts of digits 0 to 9, chi-squared statistic against uniform digits, p-value)
+#[pyfunction]
+fn py_terminal_digit_distribution(data: PyReadonlyArray1<f64>, decimals: u32) -> PyResult<(Vec<usize>, f64, f64)> {
+    let data = data.as_array().to_vec();
+    let distribution = utils::terminal_digit_distribution(&data, decimals)?;
+    Ok((distribution.counts.to_vec(), distribution.chi_square, distribution.p_value))
+}
+
+/// Bias-corrected sample skewness (scipy.stats.skew with bias=False)
+#[pyfunction]
+fn py_skewness(data: PyReadonlyArray1<f64>) -> PyResult<f64> {
+    Ok(utils::skewness(&data.as_array().to_vec())?)
+}
+
+/// Bias-corrected sample excess kurtosis (scipy.stats.kurtosis with bias=False)
+#[pyfunction]
+fn py_kurtosis(data: PyReadonlyArray1<f64>) -> PyResult<f64> {
+    Ok(utils::kurtosis_excess(&data.as_array().to_vec())?)
+}
+
+/// Medcouple robust skewness in [-1, 1]
+#[pyfunction]
+fn py_medcouple(data: PyReadonlyArray1<f64>) -> PyResult<f64> {
+    Ok(utils::medcouple(&data.as_array().to_vec())?)
+}
+
+/// Anderson-Darling test of normality with estimated mean and variance
+/// 
+/// `alpha` is one of 0.15, 0.10, 0.05 (default), 0.025 or 0.01.
+/// 
+/// # Returns
+/// * Tuple of (adjusted statistic A*², critical value, whether normality is not rejected)
+#[pyfunction]
+fn py_anderson_darling(data: PyReadonlyArray1<f64>, alpha: Option<f64>) -> PyResult<(f64, f64, bool)> {
+    let result = utils::anderson_darling_normal(&data.as_array().to_vec(), alpha.unwrap_or(0.05))?;
+    Ok((result.statistic, result.critical_value, result.is_normal))
+}
+
+/// Shapiro-Wilk test of normality (Royston AS R94, 3 to 5000 values)
+/// 
+/// # Returns
+/// * Tuple of (W statistic, p-value)
+#[pyfunction]
+fn py_shapiro_wilk(data: PyReadonlyArray1<f64>) -> PyResult<(f64, f64)> {
+    let result = utils::shapiro_wilk(&data.as_array().to_vec())?;
+    Ok((result.statistic, result.p_value))
+}
+
+/// Tukey's bisquare psi function at x with tuning constant c
+#[pyfunction]
+fn py_bisquare_psi(x: f64, c: f64) -> f64 {
+    utils::bisquare_psi(x, c)
+}
+
+/// Tukey's bisquare weight psi(x) / x with tuning constant c
+#[pyfunction]
+fn py_bisquare_weight(x: f64, c: f64) -> f64 {
+    utils::bisquare_weight(x, c)
+}
+
+/// Hampel's three-part redescending psi function at x with knots a <= b < c
+#[pyfunction]
+fn py_hampel_psi(x: f64, a: f64, b: f64, c: f64) -> f64 {
+    utils::hampel_psi(x, a, b, c)
+}
+
+/// Find every NaN or infinite entry of an array
+/// 
+/// # Returns
+/// * List of (index, value) tuples in index order, empty if all values are finite
+#[pyfunction]
+fn py_find_invalid_values(data: PyReadonlyArray1<f64>) -> PyResult<Vec<(usize, f64)>> {
+    Ok(utils::find_invalid_values(data.as_array()))
+}
+
+/// Infer the reporting resolution (finest decimal place used) of a set of results
+#[pyfunction]
+fn py_rounding_resolution(results: PyReadonlyArray1<f64>) -> PyResult<f64> {
+    Ok(utils::rounding_resolution(&results.as_array().to_vec())?)
+}
+
+/// Calculate a robust location and scale with the method selected by name
+/// 
+/// `method` is one of "algorithm_a", "hampel", "biweight" or "median".
+/// `transform` is "log", "sqrt" or "none" (default); with a transform the
+/// location and scale are returned on the original scale. `mad_consistency`
+/// scales the MAD to a standard deviation (default 1.4826, for normal data).
+/// 
+/// # Returns
+/// * Dict with keys location, scale, iterations and converged
+#[pyfunction]
+fn py_calculate_robust(
+    py: Python,
+    results: PyReadonlyArray1<f64>,
+    method: &str,
+    tolerance: Option<f64>,
+    max_iterations: Option<usize>,
+    transform: Option<&str>,
+    mad_consistency: Option<f64>,
+) -> PyResult<PyObject> {
+    let method: RobustMethod = method.parse()?;
+    let transform = match transform {
+        None | Some("none") => None,
+        Some(transform) => Some(transform.parse::<Transform>()?),
+    };
+    let defaults = RobustConfig::default();
+    let config = RobustConfig {
+        tolerance: tolerance.unwrap_or(defaults.tolerance),
+        max_iterations: max_iterations.unwrap_or(defaults.max_iterations),
+        mad_consistency: mad_consistency.unwrap_or(defaults.mad_consistency),
+    };
+    
+    let estimate = calculate_robust(results.as_array(), method, &config, transform)?;
+    
+    let dict = PyDict::new(py);
+    dict.set_item("location", estimate.location)?;
+    dict.set_item("scale", estimate.scale)?;
+    dict.set_item("iterations", estimate.iterations)?;
+    dict.set_item("converged", estimate.converged)?;
+    Ok(dict.into())
+}
+
+/// Check whether `n` participants are enough for the named robust method
+/// 
+/// `method` is one of "algorithm_a", "hampel", "biweight" or "median".
+/// 
+/// # Returns
+/// * "adequate", "marginal" or "inadequate"
+#[pyfunction]
+fn py_assess_participant_adequacy(n: usize, method: &str) -> PyResult<&'static str> {
+    let method: RobustMethod = method.parse()?;
+    Ok(assess_participant_adequacy(n, method).as_str())
+}
+
+/// Calculate assigned value from CRM
+#[pyfunction]
+fn py_calculate_from_crm(crm_value: f64) -> PyResult<f64> {
+    match calculate_from_crm(crm_value) {
+        Ok(result) => Ok(result),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Calculate assigned value from formulation
+#[pyfunction]
+fn py_calculate_from_formulation(formulation_value: f64) -> PyResult<f64> {
+    match calculate_from_formulation(formulation_value) {
+        Ok(result) => Ok(result),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Calculate assigned value from expert consensus
+#[pyfunction]
+fn py_calculate_from_expert_consensus(expert_value: f64) -> PyResult<f64> {
+    match calculate_from_expert_consensus(expert_value) {
+        Ok(result) => Ok(result),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Calculate assigned value and combined uncertainty from a reference laboratory
+/// 
+/// # Returns
+/// * Tuple of (x_pt, u_x_pt)
+#[pyfunction]
+fn py_calculate_from_reference_lab(
+    value: f64,
+    u_measurement: f64,
+    u_transfer: f64,
+) -> PyResult<(f64, f64)> {
+    match calculate_from_reference_lab(value, u_measurement, u_transfer) {
+        Ok(result) => Ok(result),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Calculate the consensus median as assigned value with a bootstrap uncertainty
+/// 
+/// `seed` (default 0) makes the resampling reproducible.
+/// 
+/// # Returns
+/// * Tuple of (median, bootstrap standard uncertainty)
+#[pyfunction]
+fn py_calculate_median_consensus(
+    results: PyReadonlyArray1<f64>,
+    n_resamples: usize,
+    seed: Option<u64>,
+) -> PyResult<(f64, f64)> {
+    Ok(calculate_median_consensus(results.as_array(), n_resamples, seed.unwrap_or(0))?)
+}
+
+/// Estimate the mode of skewed data with the half-range mode
+#[pyfunction]
+fn py_half_range_mode(data: PyReadonlyArray1<f64>) -> PyResult<f64> {
+    let data = data.as_array().to_vec();
+    Ok(half_range_mode(&data)?)
+}
+
+/// Calculate the consensus assigned value and its uncertainty in one call
+/// 
+/// `config` is an optional dict with keys "tolerance" and "max_iterations".
+/// When `coverage_factor` is given, a `CoverageInterval` at `confidence_level`
+/// (default 0.95) is added under the key coverage_interval.
+/// 
+/// With `emit_warnings=True` each Algorithm A warning is also issued through
+/// Python's `warnings.warn`.
+/// 
+/// # Returns
+/// * Dict with keys x_pt, u_x_pt, s_star, n_total, iterations, scale_clamped and
+///   warnings (a list of (code, message) tuples)
+#[pyfunction]
+fn py_robust_consensus(
+    py: Python,
+    results: PyReadonlyArray1<f64>,
+    config: Option<&PyDict>,
+    coverage_factor: Option<f64>,
+    confidence_level: Option<f64>,
+    emit_warnings: Option<bool>,
+) -> PyResult<PyObject> {
+    let mut tolerance = utils::constants::DEFAULT_TOLERANCE;
+    let mut max_iterations = utils::constants::DEFAULT_MAX_ITERATIONS;
+    
+    if let Some(config) = config {
+        for (key, value) in config.iter() {
+            match key.extract::<&str>()? {
+                "tolerance" => tolerance = value.extract()?,
+                "max_iterations" => max_iterations = value.extract()?,
+                other => {
+                    return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                        "Unknown config key '{}': expected 'tolerance' or 'max_iterations'",
+                        other
+                    )))
+                }
+            }
+        }
+    }
+    
+    let consensus = robust_consensus(results.as_array(), tolerance, max_iterations)?;
+    
+    let dict = PyDict::new(py);
+    dict.set_item("x_pt", consensus.x_pt)?;
+    dict.set_item("u_x_pt", consensus.u_x_pt)?;
+    dict.set_item("s_star", consensus.s_star)?;
+    dict.set_item("n_total", consensus.n_total)?;
+    dict.set_item("iterations", consensus.iterations)?;
+    dict.set_item("scale_clamped", consensus.scale_clamped)?;
+    dict.set_item("warnings", warnings_to_py(py, &consensus.warnings, emit_warnings.unwrap_or(false))?)?;
+    if let Some(k) = coverage_factor {
+        let interval = consensus.coverage_interval(k, confidence_level.unwrap_or(0.95))?;
+        dict.set_item("coverage_interval", Py::new(py, interval)?)?;
+    }
+    Ok(dict.into())
+}
+
+/// Calculate assigned value and uncertainty from a gravimetric preparation
+/// 
+/// # Returns
+/// * Tuple of (mass fraction, standard uncertainty)
+#[pyfunction]
+fn py_calculate_from_gravimetric(
+    masses: PyReadonlyArray1<f64>,
+    u_masses: PyReadonlyArray1<f64>,
+    purities: PyReadonlyArray1<f64>,
+    u_purities: PyReadonlyArray1<f64>,
+    total_mass: f64,
+    u_total: f64,
+) -> PyResult<(f64, f64)> {
+    match calculate_from_gravimetric(
+        masses.as_array(),
+        u_masses.as_array(),
+        purities.as_array(),
+        u_purities.as_array(),
+        total_mass,
+        u_total,
+    ) {
+        Ok(result) => Ok(result),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Return u(x_pt), or (u(x_pt), relative uncertainty in %) if `as_relative` is set
+/// 
+/// The relative mode needs `x_pt` and cannot be combined with `budget=True`.
+fn uncertainty_output(
+    py: Python,
+    u_x_pt: f64,
+    budget: bool,
+    as_relative: Option<bool>,
+    x_pt: Option<f64>,
+) -> PyResult<PyObject> {
+    if !as_relative.unwrap_or(false) {
+        return Ok(u_x_pt.into_py(py));
+    }
+    
+    if budget {
+        return Err(CalculationError::InvalidInput {
+            message: "as_relative cannot be combined with budget=True".to_string(),
+        }.into());
+    }
+    
+    let x_pt = x_pt.ok_or_else(|| CalculationError::InvalidInput {
+        message: "as_relative requires the assigned value x_pt".to_string(),
+    })?;
+    Ok((u_x_pt, relative_uncertainty(x_pt, u_x_pt)?).into_py(py))
+}
+
+/// Calculate uncertainty for consensus values (Algorithm A results)
+/// 
+/// `small_sample_correction` (default false) multiplies by the t-factor for
+/// p - 1 degrees of freedom at 68.27 % coverage. `scale_method` names the
+/// estimator of `robust_std_dev`: "s_star" (default), "qn", "niqr" or "made".
+/// With `budget=True` an `UncertaintyBudget` is returned instead of the bare
+/// uncertainty; with `as_relative=True` a tuple of (u_x_pt, 100 * u_x_pt / |x_pt|)
+/// is returned, which requires `x_pt`.
+#[pyfunction]
+#[allow(clippy::too_many_arguments)]
+fn py_calculate_uncertainty_consensus(
+    py: Python,
+    robust_std_dev: f64,
+    num_participants: usize,
+    small_sample_correction: Option<bool>,
+    budget: Option<bool>,
+    scale_method: Option<&str>,
+    as_relative: Option<bool>,
+    x_pt: Option<f64>,
+) -> PyResult<PyObject> {
+    let correction = small_sample_correction.unwrap_or(false);
+    let scale_method: ScaleKind = scale_method.unwrap_or("s_star").parse()?;
+    let budget = budget.unwrap_or(false);
+    
+    if budget && !as_relative.unwrap_or(false) {
+        let budget = consensus_uncertainty_budget(robust_std_dev, num_participants, correction, scale_method)?;
+        return Ok(budget.into_py(py));
+    }
+    
+    let u_x_pt = calculate_uncertainty_consensus(robust_std_dev, num_participants, correction, scale_method)?;
+    uncertainty_output(py, u_x_pt, budget, as_relative, x_pt)
+}
+
+/// Calculate the consensus uncertainty from the effective number of participants
+/// 
+/// `weights` are the final Algorithm A weights, e.g. from `py_calculate_algorithm_a_detailed`.
+/// 
+/// # Returns
+/// * Tuple of (classical u(x_pt), weight-adjusted u(x_pt), effective number of participants)
+#[pyfunction]
+fn py_calculate_uncertainty_consensus_weighted(
+    s_star: f64,
+    weights: PyReadonlyArray1<f64>,
+) -> PyResult<(f64, f64, f64)> {
+    let weights = weights.as_array().to_vec();
+    let result = calculate_uncertainty_consensus_weighted(s_star, &weights)?;
+    Ok((result.classical, result.weighted, result.p_eff))
+}
+
+/// Calculate uncertainty for a median assigned value
+/// 
+/// `scale_kind` names the estimator of `scale_estimate`: "made", "niqr", "s_star" or "qn".
+#[pyfunction]
+fn py_calculate_uncertainty_median(
+    scale_estimate: f64,
+    num_participants: usize,
+    scale_kind: &str,
+) -> PyResult<f64> {
+    let scale_kind: ScaleKind = scale_kind.parse()?;
+    Ok(calculate_uncertainty_median(scale_estimate, num_participants, scale_kind)?)
+}
+
+/// Calculate the standard uncertainty of the robust standard deviation s*
+/// 
+/// `classical` (default false) uses s / sqrt(2(p - 1)) instead of 0.83 · s* / sqrt(p).
+#[pyfunction]
+fn py_uncertainty_of_s_star(s_star: f64, num_participants: usize, classical: Option<bool>) -> PyResult<f64> {
+    Ok(uncertainty_of_s_star(s_star, num_participants, classical.unwrap_or(false))?)
+}
+
+/// Coverage interval of a consensus value using the Student t quantile for p - 1 degrees of freedom
+/// 
+/// `confidence` defaults to 0.95.
+/// 
+/// # Returns
+/// * Tuple of (lower, upper, coverage factor)
+#[pyfunction]
+fn py_coverage_interval_consensus(
+    x_pt: f64,
+    s_star: f64,
+    num_participants: usize,
+    confidence: Option<f64>,
+) -> PyResult<(f64, f64, f64)> {
+    let interval = coverage_interval_consensus(x_pt, s_star, num_participants, confidence.unwrap_or(0.95))?;
+    Ok((interval.lower, interval.upper, interval.coverage_factor))
+}
+
+/// Expand u(x_pt) for reporting x_pt ± U
+/// 
+/// `k` defaults to 2.
+/// 
+/// # Returns
+/// * Tuple of (x_pt, U, lower, upper)
+#[pyfunction]
+fn py_assigned_value_expanded(x_pt: f64, u_x_pt: f64, k: Option<f64>) -> PyResult<(f64, f64, f64, f64)> {
+    Ok(assigned_value_expanded(x_pt, u_x_pt, k.unwrap_or(2.0))?)
+}
+
+/// Create a coverage interval value ± k·u
+/// 
+/// `coverage_factor` defaults to 2 and `confidence_level` to 0.95.
+#[pyfunction]
+fn py_coverage_interval(
+    value: f64,
+    standard_uncertainty: f64,
+    coverage_factor: Option<f64>,
+    confidence_level: Option<f64>,
+) -> PyResult<CoverageInterval> {
+    Ok(CoverageInterval::new(
+        value,
+        standard_uncertainty,
+        coverage_factor.unwrap_or(2.0),
+        confidence_level.unwrap_or(0.95),
+    )?)
+}
+
+/// Correct a result for recovery, x / R, and combine the relative uncertainties of x and R
+/// 
+/// # Returns
+/// * Tuple of (corrected value, standard uncertainty, relative standard uncertainty as a
+///   fraction, NaN for a zero corrected value)
+#[pyfunction]
+fn py_apply_recovery_correction(
+    value: f64,
+    u_value: f64,
+    recovery: f64,
+    u_recovery: f64,
+) -> PyResult<(f64, f64, f64)> {
+    let corrected = apply_recovery_correction(value, u_value, recovery, u_recovery)?;
+    Ok((corrected.value, corrected.standard_uncertainty, corrected.relative_uncertainty))
+}
+
+/// Calculate uncertainty for CRM values
+/// 
+/// With `budget=True` an `UncertaintyBudget` is returned instead of the bare uncertainty;
+/// with `as_relative=True` a tuple of (u_x_pt, 100 * u_x_pt / |x_pt|) is returned,
+/// which requires `x_pt`.
+#[pyfunction]
+fn py_calculate_uncertainty_crm(
+    py: Python,
+    crm_uncertainty: f64,
+    budget: Option<bool>,
+    as_relative: Option<bool>,
+    x_pt: Option<f64>,
+) -> PyResult<PyObject> {
+    let budget = budget.unwrap_or(false);
+    
+    if budget && !as_relative.unwrap_or(false) {
+        return Ok(crm_uncertainty_budget(crm_uncertainty)?.into_py(py));
+    }
+    
+    let u_x_pt = calculate_uncertainty_crm(crm_uncertainty)?;
+    uncertainty_output(py, u_x_pt, budget, as_relative, x_pt)
+}
+
+/// Calculate uncertainty for formulation values
+/// 
+/// With `budget=True` an `UncertaintyBudget` is returned instead of the bare uncertainty;
+/// with `as_relative=True` a tuple of (u_x_pt, 100 * u_x_pt / |x_pt|) is returned,
+/// which requires `x_pt`.
+#[pyfunction]
+fn py_calculate_uncertainty_formulation(
+    py: Python,
+    formulation_uncertainty: f64,
+    budget: Option<bool>,
+    as_relative: Option<bool>,
+    x_pt: Option<f64>,
+) -> PyResult<PyObject> {
+    let budget = budget.unwrap_or(false);
+    
+    if budget && !as_relative.unwrap_or(false) {
+        return Ok(formulation_uncertainty_budget(formulation_uncertainty)?.into_py(py));
+    }
+    
+    let u_x_pt = calculate_uncertainty_formulation(formulation_uncertainty)?;
+    uncertainty_output(py, u_x_pt, budget, as_relative, x_pt)
+}
+
+/// Propagate input uncertainties with the GUM law of propagation of uncertainty
+/// 
+/// `correlations` is an optional n x n correlation matrix (default uncorrelated).
+/// 
+/// # Returns
+/// * Tuple of (combined standard uncertainty, contribution of each input to its square)
+#[pyfunction]
+fn py_propagate_uncertainty(
+    values: PyReadonlyArray1<f64>,
+    uncertainties: PyReadonlyArray1<f64>,
+    sensitivities: PyReadonlyArray1<f64>,
+    correlations: Option<PyReadonlyArray2<f64>>,
+) -> PyResult<(f64, Vec<f64>)> {
+    let values = values.as_array().to_vec();
+    let uncertainties = uncertainties.as_array().to_vec();
+    let sensitivities = sensitivities.as_array().to_vec();
+    
+    let result = propagate_uncertainty(
+        &values,
+        &uncertainties,
+        &sensitivities,
+        correlations.as_ref().map(|r| r.as_array()),
+    )?;
+    Ok((result.combined, result.contributions))
+}
+
+/// Welch-Satterthwaite effective degrees of freedom of combined standard uncertainty components
+/// 
+/// `dofs` may contain `inf` for components with infinite degrees of freedom.
+/// 
+/// # Returns
+/// * Tuple of (effective dof, possibly inf, coverage factor for 95 % coverage)
+#[pyfunction]
+fn py_effective_degrees_of_freedom(
+    components: PyReadonlyArray1<f64>,
+    dofs: PyReadonlyArray1<f64>,
+) -> PyResult<(f64, f64)> {
+    let components = components.as_array().to_vec();
+    let dofs = dofs.as_array().to_vec();
+    let result = effective_degrees_of_freedom(&components, &dofs)?;
+    Ok((result.dof, result.coverage_factor))
+}
+
+/// Check that two assigned values (e.g. consensus and CRM) agree within their combined uncertainty
+/// 
+/// `limit` defaults to 1, the En convention for expanded uncertainties.
+/// 
+/// # Returns
+/// * Tuple of (|x1 - x2| / sqrt(u1^2 + u2^2), agree)
+#[pyfunction]
+fn py_check_assigned_value_agreement(
+    x1: f64,
+    u1: f64,
+    x2: f64,
+    u2: f64,
+    limit: Option<f64>,
+) -> PyResult<(f64, bool)> {
+    let agreement = check_assigned_value_agreement(x1, u1, x2, u2, limit.unwrap_or(1.0))?;
+    Ok((agreement.ratio, agreement.agree))
+}
+
+/// Combine a reference value and the consensus value by inverse-variance weighting
+/// 
+/// u(x_pt) is inflated by the Birge ratio when the two values disagree.
+/// 
+/// # Returns
+/// * Tuple of (x_pt, u_x_pt, birge_ratio, consistent), or of
+///   (CoverageInterval, birge_ratio, consistent) when `coverage_factor` is given;
+///   `confidence_level` defaults to 0.95
+#[pyfunction]
+fn py_combine_reference_and_consensus(
+    py: Python,
+    x_ref: f64,
+    u_ref: f64,
+    x_cons: f64,
+    u_cons: f64,
+    coverage_factor: Option<f64>,
+    confidence_level: Option<f64>,
+) -> PyResult<PyObject> {
+    let combined = combine_reference_and_consensus(x_ref, u_ref, x_cons, u_cons)?;
+    match coverage_factor {
+        Some(k) => {
+            let interval = combined.coverage_interval(k, confidence_level.unwrap_or(0.95))?;
+            Ok((Py::new(py, interval)?, combined.birge_ratio, combined.consistent).into_py(py))
+        }
+        None => Ok((combined.x_pt, combined.u_x_pt, combined.birge_ratio, combined.consistent).into_py(py)),
+    }
+}
+
+/// Estimate the excess ("dark") uncertainty that makes the reduced chi-square equal 1
+/// 
+/// # Returns
+/// * Tuple of (dark uncertainty, reduced chi-square before, reduced chi-square after,
+///   fraction of total variance, consistent), with zero dark uncertainty and
+///   consistent = True when the stated uncertainties already explain the scatter
+#[pyfunction]
+fn py_dark_uncertainty(
+    results: PyReadonlyArray1<f64>,
+    uncertainties: PyReadonlyArray1<f64>,
+    x_pt: f64,
+) -> PyResult<(f64, f64, f64, f64, bool)> {
+    let dark = dark_uncertainty(results.as_array(), uncertainties.as_array(), x_pt)?;
+    Ok((
+        dark.dark_uncertainty,
+        dark.reduced_chi_square_before,
+        dark.reduced_chi_square_after,
+        dark.fraction_of_variance,
+        dark.consistent,
+    ))
+}
+
+/// Check whether u(x_pt) <= 0.3·sigma_pt so plain z-scores can be used
+/// 
+/// # Returns
+/// * Tuple of (ratio u(x_pt) / sigma_pt, passed, recommendation), with the
+///   recommendation "use_z", "use_z_prime" or "review_sigma_pt"
+#[pyfunction]
+fn py_check_uncertainty_criterion(u_x_pt: f64, sigma_pt: f64) -> PyResult<(f64, bool, &'static str)> {
+    let criterion = check_uncertainty_criterion(u_x_pt, sigma_pt)?;
+    Ok((criterion.ratio, criterion.passed, criterion.recommendation.as_str()))
+}
+
+/// Flag participant-stated uncertainties that are implausibly small or large relative to s*
+/// 
+/// `low_factor` and `high_factor` default to 0.1 and 5; NaN marks a missing uncertainty.
+/// 
+/// # Returns
+/// * Tuple of (flags, counts), where each flag is "ok", "too_small", "too_large"
+///   or "missing" and counts maps each flag to its number of participants
+#[pyfunction]
+fn py_screen_participant_uncertainties<'py>(
+    py: Python<'py>,
+    u_results: PyReadonlyArray1<f64>,
+    s_star: f64,
+    low_factor: Option<f64>,
+    high_factor: Option<f64>,
+) -> PyResult<(Vec<&'static str>, &'py PyDict)> {
+    let screening = screen_participant_uncertainties(
+        u_results.as_array(),
+        s_star,
+        low_factor.unwrap_or(utils::constants::DEFAULT_UNCERTAINTY_LOW_FACTOR),
+        high_factor.unwrap_or(utils::constants::DEFAULT_UNCERTAINTY_HIGH_FACTOR),
+    )?;
+    
+    let counts = PyDict::new(py);
+    counts.set_item("ok", screening.n_ok)?;
+    counts.set_item("too_small", screening.n_too_small)?;
+    counts.set_item("too_large", screening.n_too_large)?;
+    counts.set_item("missing", screening.n_missing)?;
+    
+    Ok((screening.flags.iter().map(|flag| flag.as_str()).collect(), counts))
+}
+
+/// Create an empty uncertainty budget
+/// 
+/// Add components with `add_component(name, value, type_)` where `type_` is "A" or "B".
+#[pyfunction]
+fn py_uncertainty_budget() -> UncertaintyBudget {
+    UncertaintyBudget::new()
+}
+
+/// Calculate the homogeneity uncertainty u_hom = s_s from homogeneity ANOVA output
+/// 
+/// # Returns
+/// * Tuple of (u_hom, between-sample variance estimate, negative_variance), with
+///   u_hom = 0 when the variance estimate is negative
+#[pyfunction]
+fn py_uncertainty_from_homogeneity(
+    between_sample_sd: f64,
+    within_sample_sd: f64,
+    replicates_per_sample: usize,
+) -> PyResult<(f64, f64, bool)> {
+    let result = uncertainty_from_homogeneity(between_sample_sd, within_sample_sd, replicates_per_sample)?;
+    Ok((result.u_hom, result.between_sample_variance, result.negative_variance))
+}
+
+/// Calculate uncertainty for expert consensus values
+#[pyfunction]
+fn py_calculate_uncertainty_expert(expert_uncertainty: f64) -> PyResult<f64> {
+    match calculate_uncertainty_expert(expert_uncertainty) {
+        Ok(result) => Ok(result),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Calculate robust and classical uncertainties from multiple expert results
+/// 
+/// `mad_consistency` scales the MAD to the MADe (default 1.4826, for normal data).
+/// 
+/// # Returns
+/// * Tuple of (robust 1.25 * MADe / sqrt(p), classical standard error, sufficient),
+///   where sufficient is false for a single expert and both uncertainties are then 0
+#[pyfunction]
+fn py_calculate_uncertainty_expert_from_results(
+    expert_results: PyReadonlyArray1<f64>,
+    mad_consistency: Option<f64>,
+) -> PyResult<(f64, f64, bool)> {
+    let expert_results = expert_results.as_array().to_vec();
+    let mad_consistency = mad_consistency.unwrap_or(utils::constants::MAD_TO_SIGMA);
+    let result = calculate_uncertainty_expert_robust(&expert_results, mad_consistency)?;
+    Ok((result.robust, result.classical, result.sufficient))
+}
+
+/// Calculate the inverse-variance weighted expert consensus value
+/// 
+/// # Returns
+/// * Tuple of (weighted consensus value, its standard uncertainty)
+#[pyfunction]
+fn py_calculate_expert_consensus_weighted(
+    expert_results: PyReadonlyArray1<f64>,
+    expert_uncertainties: PyReadonlyArray1<f64>,
+) -> PyResult<(f64, f64)> {
+    let expert_results = expert_results.as_array().to_vec();
+    let expert_uncertainties = expert_uncertainties.as_array().to_vec();
+    Ok(calculate_expert_consensus_weighted(&expert_results, &expert_uncertainties)?)
+}
+
+/// Combine named standard uncertainty components by root-sum-of-squares
+/// 
+/// `components` is a dict of name to standard uncertainty.
+/// 
+/// # Returns
+/// * Tuple of (combined uncertainty, dict of name to percentage of the combined variance)
+#[pyfunction]
+fn py_combine_uncertainty_components(
+    py: Python,
+    components: &PyDict,
+) -> PyResult<(f64, PyObject)> {
+    let components = components.iter()
+        .map(|(name, value)| Ok((name.extract::<String>()?, value.extract::<f64>()?)))
+        .collect::<PyResult<Vec<(String, f64)>>>()?;
+    
+    let (combined, contributions) = combine_named_uncertainty_components(&components)?;
+    
+    let dict = PyDict::new(py);
+    for (name, percent) in contributions {
+        dict.set_item(name, percent)?;
+    }
+    Ok((combined, dict.into()))
+}
+
+/// Calculate the jackknife uncertainty of the Algorithm A assigned value
+/// 
+/// # Returns
+/// * Tuple of (jackknife standard error, analytic u(x_pt))
+#[pyfunction]
+fn py_jackknife_uncertainty(results: PyReadonlyArray1<f64>) -> PyResult<(f64, f64)> {
+    match jackknife_uncertainty(results.as_array()) {
+        Ok(result) => Ok((result.jackknife, result.analytic)),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Calculate the bootstrap uncertainty of the Algorithm A assigned value
+/// 
+/// `seed` (default 0) makes the resampling reproducible.
+/// 
+/// # Returns
+/// * Tuple of (bootstrap standard deviation of x_pt, analytic u(x_pt), ratio bootstrap / analytic)
+#[pyfunction]
+fn py_bootstrap_uncertainty_consensus(
+    results: PyReadonlyArray1<f64>,
+    n_resamples: usize,
+    seed: Option<u64>,
+) -> PyResult<(f64, f64, f64)> {
+    let result = bootstrap_uncertainty_consensus(results.as_array(), n_resamples, seed.unwrap_or(0))?;
+    Ok((result.bootstrap, result.analytic, result.ratio))
+}
+
+/// Propagate input uncertainties through a Python model by Monte Carlo sampling
+/// 
+/// `inputs` is a list of (estimate, standard uncertainty) tuples. `model` is
+/// called on batches of draws with one NumPy array per input, e.g.
+/// `model(x1, x2)`, and must return an array with one output per draw.
+/// `seed` (default 0) makes the sampling reproducible.
+/// 
+/// # Returns
+/// * Tuple of (mean, standard deviation, lower and upper end of the 95 % coverage interval)
+#[pyfunction]
+fn py_monte_carlo_uncertainty(
+    py: Python,
+    inputs: Vec<(f64, f64)>,
+    model: &PyAny,
+    n_draws: usize,
+    seed: Option<u64>,
+) -> PyResult<(f64, f64, f64, f64)> {
+    if !model.is_callable() {
+        return Err(pyo3::exceptions::PyTypeError::new_err("model must be callable"));
+    }
+    
+    let evaluate = |draws: &[Array1<f64>]| -> PyResult<Array1<f64>> {
+        let arrays: Vec<&PyArray1<f64>> = draws.iter()
+            .map(|draw| PyArray1::from_array(py, draw))
+            .collect();
+        let output = model.call1(PyTuple::new(py, arrays))?;
+        let output: PyReadonlyArray1<f64> = output.extract()?;
+        Ok(output.as_array().to_owned())
+    };
+    
+    let result = monte_carlo_uncertainty(&inputs, evaluate, n_draws, seed.unwrap_or(0))?;
+    Ok((result.mean, result.std_dev, result.lower, result.upper))
+}
+
+/// Check an expert-judgment sigma_pt against its documented range and return it
+#[pyfunction]
+fn py_sigma_pt_by_perception(value: f64, lower_bound: f64, upper_bound: f64) -> PyResult<f64> {
+    Ok(sigma_pt_by_perception(value, lower_bound, upper_bound)?)
+}
+
+/// Derive sigma_pt from a data-based scale with a minimum floor
+/// 
+/// # Returns
+/// * Tuple of (sigma_pt, floor_applied)
+#[pyfunction]
+fn py_sigma_pt_with_floor(data_scale: f64, floor: f64) -> PyResult<(f64, bool)> {
+    match sigma_pt_with_floor(data_scale, floor) {
+        Ok(result) => Ok((result.sigma_pt, result.floor_applied)),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Apply the negative result policy named by a Python argument
+/// 
+/// Returns `None` for the default `allow` policy so the input can be scored
+/// without copying.
+fn results_for_negative_policy(
+    results: ArrayView1<f64>,
+    negative_policy: Option<&str>,
+) -> PyResult<Option<Array1<f64>>> {
+    let policy = match negative_policy {
+        Some(policy) => policy.parse::<NegativePolicy>()?,
+        None => NegativePolicy::default(),
+    };
+    
+    match policy {
+        NegativePolicy::Allow => Ok(None),
+        policy => Ok(Some(apply_negative_policy(results, policy)?.0)),
+    }
+}
+
+/// Parse an optional dtype argument, defaulting to float64
+fn parse_score_dtype(dtype: Option<&str>) -> PyResult<ScoreDtype> {
+    Ok(dtype.map(str::parse).transpose()?.unwrap_or_default())
+}
+
+/// Convert scores computed in f64 to a NumPy array of the requested dtype
+/// 
+/// Scores are always computed in f64 and only cast here, so float32 halves the
+/// size of large score arrays at the cost of rounding to about 7 significant
+/// digits (a relative error below 6e-8), far finer than scores are reported.
+/// NaN scores stay NaN.
+fn scores_to_py(py: Python, scores: &Array1<f64>, dtype: ScoreDtype) -> PyObject {
+    match dtype {
+        ScoreDtype::Float64 => PyArray1::from_array(py, scores).into_py(py),
+        ScoreDtype::Float32 => PyArray1::from_iter(py, scores.iter().map(|&score| score as f32)).into_py(py),
+    }
+}
+
+/// Calculate z-scores for participant performance
+/// 
+/// `dtype` is "float64" (default) or "float32"; with float32 the scores are
+/// returned as float32 to halve the transfer size of large panels, though they
+/// are still computed in f64. The grouped, zeta and no-uncertainty score
+/// functions accept the same `dtype`.
+#[pyfunction]
+fn py_calculate_z_scores(
+    py: Python,
+    results: PyReadonlyArray1<f64>,
+    x_pt: f64,
+    sigma_pt: f64,
+    negative_policy: Option<&str>,
+    dtype: Option<&str>,
+) -> PyResult<PyObject> {
+    let dtype = parse_score_dtype(dtype)?;
+    let adjusted = results_for_negative_policy(results.as_array(), negative_policy)?;
+    let results_array = adjusted.as_ref().map_or(results.as_array(), |a| a.view());
+    
+    let z_scores = py.allow_threads(|| calculate_z_scores(results_array, x_pt, sigma_pt))?;
+    Ok(scores_to_py(py, &z_scores, dtype))
+}
+
+/// Calculate z-scores with different sigma_pt below and above the assigned value
+#[pyfunction]
+fn py_calculate_z_scores_asymmetric(
+    py: Python,
+    results: PyReadonlyArray1<f64>,
+    x_pt: f64,
+    sigma_below: f64,
+    sigma_above: f64,
+) -> PyResult<Py<PyArray1<f64>>> {
+    match calculate_z_scores_asymmetric(results.as_array(), x_pt, sigma_below, sigma_above) {
+        Ok(z_scores) => Ok(PyArray1::from_array(py, &z_scores).to_owned()),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Calculate z-scores with a sigma_pt for each participant group
+/// 
+/// `group_ids` is an integer label array aligned with `results` and
+/// `sigma_by_group` a dict mapping every label to its sigma_pt.
+#[pyfunction]
+fn py_calculate_z_scores_grouped(
+    py: Python,
+    results: PyReadonlyArray1<f64>,
+    group_ids: PyReadonlyArray1<i64>,
+    x_pt: f64,
+    sigma_by_group: HashMap<i64, f64>,
+    dtype: Option<&str>,
+) -> PyResult<PyObject> {
+    let dtype = parse_score_dtype(dtype)?;
+    let z_scores = calculate_z_scores_grouped(results.as_array(), group_ids.as_array(), x_pt, &sigma_by_group)?;
+    Ok(scores_to_py(py, &z_scores, dtype))
+}
+
+/// Calculate z-scores for an assigned value quoted as the interval [lower, upper]
+/// 
+/// Uses the midpoint as x_pt and (upper - lower) / (2√3) as u(x_pt), and
+/// returns z-scores with the denominator sqrt(sigma_pt² + u(x_pt)²).
+#[pyfunction]
+fn py_calculate_z_scores_from_interval(
+    py: Python,
+    results: PyReadonlyArray1<f64>,
+    lower: f64,
+    upper: f64,
+    sigma_pt: f64,
+) -> PyResult<Py<PyArray1<f64>>> {
+    let z_scores = calculate_z_scores_from_interval(results.as_array(), lower, upper, sigma_pt)?;
+    Ok(PyArray1::from_array(py, &z_scores).to_owned())
+}
+
+/// Python return type of z-scores with a specification check
+type SpecZScoresTuple = (Py<PyArray1<f64>>, Py<PyArray1<bool>>);
+
+/// Calculate z-scores and flag results within |x_i - x_pt| <= spec_tolerance
+/// 
+/// # Returns
+/// * Tuple of (z-scores, within-specification flags)
+#[pyfunction]
+fn py_calculate_z_scores_with_spec(
+    py: Python,
+    results: PyReadonlyArray1<f64>,
+    x_pt: f64,
+    sigma_pt: f64,
+    spec_tolerance: f64,
+) -> PyResult<SpecZScoresTuple> {
+    let (z_scores, within_spec) = calculate_z_scores_with_spec(results.as_array(), x_pt, sigma_pt, spec_tolerance)?;
+    Ok((
+        PyArray1::from_array(py, &z_scores).to_owned(),
+        PyArray1::from_array(py, &within_spec).to_owned(),
+    ))
+}
+
+/// Python return type of z-scores with trimmed x_pt and sigma_pt
+type TrimmedZScoresTuple = (f64, f64, Py<PyArray1<f64>>);
+
+/// Calculate z-scores against the trimmed mean and scaled trimmed SD of the results
+/// 
+/// `trim_fraction` is the fraction removed from each end, in [0, 0.5).
+/// 
+/// # Returns
+/// * Tuple of (x_pt, sigma_pt, z-scores)
+#[pyfunction]
+fn py_calculate_z_scores_trimmed(
+    py: Python,
+    results: PyReadonlyArray1<f64>,
+    trim_fraction: f64,
+) -> PyResult<TrimmedZScoresTuple> {
+    let trimmed = calculate_z_scores_trimmed(results.as_array(), trim_fraction)?;
+    Ok((trimmed.x_pt, trimmed.sigma_pt, PyArray1::from_array(py, &trimmed.z_scores).to_owned()))
+}
+
+/// Estimate a participant's systematic bias from its deviations over several rounds (NaN = missed round)
+/// 
+/// # Returns
+/// * Tuple of (mean deviation, standard error, t-statistic, two-sided p-value, rounds used)
+#[pyfunction]
+fn py_estimate_systematic_bias(deviations: PyReadonlyArray1<f64>) -> PyResult<(f64, f64, f64, f64, usize)> {
+    let bias = estimate_systematic_bias(deviations.as_array())?;
+    Ok((bias.mean, bias.standard_error, bias.t_statistic, bias.p_value, bias.n_rounds))
+}
+
+/// Python return type of one candidate in the assigned value comparison
+type AssignedValueComparisonTuple = (String, Py<PyArray1<f64>>, usize);
+
+/// Score the same results against several candidate assigned values
+/// 
+/// `candidates` is a list of (name, x_pt, u_x_pt) tuples, where u_x_pt is the
+/// standard uncertainty of the candidate value (all candidates share `sigma_pt`).
+/// A non-zero u_x_pt gives ISO 13528 z'-scores (x_i - x_pt) / sqrt(sigma_pt^2 + u_x_pt^2),
+/// not zeta-scores; u_x_pt = 0 gives plain z-scores.
+/// 
+/// # Returns
+/// * List of (name, scores, number of unsatisfactory scores) in candidate order
+#[pyfunction]
+fn py_compare_assigned_values(
+    py: Python,
+    results: PyReadonlyArray1<f64>,
+    candidates: Vec<(String, f64, f64)>,
+    sigma_pt: f64,
+) -> PyResult<Vec<AssignedValueComparisonTuple>> {
+    let comparisons = compare_assigned_values(results.as_array(), &candidates, sigma_pt)?;
+    Ok(comparisons.into_iter()
+        .map(|comparison| {
+            let z_scores = PyArray1::from_array(py, &comparison.z_scores).to_owned();
+            (comparison.name, z_scores, comparison.n_unsatisfactory)
+        })
+        .collect())
+}
+
+/// Calculate z-scores on the log10 scale
+#[pyfunction]
+fn py_calculate_log_z_scores(
+    py: Python,
+    results: PyReadonlyArray1<f64>,
+    x_pt: f64,
+    sigma_log: f64,
+) -> PyResult<Py<PyArray1<f64>>> {
+    match calculate_log_z_scores(results.as_array(), x_pt, sigma_log) {
+        Ok(z_scores) => Ok(PyArray1::from_array(py, &z_scores).to_owned()),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Convert a log10-scale sigma into multiplicative acceptance limits
+/// 
+/// # Returns
+/// * Tuple of (lower factor, upper factor) to multiply x_pt by
+#[pyfunction]
+fn py_log_sigma_acceptance_factors(sigma_log: f64, z_limit: Option<f64>) -> PyResult<(f64, f64)> {
+    match log_sigma_acceptance_factors(sigma_log, z_limit.unwrap_or(2.0)) {
+        Ok(factors) => Ok(factors),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Calculate zeta-scores (z'-scores) for participant performance
+/// 
+/// Participants with zero combined uncertainty get NaN. With `return_mask=True`
+/// a tuple of (scores, mask) is returned, where the boolean mask marks those
+/// participants.
+#[pyfunction]
+#[allow(clippy::too_many_arguments)]
+fn py_calculate_z_prime_scores(
+    py: Python,
+    results: PyReadonlyArray1<f64>,
+    u_results: PyReadonlyArray1<f64>,
+    x_pt: f64,
+    u_x_pt: f64,
+    negative_policy: Option<&str>,
+    return_mask: Option<bool>,
+    dtype: Option<&str>,
+) -> PyResult<PyObject> {
+    let dtype = parse_score_dtype(dtype)?;
+    let adjusted = results_for_negative_policy(results.as_array(), negative_policy)?;
+    let results_array = adjusted.as_ref().map_or(results.as_array(), |a| a.view());
+    let u_results_array = u_results.as_array();
+    
+    let (z_prime_scores, zero_uncertainty) = py.allow_threads(|| {
+        calculate_z_prime_scores_masked(results_array, u_results_array, x_pt, u_x_pt)
+    })?;
+    let z_prime_scores = scores_to_py(py, &z_prime_scores, dtype);
+    
+    if return_mask.unwrap_or(false) {
+        Ok((z_prime_scores, PyArray1::from_array(py, &zero_uncertainty)).into_py(py))
+    } else {
+        Ok(z_prime_scores)
+    }
+}
+
+/// Check that an output array can be written in place by the `_into` functions
+fn writable_output<'py>(out: &'py PyArray1<f64>) -> PyResult<numpy::PyReadwriteArray1<'py, f64>> {
+    if !out.is_contiguous() {
+        return Err(pyo3::exceptions::PyValueError::new_err("Output array must be contiguous"));
+    }
+    
+    out.try_readwrite()
+        .map_err(|e| pyo3::exceptions::PyValueError::new_err(format!("Output array is not writable: {}", e)))
+}
+
+/// Calculate z-scores into a preallocated float64 array
+/// 
+/// `out` must be a writable, contiguous float64 array with the same length as
+/// `results`. Returns None.
+#[pyfunction]
+fn py_calculate_z_scores_into(
+    py: Python,
+    results: PyReadonlyArray1<f64>,
+    x_pt: f64,
+    sigma_pt: f64,
+    out: &PyArray1<f64>,
+) -> PyResult<()> {
+    let mut out = writable_output(out)?;
+    let results_array = results.as_array();
+    let mut out_array = out.as_array_mut();
+    
+    match py.allow_threads(|| calculate_z_scores_into(results_array, x_pt, sigma_pt, &mut out_array)) {
+        Ok(()) => Ok(()),
         Err(e) => Err(e.into()),
     }
 }
 
-/// Calculate assigned value from CRM
+/// Calculate zeta-scores into a preallocated float64 array
+#[pyfunction]
+fn py_calculate_z_prime_scores_into(
+    py: Python,
+    results: PyReadonlyArray1<f64>,
+    u_results: PyReadonlyArray1<f64>,
+    x_pt: f64,
+    u_x_pt: f64,
+    out: &PyArray1<f64>,
+) -> PyResult<()> {
+    let mut out = writable_output(out)?;
+    let results_array = results.as_array();
+    let u_results_array = u_results.as_array();
+    let mut out_array = out.as_array_mut();
+    
+    match py.allow_threads(|| {
+        calculate_z_prime_scores_into(results_array, u_results_array, x_pt, u_x_pt, &mut out_array)
+    }) {
+        Ok(()) => Ok(()),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Calculate zeta-scores from participant uncertainties given in percent of each result
+#[pyfunction]
+fn py_calculate_z_prime_scores_relative(
+    py: Python,
+    results: PyReadonlyArray1<f64>,
+    u_results_percent: PyReadonlyArray1<f64>,
+    x_pt: f64,
+    u_x_pt: f64,
+) -> PyResult<Py<PyArray1<f64>>> {
+    let results_array = results.as_array();
+    let u_percent_array = u_results_percent.as_array();
+    
+    match calculate_z_prime_scores_relative(results_array, u_percent_array, x_pt, u_x_pt) {
+        Ok(z_prime_scores) => Ok(PyArray1::from_array(py, &z_prime_scores).to_owned()),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Calculate zeta-scores when participant uncertainties are not available
+#[pyfunction]
+fn py_calculate_z_prime_scores_no_uncertainties(
+    py: Python,
+    results: PyReadonlyArray1<f64>,
+    x_pt: f64,
+    u_x_pt: f64,
+    dtype: Option<&str>,
+) -> PyResult<PyObject> {
+    let dtype = parse_score_dtype(dtype)?;
+    let results_array = results.as_array();
+    
+    let z_prime_scores = calculate_z_prime_scores_no_participant_uncertainties(results_array, x_pt, u_x_pt)?;
+    Ok(scores_to_py(py, &z_prime_scores, dtype))
+}
+
+/// Calculate robust standardized residuals (x_i - x*) / s* for Algorithm A diagnostics
+#[pyfunction]
+fn py_standardized_residuals(
+    py: Python,
+    results: PyReadonlyArray1<f64>,
+    x_pt: f64,
+    s_star: f64,
+) -> PyResult<Py<PyArray1<f64>>> {
+    let results_array = results.as_array();
+    
+    match standardized_residuals(results_array, x_pt, s_star) {
+        Ok(residuals) => Ok(PyArray1::from_array(py, &residuals).to_owned()),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Round scores to a reporting precision and interpret the rounded z-scores
+/// 
+/// `mode` is either "half_even" (default) or "half_up".
+/// 
+/// # Returns
+/// * Tuple of (rounded scores, interpretations of the rounded scores)
+#[pyfunction]
+fn py_round_scores(
+    py: Python,
+    scores: PyReadonlyArray1<f64>,
+    decimals: u32,
+    mode: Option<&str>,
+) -> PyResult<(Py<PyArray1<f64>>, Vec<String>)> {
+    let mode = match mode {
+        Some(mode) => mode.parse::<RoundingMode>()?,
+        None => RoundingMode::default(),
+    };
+    
+    let rounded = round_scores(scores.as_array(), decimals, mode)?;
+    let interpretations = rounded.iter()
+        .map(|&score| interpret_z_score(score))
+        .collect();
+    Ok((PyArray1::from_array(py, &rounded).to_owned(), interpretations))
+}
+
+/// Rank participants by |score| (0 = smallest), with ties in input order and NaN last
+#[pyfunction]
+fn py_rank_by_abs_score(py: Python, scores: PyReadonlyArray1<f64>) -> Py<PyArray1<usize>> {
+    PyArray1::from_array(py, &rank_by_abs_score(scores.as_array())).to_owned()
+}
+
+/// Summarise the distribution of a round's z-scores, skipping NaN scores
+/// 
+/// # Returns
+/// * Dict with keys n, mean, sd, fraction_above_2 and fraction_above_3
+#[pyfunction]
+fn py_z_score_diagnostics(py: Python, z_scores: PyReadonlyArray1<f64>) -> PyResult<PyObject> {
+    let diagnostics = z_score_diagnostics(z_scores.as_array())?;
+    
+    let dict = PyDict::new(py);
+    dict.set_item("n", diagnostics.n)?;
+    dict.set_item("mean", diagnostics.mean)?;
+    dict.set_item("sd", diagnostics.sd)?;
+    dict.set_item("fraction_above_2", diagnostics.fraction_above_2)?;
+    dict.set_item("fraction_above_3", diagnostics.fraction_above_3)?;
+    Ok(dict.into())
+}
+
+/// Proportion of scores with |z| > `threshold` (default 3) and its Wilson interval
+/// 
+/// NaN scores are skipped. `confidence` defaults to 0.95.
+/// 
+/// # Returns
+/// * Tuple of (rate, lower bound, upper bound, number of scores counted)
 #[pyfunction]
-fn py_calculate_from_crm(crm_value: f64) -> PyResult<f64> {
-    match calculate_from_crm(crm_value) {
+fn py_unsatisfactory_rate(
+    z_scores: PyReadonlyArray1<f64>,
+    threshold: Option<f64>,
+    confidence: Option<f64>,
+) -> PyResult<(f64, f64, f64, usize)> {
+    let threshold = threshold.unwrap_or(ScoreLimits::default().action);
+    let result = unsatisfactory_rate(z_scores.as_array(), threshold, confidence.unwrap_or(0.95))?;
+    Ok((result.rate, result.lower, result.upper, result.n))
+}
+
+/// Interpret a zeta-score with configurable limits
+/// 
+/// Defaults to the two-level interpretation (|zeta| <= 2 satisfactory); pass
+/// `action` greater than `warning` for the three-level warning/action structure.
+#[pyfunction]
+fn py_interpret_zeta_score(
+    z_prime_score: f64,
+    warning: Option<f64>,
+    action: Option<f64>,
+) -> PyResult<String> {
+    let warning = warning.unwrap_or(2.0);
+    let action = action.unwrap_or(warning);
+    
+    match interpret_zeta_score_with_limits(z_prime_score, warning, action) {
         Ok(result) => Ok(result),
         Err(e) => Err(e.into()),
     }
 }
 
-/// Calculate assigned value from formulation
+/// Interpret an array of zeta-scores with configurable limits
 #[pyfunction]
-fn py_calculate_from_formulation(formulation_value: f64) -> PyResult<f64> {
-    match calculate_from_formulation(formulation_value) {
+fn py_interpret_zeta_scores(
+    z_prime_scores: PyReadonlyArray1<f64>,
+    warning: Option<f64>,
+    action: Option<f64>,
+) -> PyResult<Vec<String>> {
+    let warning = warning.unwrap_or(2.0);
+    let action = action.unwrap_or(warning);
+    
+    match interpret_zeta_scores_with_limits(z_prime_scores.as_array(), warning, action) {
         Ok(result) => Ok(result),
         Err(e) => Err(e.into()),
     }
 }
 
-/// Calculate assigned value from expert consensus
+/// Jointly interpret z-scores and zeta-scores
+/// 
+/// # Returns
+/// * Tuple of (matrix cell codes, advisory strings); code -1 means not evaluated
 #[pyfunction]
-fn py_calculate_from_expert_consensus(expert_value: f64) -> PyResult<f64> {
-    match calculate_from_expert_consensus(expert_value) {
-        Ok(result) => Ok(result),
-        Err(e) => Err(e.into()),
+fn py_joint_interpretation(
+    py: Python,
+    z_scores: PyReadonlyArray1<f64>,
+    zeta_scores: PyReadonlyArray1<f64>,
+) -> PyResult<(Py<PyArray1<i64>>, Vec<&'static str>)> {
+    let joint = joint_interpretation(z_scores.as_array(), zeta_scores.as_array())?;
+    
+    let codes: Vec<i64> = joint.iter().map(|j| j.code()).collect();
+    let advisories = joint.iter().map(|j| j.advisory()).collect();
+    Ok((PyArray1::from_vec(py, codes).to_owned(), advisories))
+}
+
+/// Flag participants whose zeta-score and En number disagree beyond `tolerance`
+/// 
+/// # Returns
+/// * Tuple of (flagged indices, discrepancies zeta - k·En)
+#[pyfunction]
+fn py_cross_check_scores(
+    zeta: PyReadonlyArray1<f64>,
+    en: PyReadonlyArray1<f64>,
+    k: f64,
+    tolerance: Option<f64>,
+) -> PyResult<(Vec<usize>, Vec<f64>)> {
+    let tolerance = tolerance.unwrap_or(utils::constants::DEFAULT_CROSS_CHECK_TOLERANCE);
+    let flagged = cross_check_scores(zeta.as_array(), en.as_array(), k, tolerance)?;
+    
+    Ok(flagged.iter().map(|d| (d.index, d.discrepancy)).unzip())
+}
+
+/// Calculate the propagated standard uncertainty of each z-score
+/// 
+/// NaN entries in `u_results` are missing values; they raise an error unless
+/// `missing_as_zero` is true, in which case they are treated as zero.
+#[pyfunction]
+#[allow(clippy::too_many_arguments)]
+fn py_z_score_uncertainties(
+    py: Python,
+    results: PyReadonlyArray1<f64>,
+    u_results: PyReadonlyArray1<f64>,
+    x_pt: f64,
+    u_x_pt: f64,
+    sigma_pt: f64,
+    u_sigma_pt: Option<f64>,
+    missing_as_zero: Option<bool>,
+) -> PyResult<Py<PyArray1<f64>>> {
+    let u_z = z_score_uncertainties(
+        results.as_array(),
+        u_results.as_array(),
+        x_pt,
+        u_x_pt,
+        sigma_pt,
+        u_sigma_pt,
+        missing_as_zero.unwrap_or(false),
+    )?;
+    Ok(PyArray1::from_array(py, &u_z).to_owned())
+}
+
+/// Python return type of the boundary sensitivity check
+type BoundarySensitivityTuple = (Py<PyArray1<bool>>, Py<PyArray1<f64>>, Py<PyArray1<f64>>);
+
+/// Flag scores whose classification could flip within x_pt ± 2·u(x_pt)
+/// 
+/// # Returns
+/// * Tuple of (flags, interval lower ends, interval upper ends)
+#[pyfunction]
+fn py_boundary_sensitivity(
+    py: Python,
+    z_scores: PyReadonlyArray1<f64>,
+    u_x_pt: f64,
+    sigma_pt: f64,
+    warning: Option<f64>,
+    action: Option<f64>,
+) -> PyResult<BoundarySensitivityTuple> {
+    let defaults = ScoreLimits::default();
+    let limits = ScoreLimits::new(
+        warning.unwrap_or(defaults.warning),
+        action.unwrap_or(defaults.action),
+    )?;
+    
+    let sensitivity = boundary_sensitivity(z_scores.as_array(), u_x_pt, sigma_pt, &limits)?;
+    
+    let flags: Vec<bool> = sensitivity.iter().map(|s| s.flagged).collect();
+    let z_lower: Vec<f64> = sensitivity.iter().map(|s| s.z_lower).collect();
+    let z_upper: Vec<f64> = sensitivity.iter().map(|s| s.z_upper).collect();
+    Ok((
+        PyArray1::from_vec(py, flags).to_owned(),
+        PyArray1::from_vec(py, z_lower).to_owned(),
+        PyArray1::from_vec(py, z_upper).to_owned(),
+    ))
+}
+
+/// Build a ScoreSet of z-scores (and optionally zeta-scores) keyed by participant ID
+/// 
+/// `participant_ids` is an optional list of str or int identifiers matching the
+/// length of `results`. Zeta-scores are included when both `u_results` and
+/// `u_x_pt` are supplied.
+#[pyfunction]
+fn py_score_set(
+    results: PyReadonlyArray1<f64>,
+    x_pt: f64,
+    sigma_pt: f64,
+    participant_ids: Option<&PyList>,
+    u_results: Option<PyReadonlyArray1<f64>>,
+    u_x_pt: Option<f64>,
+) -> PyResult<ScoreSet> {
+    let mut score_set = ScoreSet::new(results.as_array(), x_pt, sigma_pt)?;
+    
+    if let (Some(u_results), Some(u_x_pt)) = (u_results, u_x_pt) {
+        score_set = score_set.with_zeta_scores(u_results.as_array(), u_x_pt)?;
+    }
+    
+    if let Some(ids) = participant_ids {
+        let ids = ids.iter()
+            .map(extract_participant_id)
+            .collect::<PyResult<Vec<String>>>()?;
+        score_set = score_set.with_participant_ids(ids)?;
     }
+    
+    Ok(score_set)
 }
 
-/// Calculate uncertainty for consensus values (Algorithm A results)
+/// Create an empty RoundData builder
+/// 
+/// Add participants with `add_participant(id, value, uncertainty=None)`, then
+/// pass it to `py_score_round_data` or call `finalize()` for aligned arrays.
 #[pyfunction]
-fn py_calculate_uncertainty_consensus(
-    robust_std_dev: f64,
-    num_participants: usize,
-) -> PyResult<f64> {
-    match calculate_uncertainty_consensus(robust_std_dev, num_participants) {
-        Ok(result) => Ok(result),
+fn py_round_data() -> RoundData {
+    RoundData::new()
+}
+
+/// Build a ScoreSet from a RoundData builder
+/// 
+/// Zeta-scores are included when `u_x_pt` is supplied, which requires every
+/// participant to have reported an uncertainty.
+#[pyfunction]
+fn py_score_round_data(
+    round: PyRef<RoundData>,
+    x_pt: f64,
+    sigma_pt: f64,
+    u_x_pt: Option<f64>,
+) -> PyResult<ScoreSet> {
+    Ok(ScoreSet::from_round_data(&round, x_pt, sigma_pt, u_x_pt)?)
+}
+
+/// Calculate z-scores for the participants of a RoundData builder, in insertion order
+#[pyfunction]
+fn py_calculate_z_scores_round(
+    py: Python,
+    round: PyRef<RoundData>,
+    x_pt: f64,
+    sigma_pt: f64,
+) -> PyResult<Py<PyArray1<f64>>> {
+    let z_scores = calculate_z_scores_for_round(&round, x_pt, sigma_pt)?;
+    Ok(PyArray1::from_owned_array(py, z_scores).to_owned())
+}
+
+/// Calculate zeta-scores for the participants of a RoundData builder, in insertion order
+/// 
+/// Every participant must have reported an uncertainty.
+#[pyfunction]
+fn py_calculate_z_prime_scores_round(
+    py: Python,
+    round: PyRef<RoundData>,
+    x_pt: f64,
+    u_x_pt: f64,
+) -> PyResult<Py<PyArray1<f64>>> {
+    let z_prime_scores = calculate_z_prime_scores_for_round(&round, x_pt, u_x_pt)?;
+    Ok(PyArray1::from_owned_array(py, z_prime_scores).to_owned())
+}
+
+/// Calculate all per-participant diagnostics of a RoundData builder
+/// 
+/// Zeta-scores need `u_x_pt` and participant uncertainties, En numbers also need
+/// `coverage_factor`, and standardized residuals and weights need `s_star`;
+/// otherwise those attributes are None.
+/// 
+/// # Returns
+/// * List of ParticipantDiagnostics with attributes participant_id, value,
+///   uncertainty, z_score, zeta_score, en_number, standardized_residual, weight and label
+#[pyfunction]
+fn py_full_diagnostics(
+    round: PyRef<RoundData>,
+    x_pt: f64,
+    sigma_pt: f64,
+    u_x_pt: Option<f64>,
+    s_star: Option<f64>,
+    coverage_factor: Option<f64>,
+) -> PyResult<Vec<ParticipantDiagnostics>> {
+    let mut assigned = AssignedValue::new(x_pt)?;
+    if let Some(u_x_pt) = u_x_pt {
+        assigned = assigned.with_uncertainty(u_x_pt)?;
+    }
+    if let Some(s_star) = s_star {
+        assigned = assigned.with_s_star(s_star)?;
+    }
+    if let Some(k) = coverage_factor {
+        assigned = assigned.with_coverage_factor(k)?;
+    }
+    
+    Ok(full_diagnostics(&round, &assigned, sigma_pt)?)
+}
+
+/// Score participants overall and within their method group
+/// 
+/// `group_ids` is an integer label array aligned with `results`. `per_group` is an
+/// optional dict mapping each label to an (x_pt, sigma_pt) tuple; without it each
+/// group is scored against its own Algorithm A consensus.
+/// 
+/// # Returns
+/// * Dict with keys overall_z, group_z and groups (list of dicts with keys group,
+///   n, x_pt, sigma_pt, evaluated and median_overall_z); groups that are not
+///   evaluated have NaN group_z entries and None for x_pt and sigma_pt
+#[pyfunction]
+fn py_score_by_group(
+    py: Python,
+    results: PyReadonlyArray1<f64>,
+    group_ids: PyReadonlyArray1<i64>,
+    x_pt: f64,
+    sigma_pt: f64,
+    per_group: Option<BTreeMap<i64, (f64, f64)>>,
+) -> PyResult<PyObject> {
+    let per_group = per_group.map(|per_group| {
+        per_group.into_iter()
+            .map(|(group, (x_pt, sigma_pt))| (group, GroupAssigned { x_pt, sigma_pt }))
+            .collect::<BTreeMap<_, _>>()
+    });
+    
+    let scores = score_by_group(
+        results.as_array(),
+        group_ids.as_array(),
+        x_pt,
+        sigma_pt,
+        per_group.as_ref(),
+    )?;
+    
+    let groups = PyList::empty(py);
+    for summary in &scores.groups {
+        let group = PyDict::new(py);
+        group.set_item("group", summary.group)?;
+        group.set_item("n", summary.n)?;
+        group.set_item("x_pt", summary.assigned.map(|a| a.x_pt))?;
+        group.set_item("sigma_pt", summary.assigned.map(|a| a.sigma_pt))?;
+        group.set_item("evaluated", summary.assigned.is_some())?;
+        group.set_item("median_overall_z", summary.median_overall_z)?;
+        groups.append(group)?;
+    }
+    
+    let dict = PyDict::new(py);
+    dict.set_item("overall_z", PyArray1::from_array(py, &scores.overall_z))?;
+    dict.set_item("group_z", PyArray1::from_array(py, &scores.group_z))?;
+    dict.set_item("groups", groups)?;
+    Ok(dict.into())
+}
+
+/// Create an empty multi-round score history for a participant
+/// 
+/// `participant_id` may be a str or int identifier.
+#[pyfunction]
+fn py_participant_history(participant_id: &PyAny) -> PyResult<ParticipantHistory> {
+    Ok(ParticipantHistory::new(extract_participant_id(participant_id)?))
+}
+
+/// Export a ScoreSet as JSON for the reporting pipeline
+/// 
+/// See `ScoreSet::to_json` for the schema; NaN and missing values are null.
+#[pyfunction]
+fn py_scores_to_json(score_set: PyRef<ScoreSet>) -> PyResult<String> {
+    Ok(score_set.to_json()?)
+}
+
+/// Generate score chart data (ordered scores, categories and band edges)
+///
+/// Returns a dict with keys `scores`, `indices`, `categories` and `band_edges`.
+/// `order` is either "score" (default) or "index".
+#[pyfunction]
+fn py_score_chart_data(
+    py: Python,
+    z_scores: PyReadonlyArray1<f64>,
+    warning: Option<f64>,
+    action: Option<f64>,
+    order: Option<&str>,
+) -> PyResult<PyObject> {
+    let defaults = ScoreLimits::default();
+    let limits = ScoreLimits::new(
+        warning.unwrap_or(defaults.warning),
+        action.unwrap_or(defaults.action),
+    )?;
+    let order = match order {
+        Some(order) => order.parse::<ChartOrder>()?,
+        None => ChartOrder::ByScore,
+    };
+    
+    let chart = score_chart_data(z_scores.as_array(), &limits, order)?;
+    let categories: Vec<&str> = chart.categories.iter().map(|c| c.as_str()).collect();
+    
+    let dict = PyDict::new(py);
+    dict.set_item("scores", PyArray1::from_vec(py, chart.scores))?;
+    dict.set_item("indices", PyArray1::from_vec(py, chart.indices))?;
+    dict.set_item("categories", categories)?;
+    dict.set_item("band_edges", PyArray1::from_slice(py, &chart.band_edges))?;
+    Ok(dict.into())
+}
+
+/// Merge result sets from multiple batches, keeping the batch of each value
+/// 
+/// `sets` is a list of (name, array) tuples.
+/// 
+/// # Returns
+/// * Dict with keys values (array), labels (list) and counts (dict of name to count)
+#[pyfunction]
+fn py_merge_result_sets(
+    py: Python,
+    sets: Vec<(String, PyReadonlyArray1<f64>)>,
+) -> PyResult<PyObject> {
+    let sets = sets.into_iter()
+        .map(|(name, values)| (name, values.as_array().to_vec()))
+        .collect();
+    let merged = utils::merge_result_sets(sets)?;
+    
+    let counts = PyDict::new(py);
+    for (name, count) in &merged.batch_counts {
+        counts.set_item(name, count)?;
+    }
+    
+    let dict = PyDict::new(py);
+    dict.set_item("values", PyArray1::from_vec(py, merged.values))?;
+    dict.set_item("labels", merged.labels)?;
+    dict.set_item("counts", counts)?;
+    Ok(dict.into())
+}
+
+/// Screen for identical or near-identical results between participants
+/// 
+/// # Returns
+/// * List of index lists, one per group of matching participants
+#[pyfunction]
+fn py_detect_identical_results(
+    results: PyReadonlyArray1<f64>,
+    relative_tolerance: Option<f64>,
+) -> PyResult<Vec<Vec<usize>>> {
+    match detect_identical_results(results.as_array(), relative_tolerance.unwrap_or(0.0)) {
+        Ok(groups) => Ok(groups),
         Err(e) => Err(e.into()),
     }
 }
 
-/// Calculate uncertainty for CRM values
+/// Screen for identical results on at least `min_matches` of several analytes
+/// 
+/// `results` is a 2D array with one row per participant and one column per analyte.
 #[pyfunction]
-fn py_calculate_uncertainty_crm(crm_uncertainty: f64) -> PyResult<f64> {
-    match calculate_uncertainty_crm(crm_uncertainty) {
-        Ok(result) => Ok(result),
+fn py_detect_identical_results_multi(
+    results: PyReadonlyArray2<f64>,
+    min_matches: usize,
+    relative_tolerance: Option<f64>,
+) -> PyResult<Vec<Vec<usize>>> {
+    let tolerance = relative_tolerance.unwrap_or(0.0);
+    
+    match detect_identical_results_multi(results.as_array(), tolerance, min_matches) {
+        Ok(groups) => Ok(groups),
         Err(e) => Err(e.into()),
     }
 }
 
-/// Calculate uncertainty for formulation values
+/// Flag results that fail an iterated Grubbs outlier test
+/// 
+/// `alternative` is "two_sided" (default), "max" or "min".
+/// 
+/// # Returns
+/// * Boolean array aligned with `results`, true for each flagged outlier
+#[pyfunction]
+fn py_grubbs_flags(
+    py: Python,
+    results: PyReadonlyArray1<f64>,
+    alpha: Option<f64>,
+    alternative: Option<&str>,
+) -> PyResult<Py<PyArray1<bool>>> {
+    let alternative: GrubbsAlternative = alternative.unwrap_or("two_sided").parse()?;
+    let flags = grubbs_flags(results.as_array(), alpha.unwrap_or(0.05), alternative)?;
+    Ok(PyArray1::from_array(py, &flags).to_owned())
+}
+
+/// Single Grubbs outlier test
+/// 
+/// `alternative` is "two_sided" (default), "max" or "min".
+/// 
+/// # Returns
+/// * Tuple of (G statistic, critical value, index of the suspected outlier, whether it is an outlier)
+#[pyfunction]
+fn py_grubbs_test(data: PyReadonlyArray1<f64>, alpha: Option<f64>, alternative: Option<&str>) -> PyResult<(f64, f64, usize, bool)> {
+    let alternative: GrubbsAlternative = alternative.unwrap_or("two_sided").parse()?;
+    let result = utils::grubbs_test(&data.as_array().to_vec(), alpha.unwrap_or(0.05), alternative)?;
+    Ok((result.statistic, result.critical_value, result.index, result.is_outlier))
+}
+
+/// Repeated Grubbs test removing one outlier per pass
+/// 
+/// `max_removals` defaults to no limit; `alternative` is "two_sided" (default), "max" or "min".
+/// 
+/// # Returns
+/// * Indices of the removed values in removal order
+#[pyfunction]
+fn py_grubbs_iterative(
+    data: PyReadonlyArray1<f64>,
+    alpha: Option<f64>,
+    max_removals: Option<usize>,
+    alternative: Option<&str>,
+) -> PyResult<Vec<usize>> {
+    let alternative: GrubbsAlternative = alternative.unwrap_or("two_sided").parse()?;
+    let data = data.as_array().to_vec();
+    let max_removals = max_removals.unwrap_or(data.len());
+    Ok(utils::grubbs_iterative(&data, alpha.unwrap_or(0.05), alternative, max_removals)?)
+}
+
+/// Cochran's C test for an outlying within-laboratory variance
+/// 
+/// `alpha` defaults to 0.05; ISO 5725-2 uses 0.05 for stragglers and 0.01 for outliers.
+/// 
+/// # Returns
+/// * Tuple of (C statistic, critical value, index of the largest variance, whether it is outlying)
+#[pyfunction]
+fn py_cochran_test(variances: PyReadonlyArray1<f64>, replicates: usize, alpha: Option<f64>) -> PyResult<(f64, f64, usize, bool)> {
+    let result = utils::cochran_test(&variances.as_array().to_vec(), replicates, alpha.unwrap_or(0.05))?;
+    Ok((result.statistic, result.critical_value, result.index, result.is_outlier))
+}
+
+/// Calculate Tukey's fences (Q1 - k·IQR, Q3 + k·IQR) for outlier screening
 #[pyfunction]
-fn py_calculate_uncertainty_formulation(formulation_uncertainty: f64) -> PyResult<f64> {
-    match calculate_uncertainty_formulation(formulation_uncertainty) {
+fn py_tukey_fences(data: PyReadonlyArray1<f64>, k: Option<f64>) -> PyResult<(f64, f64)> {
+    let data = data.as_array().to_vec();
+    let k = k.unwrap_or(utils::constants::DEFAULT_TUKEY_K);
+    
+    match utils::tukey_fences(&data, k) {
         Ok(result) => Ok(result),
         Err(e) => Err(e.into()),
     }
 }
 
-/// Calculate uncertainty for expert consensus values
+/// Return the indices of values outside Tukey's fences
 #[pyfunction]
-fn py_calculate_uncertainty_expert(expert_uncertainty: f64) -> PyResult<f64> {
-    match calculate_uncertainty_expert(expert_uncertainty) {
+fn py_flag_tukey_outliers(data: PyReadonlyArray1<f64>, k: Option<f64>) -> PyResult<Vec<usize>> {
+    let data = data.as_array().to_vec();
+    let k = k.unwrap_or(utils::constants::DEFAULT_TUKEY_K);
+    
+    match utils::flag_tukey_outliers(&data, k) {
         Ok(result) => Ok(result),
         Err(e) => Err(e.into()),
     }
 }
 
-/// Calculate z-scores for participant performance
+/// Flag results likely off from x_pt by a factor of 10, 100 or 1000
+/// 
+/// # Returns
+/// * Tuple of (flagged indices, likely factor of each flagged result relative to x_pt)
 #[pyfunction]
-fn py_calculate_z_scores(
-    py: Python,
+fn py_detect_magnitude_errors(
     results: PyReadonlyArray1<f64>,
     x_pt: f64,
-    sigma_pt: f64,
-) -> PyResult<Py<PyArray1<f64>>> {
-    let results_array = results.as_array();
+    tolerance: Option<f64>,
+) -> PyResult<(Vec<usize>, Vec<f64>)> {
+    let tolerance = tolerance.unwrap_or(utils::constants::DEFAULT_MAGNITUDE_TOLERANCE);
+    let flagged = detect_magnitude_errors(results.as_array(), x_pt, tolerance)?;
     
-    match calculate_z_scores(results_array, x_pt, sigma_pt) {
-        Ok(z_scores) => Ok(PyArray1::from_array(py, &z_scores).to_owned()),
-        Err(e) => Err(e.into()),
-    }
+    Ok(flagged.iter().map(|error| (error.index, error.factor)).unzip())
 }
 
-/// Calculate zeta-scores (z'-scores) for participant performance
+/// Python return type of the significant figures check
+type SignificantFiguresTuple = (Py<PyArray1<usize>>, Py<PyArray1<bool>>);
+
+/// Check that results are reported with at least `min_sig_figs` significant figures
+/// 
+/// # Returns
+/// * Tuple of (apparent significant figures, violation flags)
 #[pyfunction]
-fn py_calculate_z_prime_scores(
+fn py_check_significant_figures(
     py: Python,
     results: PyReadonlyArray1<f64>,
-    u_results: PyReadonlyArray1<f64>,
-    x_pt: f64,
-    u_x_pt: f64,
-) -> PyResult<Py<PyArray1<f64>>> {
-    let results_array = results.as_array();
-    let u_results_array = u_results.as_array();
+    min_sig_figs: usize,
+) -> PyResult<SignificantFiguresTuple> {
+    let check = check_significant_figures(results.as_array(), min_sig_figs)?;
+    Ok((
+        PyArray1::from_vec(py, check.counts).to_owned(),
+        PyArray1::from_vec(py, check.violations).to_owned(),
+    ))
+}
+
+/// Calculate the Spearman rank correlation matrix of the analytes
+/// 
+/// `data` is a 2D array with one row per participant and one column per analyte.
+/// NaN marks a missing result and is excluded pairwise; entries with fewer than
+/// 3 complete pairs or a constant analyte are NaN.
+#[pyfunction]
+fn py_robust_correlation_matrix(
+    py: Python,
+    data: PyReadonlyArray2<f64>,
+) -> PyResult<Py<PyArray2<f64>>> {
+    let matrix = robust_correlation_matrix(data.as_array())?;
+    Ok(PyArray2::from_owned_array(py, matrix).to_owned())
+}
+
+/// Smallest true bias (in measurement units) the z-score scheme detects with probability `power`
+/// 
+/// `alpha` is the two-sided false signal probability of the action limit.
+#[pyfunction]
+fn py_minimum_detectable_bias(sigma_pt: f64, u_x_pt: f64, power: f64, alpha: f64) -> PyResult<f64> {
+    Ok(minimum_detectable_bias(sigma_pt, u_x_pt, power, alpha)?)
+}
+
+/// Algorithm A consensus of each level of a multi-level round and the precision profile
+/// 
+/// `levels` is a list of result arrays, one per material.
+/// 
+/// # Returns
+/// * Dict with keys levels (list of dicts with keys x_pt, s_star, participants_used,
+///   iterations and warnings), cv (coefficient of variation s* / |x*| of each level)
+///   and profile_exponent (b of s* = a·|x*|^b, or None)
+#[pyfunction]
+fn py_analyze_multilevel(
+    py: Python,
+    levels: Vec<PyReadonlyArray1<f64>>,
+    tolerance: Option<f64>,
+    max_iterations: Option<usize>,
+) -> PyResult<PyObject> {
+    let tol = tolerance.unwrap_or(utils::constants::DEFAULT_TOLERANCE);
+    let max_iter = max_iterations.unwrap_or(utils::constants::DEFAULT_MAX_ITERATIONS);
     
-    match calculate_z_prime_scores(results_array, u_results_array, x_pt, u_x_pt) {
-        Ok(z_prime_scores) => Ok(PyArray1::from_array(py, &z_prime_scores).to_owned()),
-        Err(e) => Err(e.into()),
+    let analysis = analyze_multilevel(levels.iter().map(|level| level.as_array()).collect(), tol, max_iter)?;
+    
+    let level_list = PyList::empty(py);
+    for result in &analysis.levels {
+        let level = PyDict::new(py);
+        level.set_item("x_pt", result.x_pt)?;
+        level.set_item("s_star", result.s_star)?;
+        level.set_item("participants_used", result.participants_used)?;
+        level.set_item("iterations", result.iterations)?;
+        level.set_item("warnings", warnings_to_py(py, &result.warnings, false)?)?;
+        level_list.append(level)?;
     }
+    
+    let dict = PyDict::new(py);
+    dict.set_item("levels", level_list)?;
+    dict.set_item("cv", PyArray1::from_vec(py, analysis.cv))?;
+    dict.set_item("profile_exponent", analysis.profile_exponent)?;
+    Ok(dict.into())
 }
 
-/// Calculate zeta-scores when participant uncertainties are not available
+/// Correct results for linear drift to `reference_time`: x_i - drift_rate * (t_i - reference_time)
+/// 
+/// A positive `drift_rate` means the measured value increases with time.
 #[pyfunction]
-fn py_calculate_z_prime_scores_no_uncertainties(
+fn py_drift_correct(
     py: Python,
     results: PyReadonlyArray1<f64>,
-    x_pt: f64,
-    u_x_pt: f64,
+    measurement_times: PyReadonlyArray1<f64>,
+    drift_rate: f64,
+    reference_time: f64,
 ) -> PyResult<Py<PyArray1<f64>>> {
-    let results_array = results.as_array();
-    
-    match calculate_z_prime_scores_no_participant_uncertainties(results_array, x_pt, u_x_pt) {
-        Ok(z_prime_scores) => Ok(PyArray1::from_array(py, &z_prime_scores).to_owned()),
-        Err(e) => Err(e.into()),
-    }
+    let corrected = drift_correct(results.as_array(), measurement_times.as_array(), drift_rate, reference_time)?;
+    Ok(PyArray1::from_owned_array(py, corrected).to_owned())
+}
+
+/// Robust repeatability and between-participant SDs from a participants x replicates array
+/// 
+/// `mad_consistency` converts the MAD of the participant means to an SD (default 1.4826).
+/// 
+/// # Returns
+/// * Tuple of (repeatability SD s_r, between-participant SD s_L, robust SD of the participant means)
+#[pyfunction]
+fn py_robust_variance_components(
+    replicates: PyReadonlyArray2<f64>,
+    mad_consistency: Option<f64>,
+) -> PyResult<(f64, f64, f64)> {
+    let mad_consistency = mad_consistency.unwrap_or(utils::constants::MAD_TO_SIGMA);
+    let components = robust_variance_components(replicates.as_array(), mad_consistency)?;
+    Ok((components.repeatability_sd, components.between_sd, components.means_sd))
 }
 
 /// Python module definition
@@ -168,29 +2092,151 @@ fn py_calculate_z_prime_scores_no_uncertainties(
 fn pt_cli_rust(_py: Python, m: &PyModule) -> PyResult<()> {
     // Add estimator functions
     m.add_function(wrap_pyfunction!(py_calculate_algorithm_a, m)?)?;
+    m.add_function(wrap_pyfunction!(py_calculate_algorithm_a_detailed, m)?)?;
+    m.add_function(wrap_pyfunction!(py_breakdown_analysis, m)?)?;
+    m.add_function(wrap_pyfunction!(py_effective_sample_size, m)?)?;
+    m.add_function(wrap_pyfunction!(py_weighted_median, m)?)?;
+    m.add_function(wrap_pyfunction!(py_sn_scale, m)?)?;
+    m.add_function(wrap_pyfunction!(py_quantile, m)?)?;
+    m.add_function(wrap_pyfunction!(py_rounding_resolution, m)?)?;
+    m.add_function(wrap_pyfunction!(py_find_invalid_values, m)?)?;
+    m.add_function(wrap_pyfunction!(py_scale_vs_resolution, m)?)?;
+    m.add_function(wrap_pyfunction!(py_terminal_digit_distribution, m)?)?;
+    m.add_function(wrap_pyfunction!(py_skewness, m)?)?;
+    m.add_function(wrap_pyfunction!(py_kurtosis, m)?)?;
+    m.add_function(wrap_pyfunction!(py_medcouple, m)?)?;
+    m.add_function(wrap_pyfunction!(py_anderson_darling, m)?)?;
+    m.add_function(wrap_pyfunction!(py_shapiro_wilk, m)?)?;
+    m.add_function(wrap_pyfunction!(py_bisquare_psi, m)?)?;
+    m.add_function(wrap_pyfunction!(py_bisquare_weight, m)?)?;
+    m.add_function(wrap_pyfunction!(py_hampel_psi, m)?)?;
+    m.add_function(wrap_pyfunction!(py_calculate_robust, m)?)?;
+    m.add_function(wrap_pyfunction!(py_assess_participant_adequacy, m)?)?;
     m.add_function(wrap_pyfunction!(py_calculate_from_crm, m)?)?;
     m.add_function(wrap_pyfunction!(py_calculate_from_formulation, m)?)?;
     m.add_function(wrap_pyfunction!(py_calculate_from_expert_consensus, m)?)?;
+    m.add_function(wrap_pyfunction!(py_calculate_from_reference_lab, m)?)?;
+    m.add_function(wrap_pyfunction!(py_calculate_from_gravimetric, m)?)?;
+    m.add_function(wrap_pyfunction!(py_calculate_median_consensus, m)?)?;
+    m.add_function(wrap_pyfunction!(py_half_range_mode, m)?)?;
     
     // Add uncertainty functions
     m.add_function(wrap_pyfunction!(py_calculate_uncertainty_consensus, m)?)?;
+    m.add_function(wrap_pyfunction!(py_calculate_uncertainty_consensus_weighted, m)?)?;
+    m.add_function(wrap_pyfunction!(py_calculate_uncertainty_median, m)?)?;
+    m.add_function(wrap_pyfunction!(py_coverage_interval_consensus, m)?)?;
+    m.add_function(wrap_pyfunction!(py_coverage_interval, m)?)?;
+    m.add_function(wrap_pyfunction!(py_assigned_value_expanded, m)?)?;
+    m.add_function(wrap_pyfunction!(py_uncertainty_of_s_star, m)?)?;
     m.add_function(wrap_pyfunction!(py_calculate_uncertainty_crm, m)?)?;
     m.add_function(wrap_pyfunction!(py_calculate_uncertainty_formulation, m)?)?;
     m.add_function(wrap_pyfunction!(py_calculate_uncertainty_expert, m)?)?;
+    m.add_function(wrap_pyfunction!(py_uncertainty_from_homogeneity, m)?)?;
+    m.add_function(wrap_pyfunction!(py_calculate_uncertainty_expert_from_results, m)?)?;
+    m.add_function(wrap_pyfunction!(py_calculate_expert_consensus_weighted, m)?)?;
+    m.add_function(wrap_pyfunction!(py_jackknife_uncertainty, m)?)?;
+    m.add_function(wrap_pyfunction!(py_bootstrap_uncertainty_consensus, m)?)?;
+    m.add_function(wrap_pyfunction!(py_monte_carlo_uncertainty, m)?)?;
+    m.add_function(wrap_pyfunction!(py_robust_consensus, m)?)?;
+    m.add_function(wrap_pyfunction!(py_combine_uncertainty_components, m)?)?;
+    m.add_function(wrap_pyfunction!(py_propagate_uncertainty, m)?)?;
+    m.add_function(wrap_pyfunction!(py_apply_recovery_correction, m)?)?;
+    m.add_function(wrap_pyfunction!(py_effective_degrees_of_freedom, m)?)?;
+    m.add_function(wrap_pyfunction!(py_check_uncertainty_criterion, m)?)?;
+    m.add_function(wrap_pyfunction!(py_screen_participant_uncertainties, m)?)?;
+    m.add_function(wrap_pyfunction!(py_check_assigned_value_agreement, m)?)?;
+    m.add_function(wrap_pyfunction!(py_combine_reference_and_consensus, m)?)?;
+    m.add_function(wrap_pyfunction!(py_dark_uncertainty, m)?)?;
+    m.add_function(wrap_pyfunction!(py_uncertainty_budget, m)?)?;
+    m.add_class::<UncertaintyBudget>()?;
+    m.add_class::<CoverageInterval>()?;
+    
+    // Add sigma_pt functions
+    m.add_function(wrap_pyfunction!(py_sigma_pt_with_floor, m)?)?;
+    m.add_function(wrap_pyfunction!(py_sigma_pt_by_perception, m)?)?;
     
     // Add scoring functions
     m.add_function(wrap_pyfunction!(py_calculate_z_scores, m)?)?;
+    m.add_function(wrap_pyfunction!(py_calculate_z_scores_asymmetric, m)?)?;
+    m.add_function(wrap_pyfunction!(py_calculate_z_scores_grouped, m)?)?;
+    m.add_function(wrap_pyfunction!(py_calculate_z_scores_from_interval, m)?)?;
+    m.add_function(wrap_pyfunction!(py_calculate_z_scores_with_spec, m)?)?;
+    m.add_function(wrap_pyfunction!(py_calculate_z_scores_trimmed, m)?)?;
+    m.add_function(wrap_pyfunction!(py_estimate_systematic_bias, m)?)?;
+    m.add_function(wrap_pyfunction!(py_compare_assigned_values, m)?)?;
+    m.add_function(wrap_pyfunction!(py_calculate_log_z_scores, m)?)?;
+    m.add_function(wrap_pyfunction!(py_log_sigma_acceptance_factors, m)?)?;
     m.add_function(wrap_pyfunction!(py_calculate_z_prime_scores, m)?)?;
+    m.add_function(wrap_pyfunction!(py_calculate_z_scores_into, m)?)?;
+    m.add_function(wrap_pyfunction!(py_calculate_z_prime_scores_into, m)?)?;
+    m.add_function(wrap_pyfunction!(py_calculate_z_prime_scores_relative, m)?)?;
     m.add_function(wrap_pyfunction!(py_calculate_z_prime_scores_no_uncertainties, m)?)?;
+    m.add_function(wrap_pyfunction!(py_standardized_residuals, m)?)?;
+    m.add_function(wrap_pyfunction!(py_round_scores, m)?)?;
+    m.add_function(wrap_pyfunction!(py_rank_by_abs_score, m)?)?;
+    m.add_function(wrap_pyfunction!(py_z_score_diagnostics, m)?)?;
+    m.add_function(wrap_pyfunction!(py_unsatisfactory_rate, m)?)?;
+    m.add_function(wrap_pyfunction!(py_interpret_zeta_score, m)?)?;
+    m.add_function(wrap_pyfunction!(py_interpret_zeta_scores, m)?)?;
+    m.add_function(wrap_pyfunction!(py_joint_interpretation, m)?)?;
+    m.add_function(wrap_pyfunction!(py_cross_check_scores, m)?)?;
+    m.add_function(wrap_pyfunction!(py_boundary_sensitivity, m)?)?;
+    m.add_function(wrap_pyfunction!(py_z_score_uncertainties, m)?)?;
+    m.add_function(wrap_pyfunction!(py_score_set, m)?)?;
+    m.add_function(wrap_pyfunction!(py_round_data, m)?)?;
+    m.add_function(wrap_pyfunction!(py_score_round_data, m)?)?;
+    m.add_function(wrap_pyfunction!(py_calculate_z_scores_round, m)?)?;
+    m.add_function(wrap_pyfunction!(py_calculate_z_prime_scores_round, m)?)?;
+    m.add_class::<RoundData>()?;
+    m.add_function(wrap_pyfunction!(py_full_diagnostics, m)?)?;
+    m.add_class::<ParticipantDiagnostics>()?;
+    m.add_function(wrap_pyfunction!(py_scores_to_json, m)?)?;
+    m.add_function(wrap_pyfunction!(py_score_by_group, m)?)?;
+    m.add_class::<ScoreSet>()?;
+    m.add_function(wrap_pyfunction!(py_participant_history, m)?)?;
+    m.add_class::<ParticipantHistory>()?;
+    
+    // Add screening utilities
+    m.add_function(wrap_pyfunction!(py_merge_result_sets, m)?)?;
+    m.add_function(wrap_pyfunction!(py_detect_identical_results, m)?)?;
+    m.add_function(wrap_pyfunction!(py_detect_identical_results_multi, m)?)?;
+    m.add_function(wrap_pyfunction!(py_tukey_fences, m)?)?;
+    m.add_function(wrap_pyfunction!(py_flag_tukey_outliers, m)?)?;
+    m.add_function(wrap_pyfunction!(py_grubbs_flags, m)?)?;
+    m.add_function(wrap_pyfunction!(py_grubbs_test, m)?)?;
+    m.add_function(wrap_pyfunction!(py_grubbs_iterative, m)?)?;
+    m.add_function(wrap_pyfunction!(py_cochran_test, m)?)?;
+    m.add_function(wrap_pyfunction!(py_detect_magnitude_errors, m)?)?;
+    
+    // Add validation functions
+    m.add_function(wrap_pyfunction!(py_check_significant_figures, m)?)?;
+    
+    // Add multi-analyte functions
+    m.add_function(wrap_pyfunction!(py_robust_correlation_matrix, m)?)?;
+    
+    // Add design functions
+    m.add_function(wrap_pyfunction!(py_minimum_detectable_bias, m)?)?;
+    m.add_function(wrap_pyfunction!(py_analyze_multilevel, m)?)?;
+    
+    // Add stability functions
+    m.add_function(wrap_pyfunction!(py_drift_correct, m)?)?;
+    
+    // Add precision functions
+    m.add_function(wrap_pyfunction!(py_robust_variance_components, m)?)?;
+    
+    // Add plotting functions
+    m.add_function(wrap_pyfunction!(py_score_chart_data, m)?)?;
     
     Ok(())
 }
 
 #[cfg(test)]
 mod tests {
+    #[allow(unused_imports)]
     use super::*;
 
     #[test]
+    #[allow(clippy::assertions_on_constants)]
     fn test_basic_functionality() {
         // Basic smoke test
         assert!(true);
@@ -0,0 +1,169 @@
+//! Multi-analyte analysis module
+//!
+//! This module implements statistics across the analytes of a round, used to
+//! investigate systematic laboratory effects that show up in several analytes.
+
+use crate::utils::{CalculationError, is_valid_float};
+use ndarray::{Array2, ArrayView2};
+
+/// Average ranks (1-based) of the values, with tied values sharing their mean rank
+fn average_ranks(values: &[f64]) -> Vec<f64> {
+    let mut order: Vec<usize> = (0..values.len()).collect();
+    order.sort_by(|&a, &b| values[a].total_cmp(&values[b]));
+
+    let mut ranks = vec![0.0; values.len()];
+    let mut start = 0;
+    while start < order.len() {
+        let mut end = start + 1;
+        while end < order.len() && values[order[end]] == values[order[start]] {
+            end += 1;
+        }
+
+        // Positions start..end (0-based) share the mean of ranks start + 1..=end
+        let rank = (start + end + 1) as f64 / 2.0;
+        for &index in &order[start..end] {
+            ranks[index] = rank;
+        }
+        start = end;
+    }
+    ranks
+}
+
+/// Pearson correlation of two equally long slices, NaN if either is constant
+fn pearson(x: &[f64], y: &[f64]) -> f64 {
+    let n = x.len() as f64;
+    let mean_x = x.iter().sum::<f64>() / n;
+    let mean_y = y.iter().sum::<f64>() / n;
+
+    let (mut sxy, mut sxx, mut syy) = (0.0, 0.0, 0.0);
+    for (&xi, &yi) in x.iter().zip(y) {
+        sxy += (xi - mean_x) * (yi - mean_y);
+        sxx += (xi - mean_x).powi(2);
+        syy += (yi - mean_y).powi(2);
+    }
+
+    if sxx == 0.0 || syy == 0.0 {
+        return f64::NAN;
+    }
+    (sxy / (sxx * syy).sqrt()).clamp(-1.0, 1.0)
+}
+
+/// Calculate the Spearman rank correlation matrix of the analytes
+///
+/// Spearman's rho is the Pearson correlation of the ranks, so a single wild
+/// result moves it by at most one rank and it also picks up monotonic,
+/// non-linear relationships between analytes. Tied results get their average
+/// rank.
+///
+/// NaN marks a missing result and is handled pairwise: each entry (j, k) uses
+/// only the participants with results for both analytes j and k, ranked
+/// within that subset. An entry is NaN if fewer than 3 such participants
+/// remain or either analyte is constant on them; this includes the diagonal,
+/// which is otherwise 1.
+///
+/// # Arguments
+/// * `data` - 2D array with one row per participant and one column per analyte
+///
+/// # Returns
+/// * `Ok(Array2<f64>)` - Symmetric m x m correlation matrix for m analytes
+/// * `Err(CalculationError)` - If there are no analytes or a result is infinite
+pub fn robust_correlation_matrix(data: ArrayView2<f64>) -> Result<Array2<f64>, CalculationError> {
+    let m = data.ncols();
+    if m == 0 {
+        return Err(CalculationError::InsufficientData {
+            required: 1,
+            actual: 0,
+        });
+    }
+
+    if let Some(&value) = data.iter().find(|&&v| !v.is_nan() && !is_valid_float(v)) {
+        return Err(CalculationError::InvalidInput {
+            message: format!("Invalid participant result: {}", value),
+        });
+    }
+
+    let mut matrix = Array2::from_elem((m, m), f64::NAN);
+    for j in 0..m {
+        for k in j..m {
+            let (x, y): (Vec<f64>, Vec<f64>) = data.column(j).iter()
+                .zip(data.column(k).iter())
+                .filter(|(a, b)| !a.is_nan() && !b.is_nan())
+                .map(|(&a, &b)| (a, b))
+                .unzip();
+
+            if x.len() < 3 {
+                continue;
+            }
+
+            let rho = pearson(&average_ranks(&x), &average_ranks(&y));
+            matrix[[j, k]] = rho;
+            matrix[[k, j]] = rho;
+        }
+    }
+
+    Ok(matrix)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+    use ndarray::array;
+
+    #[test]
+    fn test_average_ranks_with_ties() {
+        assert_eq!(average_ranks(&[3.0, 1.0, 2.0, 2.0]), vec![4.0, 1.0, 2.5, 2.5]);
+    }
+
+    #[test]
+    fn test_robust_correlation_matrix() {
+        // Analyte 1 is a monotonic transform of analyte 0 with one wild value,
+        // analyte 2 is reversed
+        let data = array![
+            [1.0, 1.0, 5.0],
+            [2.0, 4.0, 4.0],
+            [3.0, 9.0, 3.0],
+            [4.0, 16.0, 2.0],
+            [5.0, 1000.0, 1.0],
+        ];
+        let matrix = robust_correlation_matrix(data.view()).unwrap();
+
+        assert_eq!(matrix.dim(), (3, 3));
+        for j in 0..3 {
+            assert_abs_diff_eq!(matrix[[j, j]], 1.0, epsilon = 1e-12);
+        }
+        assert_abs_diff_eq!(matrix[[0, 1]], 1.0, epsilon = 1e-12);
+        assert_abs_diff_eq!(matrix[[0, 2]], -1.0, epsilon = 1e-12);
+        assert_eq!(matrix, matrix.t());
+
+        // Σd² = 2 for ranks (1, 2, 3, 4) and (2, 1, 3, 4): rho = 1 - 6·2 / (4·15) = 0.8
+        let swapped = array![[1.0, 2.0], [2.0, 1.0], [3.0, 3.0], [4.0, 4.0]];
+        let matrix = robust_correlation_matrix(swapped.view()).unwrap();
+        assert_abs_diff_eq!(matrix[[0, 1]], 0.8, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_robust_correlation_matrix_pairwise_nan() {
+        let data = array![
+            [1.0, 2.0, f64::NAN],
+            [2.0, f64::NAN, 7.0],
+            [3.0, 6.0, f64::NAN],
+            [4.0, 8.0, 7.0],
+            [5.0, 10.0, 7.0],
+        ];
+        let matrix = robust_correlation_matrix(data.view()).unwrap();
+
+        // Analytes 0 and 1 share 4 participants and agree perfectly
+        assert_abs_diff_eq!(matrix[[0, 1]], 1.0, epsilon = 1e-12);
+        // Analyte 2 is constant on its 3 results, and shares only 2 with analyte 1
+        assert!(matrix[[2, 2]].is_nan());
+        assert!(matrix[[0, 2]].is_nan());
+        assert!(matrix[[1, 2]].is_nan());
+    }
+
+    #[test]
+    fn test_robust_correlation_matrix_invalid() {
+        assert!(robust_correlation_matrix(Array2::<f64>::zeros((3, 0)).view()).is_err());
+        assert!(robust_correlation_matrix(array![[1.0, f64::INFINITY]].view()).is_err());
+    }
+}
@@ -0,0 +1,113 @@
+//! Outlier and blunder screening module
+//!
+//! This module implements pre-screens that flag suspicious participant results
+//! for follow-up by the coordinator. The flags are for feedback only and do not
+//! change the robust statistics.
+
+use crate::utils::{CalculationError, is_valid_float, validate_floats};
+use ndarray::ArrayView1;
+
+/// A result that looks like the assigned value multiplied by a power of ten
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MagnitudeError {
+    /// Index of the result
+    pub index: usize,
+    /// Likely factor between the result and x_pt (10, 100, 1000 or their reciprocals)
+    pub factor: f64,
+}
+
+/// Flag results that are likely off by a factor of 10, 100 or 1000
+///
+/// A result is flagged when log10(x_i / x_pt) lies within `tolerance` of
+/// ±1, ±2 or ±3, which is typical of unit or decimal point mistakes (e.g. mg/kg
+/// reported as µg/kg). Results with a different sign from x_pt or equal to zero
+/// cannot be compared on the log scale and are never flagged.
+///
+/// # Arguments
+/// * `results` - Array view of participant results
+/// * `x_pt` - Assigned value (non-zero)
+/// * `tolerance` - Allowed distance from a whole decade on the log10 scale (0 to 0.5)
+///
+/// # Returns
+/// * `Ok(Vec<MagnitudeError>)` - Flagged results in index order with their likely factor
+/// * `Err(CalculationError)` - If the input or parameters are invalid
+pub fn detect_magnitude_errors(
+    results: ArrayView1<f64>,
+    x_pt: f64,
+    tolerance: f64,
+) -> Result<Vec<MagnitudeError>, CalculationError> {
+    validate_floats(results, "participant results")?;
+
+    if !is_valid_float(x_pt) || x_pt == 0.0 {
+        return Err(CalculationError::InvalidInput {
+            message: format!("Invalid or zero assigned value x_pt: {}", x_pt),
+        });
+    }
+
+    // Windows around neighbouring decades must not overlap
+    if !is_valid_float(tolerance) || tolerance <= 0.0 || tolerance >= 0.5 {
+        return Err(CalculationError::InvalidInput {
+            message: format!("Magnitude tolerance must be in (0, 0.5), got {}", tolerance),
+        });
+    }
+
+    Ok(results.iter()
+        .enumerate()
+        .filter_map(|(index, &x_i)| {
+            let ratio = x_i / x_pt;
+            if ratio <= 0.0 {
+                return None;
+            }
+
+            let log_ratio = ratio.log10();
+            let decades = log_ratio.round();
+            let is_blunder = decades != 0.0
+                && decades.abs() <= 3.0
+                && (log_ratio - decades).abs() <= tolerance;
+
+            is_blunder.then(|| MagnitudeError {
+                index,
+                factor: 10f64.powi(decades as i32),
+            })
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::array;
+
+    #[test]
+    fn test_detect_magnitude_errors() {
+        let results = array![10.1, 9.8, 101.0, 0.0102, 0.95, 10_500.0, 25.0, -100.0, 0.0];
+        let flagged = detect_magnitude_errors(results.view(), 10.0, 0.1).unwrap();
+
+        assert_eq!(flagged, vec![
+            MagnitudeError { index: 2, factor: 10.0 },
+            MagnitudeError { index: 3, factor: 0.001 },
+            MagnitudeError { index: 4, factor: 0.1 },
+            MagnitudeError { index: 5, factor: 1000.0 },
+        ]);
+    }
+
+    #[test]
+    fn test_detect_magnitude_errors_outside_range() {
+        // Four decades away is not a plausible unit slip, and 2.5x is not a decade
+        let results = array![100_000.0, 25.0];
+        assert!(detect_magnitude_errors(results.view(), 10.0, 0.1).unwrap().is_empty());
+
+        // Negative assigned values compare by ratio
+        let flagged = detect_magnitude_errors(array![-50.0].view(), -5.0, 0.1).unwrap();
+        assert_eq!(flagged, vec![MagnitudeError { index: 0, factor: 10.0 }]);
+    }
+
+    #[test]
+    fn test_detect_magnitude_errors_invalid() {
+        let results = array![1.0, 10.0];
+        assert!(detect_magnitude_errors(results.view(), 0.0, 0.1).is_err());
+        assert!(detect_magnitude_errors(results.view(), 1.0, 0.0).is_err());
+        assert!(detect_magnitude_errors(results.view(), 1.0, 0.5).is_err());
+        assert!(detect_magnitude_errors(array![f64::NAN].view(), 1.0, 0.1).is_err());
+    }
+}
@@ -0,0 +1,159 @@
+//! Score chart data module
+//!
+//! This module prepares the numeric data behind control-chart style score plots
+//! (ordered scores, per-participant categories and band edges) so that the
+//! plotting layer does not need to hard-code the classification limits.
+
+use crate::scoring::{classify_score, ScoreCategory, ScoreLimits};
+use crate::utils::CalculationError;
+use ndarray::ArrayView1;
+use std::str::FromStr;
+
+/// Ordering of the points in a score chart
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChartOrder {
+    /// Ascending by score, NaN scores last
+    ByScore,
+    /// Original participant order
+    ByIndex,
+}
+
+impl FromStr for ChartOrder {
+    type Err = CalculationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "score" => Ok(ChartOrder::ByScore),
+            "index" => Ok(ChartOrder::ByIndex),
+            _ => Err(CalculationError::InvalidInput {
+                message: format!("Unknown chart order '{}': expected 'score' or 'index'", s),
+            }),
+        }
+    }
+}
+
+/// Data required to draw a score chart
+#[derive(Debug, Clone)]
+pub struct ScoreChartData {
+    /// Scores in chart order
+    pub scores: Vec<f64>,
+    /// Original participant index of each plotted score
+    pub indices: Vec<usize>,
+    /// Category of each plotted score
+    pub categories: Vec<ScoreCategory>,
+    /// Band edges in ascending order: [-action, -warning, warning, action]
+    pub band_edges: [f64; 4],
+}
+
+/// Generate the data for a score chart
+///
+/// NaN scores are kept in the output (categorised as `NotEvaluated`) so that
+/// every participant appears in the chart; when ordering by score they are
+/// placed after all other values. Ties keep their original participant order.
+///
+/// # Arguments
+/// * `z_scores` - Array view of participant scores
+/// * `limits` - Warning and action limits defining the chart bands
+/// * `order` - Ordering of the points in the returned data
+///
+/// # Returns
+/// * `Ok(ScoreChartData)` - Ordered scores, indices, categories and band edges
+/// * `Err(CalculationError)` - If the input is empty
+pub fn score_chart_data(
+    z_scores: ArrayView1<f64>,
+    limits: &ScoreLimits,
+    order: ChartOrder,
+) -> Result<ScoreChartData, CalculationError> {
+    if z_scores.is_empty() {
+        return Err(CalculationError::InsufficientData {
+            required: 1,
+            actual: 0,
+        });
+    }
+
+    let mut indices: Vec<usize> = (0..z_scores.len()).collect();
+
+    if order == ChartOrder::ByScore {
+        // Stable sort keeps tied scores in participant order
+        indices.sort_by(|&a, &b| {
+            let (za, zb) = (z_scores[a], z_scores[b]);
+            za.is_nan().cmp(&zb.is_nan()).then(za.total_cmp(&zb))
+        });
+    }
+
+    let scores: Vec<f64> = indices.iter().map(|&i| z_scores[i]).collect();
+    let categories = scores.iter()
+        .map(|&z| classify_score(z, limits))
+        .collect();
+
+    Ok(ScoreChartData {
+        scores,
+        indices,
+        categories,
+        band_edges: [-limits.action, -limits.warning, limits.warning, limits.action],
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scoring::interpret_z_score;
+    use ndarray::array;
+
+    #[test]
+    fn test_chart_categories_match_classification() {
+        let z_scores = array![0.5, -2.5, 3.5, 2.0, -3.0, -0.1];
+        let limits = ScoreLimits::default();
+        let chart = score_chart_data(z_scores.view(), &limits, ChartOrder::ByScore).unwrap();
+
+        for ((&z, &i), category) in chart.scores.iter().zip(&chart.indices).zip(&chart.categories) {
+            assert_eq!(z, z_scores[i]);
+            assert_eq!(*category, classify_score(z, &limits));
+            assert_eq!(category.as_str(), interpret_z_score(z));
+        }
+    }
+
+    #[test]
+    fn test_chart_order_by_score() {
+        let z_scores = array![1.0, -2.0, f64::NAN, 0.5, 1.0];
+        let chart = score_chart_data(
+            z_scores.view(),
+            &ScoreLimits::default(),
+            ChartOrder::ByScore,
+        ).unwrap();
+
+        assert_eq!(chart.indices, vec![1, 3, 0, 4, 2]);
+        assert!(chart.scores[4].is_nan());
+        assert_eq!(chart.categories[4], ScoreCategory::NotEvaluated);
+    }
+
+    #[test]
+    fn test_chart_order_by_index() {
+        let z_scores = array![1.0, -2.0, 0.5];
+        let chart = score_chart_data(
+            z_scores.view(),
+            &ScoreLimits::default(),
+            ChartOrder::ByIndex,
+        ).unwrap();
+
+        assert_eq!(chart.indices, vec![0, 1, 2]);
+        assert_eq!(chart.scores, vec![1.0, -2.0, 0.5]);
+    }
+
+    #[test]
+    fn test_chart_custom_limits() {
+        let z_scores = array![1.2, 0.8];
+        let limits = ScoreLimits::new(1.0, 1.5).unwrap();
+        let chart = score_chart_data(z_scores.view(), &limits, ChartOrder::ByIndex).unwrap();
+
+        assert_eq!(chart.band_edges, [-1.5, -1.0, 1.0, 1.5]);
+        assert_eq!(chart.categories, vec![ScoreCategory::Questionable, ScoreCategory::Satisfactory]);
+    }
+
+    #[test]
+    fn test_chart_order_parsing() {
+        assert_eq!("score".parse::<ChartOrder>().unwrap(), ChartOrder::ByScore);
+        assert_eq!("index".parse::<ChartOrder>().unwrap(), ChartOrder::ByIndex);
+        assert!("alphabetical".parse::<ChartOrder>().is_err());
+    }
+}
@@ -0,0 +1,212 @@
+//! Precision module
+//!
+//! This module implements the partition of variance for replicate designs,
+//! in which each participant reports several results on the same item.
+
+use crate::utils::{CalculationError, mad, median_of, normal_cdf, validate_mad_consistency};
+use ndarray::ArrayView2;
+
+/// Robust repeatability and between-participant standard deviations
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RobustVarianceComponents {
+    /// Repeatability (within-participant) standard deviation s_r
+    pub repeatability_sd: f64,
+    /// Between-participant standard deviation s_L (0 if the means scatter no more than s_r predicts)
+    pub between_sd: f64,
+    /// Robust standard deviation of the participant means
+    pub means_sd: f64,
+    /// Number of replicates per participant
+    pub replicates: usize,
+}
+
+/// Median of the range of `m` independent standard normal values
+///
+/// Solves P(R <= r) = 1/2 with P(R <= r) = m ∫ φ(x) [Φ(x + r) - Φ(x)]^(m-1) dx,
+/// integrated with Simpson's rule over [-10, 10]. For m = 2 this is
+/// √2·Φ⁻¹(3/4) ≈ 0.9539.
+fn median_normal_range(m: usize) -> f64 {
+    const STEPS: usize = 2000;
+    const LIMIT: f64 = 10.0;
+
+    let range_cdf = |r: f64| {
+        let h = 2.0 * LIMIT / STEPS as f64;
+        let integrand = |x: f64| {
+            let density = (-0.5 * x * x).exp() / (2.0 * std::f64::consts::PI).sqrt();
+            density * (normal_cdf(x + r) - normal_cdf(x)).powi(m as i32 - 1)
+        };
+        let sum: f64 = (0..=STEPS)
+            .map(|k| {
+                let weight = if k == 0 || k == STEPS { 1.0 } else if k % 2 == 1 { 4.0 } else { 2.0 };
+                weight * integrand(-LIMIT + k as f64 * h)
+            })
+            .sum();
+        m as f64 * sum * h / 3.0
+    };
+
+    let (mut lower, mut upper) = (0.0, 2.0 * LIMIT);
+    for _ in 0..100 {
+        let mid = 0.5 * (lower + upper);
+        if range_cdf(mid) < 0.5 {
+            lower = mid;
+        } else {
+            upper = mid;
+        }
+        if upper - lower <= 1e-12 {
+            break;
+        }
+    }
+    0.5 * (lower + upper)
+}
+
+/// Robustly partition replicate results into repeatability and between-participant variance
+///
+/// The repeatability SD is the median of the participants' ranges divided by
+/// the median range of that many standard normal values, so that a few
+/// participants with erratic replicates do not inflate it. The participant
+/// means are summarized by their MADe, and the between-participant SD is
+/// s_L = sqrt(max(0, MADe² - s_r² / m)) for m replicates.
+///
+/// # Arguments
+/// * `replicates` - Results with one row per participant and one column per replicate;
+///   NaN marks a missing replicate
+/// * `mad_consistency` - Factor converting the MAD of the means to the MADe
+///   (MAD_TO_SIGMA for normal data)
+///
+/// # Returns
+/// * `Ok(RobustVarianceComponents)` - s_r, s_L and the robust SD of the means
+/// * `Err(CalculationError)` - If a replicate is missing (unequal replicate counts), a value
+///   is infinite, there are fewer than 2 participants or 2 replicates, or
+///   `mad_consistency` is invalid
+pub fn robust_variance_components(
+    replicates: ArrayView2<f64>,
+    mad_consistency: f64,
+) -> Result<RobustVarianceComponents, CalculationError> {
+    validate_mad_consistency(mad_consistency)?;
+    
+    let (p, m) = replicates.dim();
+
+    if p < 2 {
+        return Err(CalculationError::InsufficientData { required: 2, actual: p });
+    }
+
+    if m < 2 {
+        return Err(CalculationError::InvalidInput {
+            message: format!("At least 2 replicates per participant are required, got {}", m),
+        });
+    }
+
+    for ((i, j), &x) in replicates.indexed_iter() {
+        if x.is_nan() {
+            return Err(CalculationError::InvalidInput {
+                message: format!(
+                    "Unequal replicate counts: participant {} is missing replicate {}",
+                    i, j
+                ),
+            });
+        }
+        if x.is_infinite() {
+            return Err(CalculationError::InvalidInput {
+                message: format!("Invalid replicate at ({}, {}): {}", i, j, x),
+            });
+        }
+    }
+
+    let mut ranges = Vec::with_capacity(p);
+    let mut means = Vec::with_capacity(p);
+    for row in replicates.rows() {
+        let min = row.iter().copied().fold(f64::INFINITY, f64::min);
+        let max = row.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+        ranges.push(max - min);
+        means.push(row.sum() / m as f64);
+    }
+
+    let repeatability_sd = median_of(&ranges).unwrap() / median_normal_range(m);
+    let median_mean = median_of(&means).unwrap();
+    let means_sd = mad(&means, median_mean)? * mad_consistency;
+    let between_variance = means_sd * means_sd - repeatability_sd * repeatability_sd / m as f64;
+
+    Ok(RobustVarianceComponents {
+        repeatability_sd,
+        between_sd: between_variance.max(0.0).sqrt(),
+        means_sd,
+        replicates: m,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::{SplitMix64, constants::MAD_TO_SIGMA, normal_quantile};
+    use approx::assert_abs_diff_eq;
+    use ndarray::{array, Array2};
+
+    #[test]
+    fn test_median_normal_range() {
+        let expected = 2.0_f64.sqrt() * normal_quantile(0.75).unwrap();
+        assert_abs_diff_eq!(median_normal_range(2), expected, epsilon = 1e-6);
+
+        // Medians are below the mean ranges d2 = 1.693 and 2.059
+        let (m3, m4) = (median_normal_range(3), median_normal_range(4));
+        assert!(m3 > expected && m3 < 1.693);
+        assert!(m4 > m3 && m4 < 2.059);
+    }
+
+    #[test]
+    fn test_robust_variance_components() {
+        // Duplicates: ranges 0.2, 0.2, 0.4, 0.2, 4.0 (median 0.2); means 10, 11, 12, 13, 14
+        let replicates = array![
+            [9.9, 10.1],
+            [10.9, 11.1],
+            [11.8, 12.2],
+            [12.9, 13.1],
+            [12.0, 16.0],
+        ];
+        let components = robust_variance_components(replicates.view(), MAD_TO_SIGMA).unwrap();
+
+        let s_r = 0.2 / median_normal_range(2);
+        assert_abs_diff_eq!(components.repeatability_sd, s_r, epsilon = 1e-9);
+        assert_abs_diff_eq!(components.means_sd, MAD_TO_SIGMA, epsilon = 1e-12);
+        assert_abs_diff_eq!(
+            components.between_sd,
+            (MAD_TO_SIGMA * MAD_TO_SIGMA - s_r * s_r / 2.0).sqrt(),
+            epsilon = 1e-9
+        );
+        assert_eq!(components.replicates, 2);
+
+        // The MAD of the means is 1, so the constant is the robust SD of the means
+        let custom = robust_variance_components(replicates.view(), 1.0).unwrap();
+        assert_abs_diff_eq!(custom.means_sd, 1.0, epsilon = 1e-12);
+        assert_eq!(custom.repeatability_sd, components.repeatability_sd);
+        assert!(robust_variance_components(replicates.view(), 0.0).is_err());
+    }
+
+    #[test]
+    fn test_robust_variance_components_simulated() {
+        // sigma_L = 2, sigma_r = 0.5, 200 participants with 3 replicates
+        let mut rng = SplitMix64::new(897);
+        let mut replicates = Array2::zeros((200, 3));
+        for mut row in replicates.rows_mut() {
+            let level = 50.0 + 2.0 * rng.next_normal();
+            row.mapv_inplace(|_| level + 0.5 * rng.next_normal());
+        }
+
+        let components = robust_variance_components(replicates.view(), MAD_TO_SIGMA).unwrap();
+        assert!((components.repeatability_sd - 0.5).abs() < 0.06, "{:?}", components);
+        assert!((components.between_sd - 2.0).abs() < 0.3, "{:?}", components);
+    }
+
+    #[test]
+    fn test_robust_variance_components_no_between_variance() {
+        let replicates = array![[10.0, 10.4], [10.2, 9.8], [9.9, 10.3]];
+        let components = robust_variance_components(replicates.view(), MAD_TO_SIGMA).unwrap();
+        assert_eq!(components.between_sd, 0.0);
+    }
+
+    #[test]
+    fn test_robust_variance_components_invalid() {
+        assert!(robust_variance_components(array![[1.0, 2.0]].view(), MAD_TO_SIGMA).is_err());
+        assert!(robust_variance_components(array![[1.0], [2.0]].view(), MAD_TO_SIGMA).is_err());
+        assert!(robust_variance_components(array![[1.0, 2.0], [3.0, f64::NAN]].view(), MAD_TO_SIGMA).is_err());
+        assert!(robust_variance_components(array![[1.0, 2.0], [3.0, f64::INFINITY]].view(), MAD_TO_SIGMA).is_err());
+    }
+}
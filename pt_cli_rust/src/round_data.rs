@@ -0,0 +1,209 @@
+//! Structured round input module
+//!
+//! This module implements a builder that collects each participant's
+//! identifier, result and uncertainty together, so integrators do not have to
+//! keep separate positional arrays aligned.
+
+use crate::utils::{CalculationError, is_valid_float};
+use ndarray::Array1;
+use numpy::PyArray1;
+use pyo3::prelude::*;
+
+/// Participant results of one round, added one participant at a time
+///
+/// Each participant is validated as it is added, so duplicate identifiers and
+/// invalid values are reported at the offending call rather than at analysis.
+#[pyclass]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RoundData {
+    participant_ids: Vec<String>,
+    results: Vec<f64>,
+    uncertainties: Vec<Option<f64>>,
+}
+
+/// Aligned arrays produced by `RoundData::finalize`
+#[derive(Debug, Clone, PartialEq)]
+pub struct RoundArrays {
+    pub participant_ids: Vec<String>,
+    pub results: Array1<f64>,
+    /// Standard uncertainties, present only if every participant reported one
+    pub uncertainties: Option<Array1<f64>>,
+}
+
+impl RoundData {
+    /// Create an empty round
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a participant's result and optional standard uncertainty
+    ///
+    /// # Returns
+    /// * `Ok(&mut Self)` - The round, for chaining
+    /// * `Err(CalculationError)` - If the identifier is taken, the result is not finite
+    ///   or the uncertainty is negative or not finite
+    pub fn add_participant(
+        &mut self,
+        participant_id: String,
+        value: f64,
+        uncertainty: Option<f64>,
+    ) -> Result<&mut Self, CalculationError> {
+        if self.participant_ids.contains(&participant_id) {
+            return Err(CalculationError::InvalidInput {
+                message: format!("Duplicate participant ID '{}'", participant_id),
+            });
+        }
+
+        if !is_valid_float(value) {
+            return Err(CalculationError::InvalidInput {
+                message: format!("Invalid result for participant '{}': {}", participant_id, value),
+            });
+        }
+
+        if let Some(u) = uncertainty {
+            if !is_valid_float(u) || u < 0.0 {
+                return Err(CalculationError::InvalidInput {
+                    message: format!("Invalid uncertainty for participant '{}': {}", participant_id, u),
+                });
+            }
+        }
+
+        self.participant_ids.push(participant_id);
+        self.results.push(value);
+        self.uncertainties.push(uncertainty);
+        Ok(self)
+    }
+
+    /// Validate the round and return its aligned arrays
+    ///
+    /// Uncertainties must be reported by every participant or by none, since
+    /// the scores that use them need one per result.
+    ///
+    /// # Returns
+    /// * `Ok(RoundArrays)` - Identifiers, results and uncertainties in insertion order
+    /// * `Err(CalculationError)` - If the round is empty or only some uncertainties were reported
+    pub fn finalize(&self) -> Result<RoundArrays, CalculationError> {
+        if self.results.is_empty() {
+            return Err(CalculationError::InsufficientData {
+                required: 1,
+                actual: 0,
+            });
+        }
+
+        let reported: Option<Vec<f64>> = self.uncertainties.iter().copied().collect();
+        if reported.is_none() {
+            if let Some(i) = self.uncertainties.iter().position(Option::is_some) {
+                let missing = self.uncertainties.iter().position(Option::is_none).unwrap();
+                return Err(CalculationError::InvalidInput {
+                    message: format!(
+                        "Participant '{}' reported an uncertainty but '{}' did not; report all or none",
+                        self.participant_ids[i], self.participant_ids[missing]
+                    ),
+                });
+            }
+        }
+
+        Ok(RoundArrays {
+            participant_ids: self.participant_ids.clone(),
+            results: Array1::from(self.results.clone()),
+            uncertainties: reported.map(Array1::from),
+        })
+    }
+
+    /// Number of participants added
+    pub fn len(&self) -> usize {
+        self.results.len()
+    }
+
+    /// Whether no participants have been added
+    pub fn is_empty(&self) -> bool {
+        self.results.is_empty()
+    }
+}
+
+/// Convert a Python participant identifier (str or int) to its string form
+pub fn extract_participant_id(id: &PyAny) -> PyResult<String> {
+    if let Ok(id) = id.extract::<String>() {
+        return Ok(id);
+    }
+
+    match id.extract::<i64>() {
+        Ok(id) => Ok(id.to_string()),
+        Err(_) => Err(pyo3::exceptions::PyTypeError::new_err(format!(
+            "Participant IDs must be str or int, got {}",
+            id.get_type().name()?
+        ))),
+    }
+}
+
+/// Python return type of `RoundData.finalize`
+type RoundArraysTuple = (Vec<String>, Py<PyArray1<f64>>, Option<Py<PyArray1<f64>>>);
+
+#[pymethods]
+impl RoundData {
+    fn __len__(&self) -> usize {
+        self.len()
+    }
+
+    /// Add a participant (str or int ID), result and optional standard uncertainty
+    #[pyo3(name = "add_participant")]
+    fn py_add_participant(
+        &mut self,
+        participant_id: &PyAny,
+        value: f64,
+        uncertainty: Option<f64>,
+    ) -> PyResult<()> {
+        self.add_participant(extract_participant_id(participant_id)?, value, uncertainty)?;
+        Ok(())
+    }
+
+    /// Validate and return (participant_ids, results, uncertainties or None)
+    #[pyo3(name = "finalize")]
+    fn py_finalize(&self, py: Python) -> PyResult<RoundArraysTuple> {
+        let arrays = self.finalize()?;
+        Ok((
+            arrays.participant_ids,
+            PyArray1::from_array(py, &arrays.results).to_owned(),
+            arrays.uncertainties.map(|u| PyArray1::from_array(py, &u).to_owned()),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::array;
+
+    #[test]
+    fn test_round_data_finalize() {
+        let mut round = RoundData::new();
+        round.add_participant("LAB001".to_string(), 10.1, Some(0.2)).unwrap()
+            .add_participant("LAB002".to_string(), 9.8, Some(0.3)).unwrap();
+
+        let arrays = round.finalize().unwrap();
+        assert_eq!(arrays.participant_ids, vec!["LAB001", "LAB002"]);
+        assert_eq!(arrays.results, array![10.1, 9.8]);
+        assert_eq!(arrays.uncertainties, Some(array![0.2, 0.3]));
+
+        let mut without_u = RoundData::new();
+        without_u.add_participant("A".to_string(), 1.0, None).unwrap();
+        assert_eq!(without_u.finalize().unwrap().uncertainties, None);
+    }
+
+    #[test]
+    fn test_round_data_invalid() {
+        let mut round = RoundData::new();
+        assert!(round.finalize().is_err());
+
+        round.add_participant("LAB001".to_string(), 10.1, Some(0.2)).unwrap();
+        assert!(round.add_participant("LAB001".to_string(), 9.9, Some(0.2)).is_err());
+        assert!(round.add_participant("LAB002".to_string(), f64::NAN, None).is_err());
+        assert!(round.add_participant("LAB002".to_string(), 9.9, Some(-0.1)).is_err());
+        assert_eq!(round.len(), 1);
+
+        // Mixed reporting of uncertainties is caught at finalize
+        round.add_participant("LAB002".to_string(), 9.9, None).unwrap();
+        let err = round.finalize().unwrap_err();
+        assert!(err.to_string().contains("LAB002"), "{}", err);
+    }
+}
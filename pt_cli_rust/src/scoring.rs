@@ -3,8 +3,85 @@
 //! This module implements the calculation of participant performance scores
 //! (z-scores and zeta-scores) based on calculated assigned values and uncertainties.
 
-use crate::utils::{CalculationError, validate_array_dimensions, validate_floats, is_valid_float};
-use ndarray::{Array1, ArrayView1};
+use crate::utils::{CalculationError, constants::*, huber_weight, validate_array_dimensions,
+                   validate_floats, is_valid_float, grubbs_iterative, GrubbsAlternative, median, normal_quantile,
+                   running_stats, t_cdf};
+use crate::estimators::{calculate_algorithm_a, HUBER_C};
+use crate::round_data::{RoundData, extract_participant_id};
+use crate::uncertainty::RobustConsensus;
+use ndarray::{Array1, ArrayView1, ArrayView2, ArrayViewMut1, Zip};
+use numpy::PyArray1;
+use pyo3::exceptions::PyKeyError;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use serde::{Serialize, Serializer};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::str::FromStr;
+
+/// Handling of negative reported results before scoring
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NegativePolicy {
+    /// Negative results are scored as reported
+    #[default]
+    Allow,
+    /// Negative results are replaced by zero
+    ClampZero,
+    /// Any negative result is an error
+    Reject,
+}
+
+impl FromStr for NegativePolicy {
+    type Err = CalculationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "allow" => Ok(NegativePolicy::Allow),
+            "clamp_zero" => Ok(NegativePolicy::ClampZero),
+            "reject" => Ok(NegativePolicy::Reject),
+            _ => Err(CalculationError::InvalidInput {
+                message: format!(
+                    "Unknown negative policy '{}': expected 'allow', 'clamp_zero' or 'reject'",
+                    s
+                ),
+            }),
+        }
+    }
+}
+
+/// Apply a negative result policy to participant results
+/// 
+/// # Arguments
+/// * `results` - Array view of participant results (x_i)
+/// * `policy` - How negative results are handled
+/// 
+/// # Returns
+/// * `Ok((Array1<f64>, usize))` - Results to score and the number of negative results found
+/// * `Err(CalculationError)` - If a result is not finite, or the policy is `Reject`
+///   and a negative result is present
+pub fn apply_negative_policy(
+    results: ArrayView1<f64>,
+    policy: NegativePolicy,
+) -> Result<(Array1<f64>, usize), CalculationError> {
+    // Validate before clamping, which would otherwise turn NaN into 0
+    validate_floats(results, "participant results")?;
+    
+    let negatives = results.iter().filter(|&&x_i| x_i < 0.0).count();
+    
+    match policy {
+        NegativePolicy::Allow => Ok((results.to_owned(), negatives)),
+        NegativePolicy::ClampZero => Ok((results.mapv(|x_i| x_i.max(0.0)), negatives)),
+        NegativePolicy::Reject if negatives > 0 => {
+            let first = results.iter().position(|&x_i| x_i < 0.0).unwrap();
+            Err(CalculationError::InvalidInput {
+                message: format!(
+                    "{} negative result(s) rejected, first at index {}: {}",
+                    negatives, first, results[first]
+                ),
+            })
+        }
+        NegativePolicy::Reject => Ok((results.to_owned(), 0)),
+    }
+}
 
 /// Calculate z-scores for participant performance assessment
 /// 
@@ -23,8 +100,30 @@ pub fn calculate_z_scores(
     x_pt: f64,
     sigma_pt: f64,
 ) -> Result<Array1<f64>, CalculationError> {
+    let mut z_scores = Array1::zeros(results.len());
+    calculate_z_scores_into(results, x_pt, sigma_pt, &mut z_scores.view_mut())?;
+    Ok(z_scores)
+}
+
+/// Calculate z-scores into a preallocated output array
+/// 
+/// Same as `calculate_z_scores` but writes the scores into `out`, which must
+/// have the same length as `results`. Nothing is written if validation fails.
+/// Arrays of at least `PARALLEL_THRESHOLD` elements are scored in parallel;
+/// the result is bit-identical to the serial path.
+/// 
+/// # Returns
+/// * `Ok(())` - If the scores were written
+/// * `Err(CalculationError)` - If calculation fails
+pub fn calculate_z_scores_into(
+    results: ArrayView1<f64>,
+    x_pt: f64,
+    sigma_pt: f64,
+    out: &mut ArrayViewMut1<f64>,
+) -> Result<(), CalculationError> {
     // Validate inputs
-    validate_floats(&results, "participant results")?;
+    validate_array_dimensions(results.len(), out.len(), "results", "out")?;
+    validate_floats(results, "participant results")?;
     
     if !is_valid_float(x_pt) {
         return Err(CalculationError::InvalidInput {
@@ -39,17 +138,347 @@ pub fn calculate_z_scores(
     }
     
     // Calculate z-scores
-    let z_scores: Vec<f64> = data.iter()
-        .map(|&x_i| (x_i - x_pt) / sigma_pt)
-        .collect();
+    let parallel = results.len() >= PARALLEL_THRESHOLD;
+    z_score_kernel(results, x_pt, sigma_pt, out, parallel);
+    
+    Ok(())
+}
+
+/// Element-wise z-score kernel shared by the serial and parallel paths
+fn z_score_kernel(
+    results: ArrayView1<f64>,
+    x_pt: f64,
+    sigma_pt: f64,
+    out: &mut ArrayViewMut1<f64>,
+    parallel: bool,
+) {
+    let zip = Zip::from(out.view_mut()).and(results);
+    let score = |z: &mut f64, &x_i: &f64| *z = (x_i - x_pt) / sigma_pt;
+    
+    if parallel {
+        zip.par_for_each(score);
+    } else {
+        zip.for_each(score);
+    }
+}
+
+/// Calculate z-scores with different sigma_pt below and above the assigned value
+/// 
+/// Implements z = (x_i - x_pt) / σ_below for x_i < x_pt and
+/// z = (x_i - x_pt) / σ_above for x_i > x_pt. Results equal to x_pt score 0.
+/// 
+/// # Arguments
+/// * `results` - Array view of participant results (x_i)
+/// * `x_pt` - Assigned value
+/// * `sigma_below` - Standard deviation for results below the assigned value
+/// * `sigma_above` - Standard deviation for results above the assigned value
+/// 
+/// # Returns
+/// * `Ok(Array1<f64>)` - Array of z-scores for each participant
+/// * `Err(CalculationError)` - If calculation fails
+pub fn calculate_z_scores_asymmetric(
+    results: ArrayView1<f64>,
+    x_pt: f64,
+    sigma_below: f64,
+    sigma_above: f64,
+) -> Result<Array1<f64>, CalculationError> {
+    validate_floats(results, "participant results")?;
+    
+    if !is_valid_float(x_pt) {
+        return Err(CalculationError::InvalidInput {
+            message: format!("Invalid assigned value x_pt: {}", x_pt),
+        });
+    }
+    
+    for (name, sigma) in [("sigma_below", sigma_below), ("sigma_above", sigma_above)] {
+        if !is_valid_float(sigma) || sigma <= 0.0 {
+            return Err(CalculationError::InvalidInput {
+                message: format!("Invalid or non-positive {}: {}", name, sigma),
+            });
+        }
+    }
+    
+    Ok(results.mapv(|x_i| {
+        let deviation = x_i - x_pt;
+        if deviation < 0.0 {
+            deviation / sigma_below
+        } else if deviation > 0.0 {
+            deviation / sigma_above
+        } else {
+            0.0
+        }
+    }))
+}
+
+/// Calculate z-scores together with a fitness-for-purpose specification check
+/// 
+/// The z-scores use z = (x_i - x_pt) / σ_pt as usual; in addition each result
+/// passes the specification when |x_i - x_pt| <= `spec_tolerance`, for schemes
+/// where sigma_pt is derived from a fitness-for-purpose goal.
+/// 
+/// # Arguments
+/// * `results` - Array view of participant results (x_i)
+/// * `x_pt` - Assigned value
+/// * `sigma_pt` - Standard deviation for proficiency assessment
+/// * `spec_tolerance` - Maximum allowed absolute deviation from x_pt (positive)
+/// 
+/// # Returns
+/// * `Ok((Array1<f64>, Array1<bool>))` - z-scores and whether each result is within specification
+/// * `Err(CalculationError)` - If `spec_tolerance` is not positive or any input is invalid
+pub fn calculate_z_scores_with_spec(
+    results: ArrayView1<f64>,
+    x_pt: f64,
+    sigma_pt: f64,
+    spec_tolerance: f64,
+) -> Result<(Array1<f64>, Array1<bool>), CalculationError> {
+    if !is_valid_float(spec_tolerance) || spec_tolerance <= 0.0 {
+        return Err(CalculationError::InvalidInput {
+            message: format!("Invalid or non-positive specification tolerance: {}", spec_tolerance),
+        });
+    }
+    
+    let z_scores = calculate_z_scores(results, x_pt, sigma_pt)?;
+    let within_spec = results.mapv(|x_i| (x_i - x_pt).abs() <= spec_tolerance);
+    
+    Ok((z_scores, within_spec))
+}
+
+/// z-scores against an assigned value and sigma_pt derived by trimming
+#[derive(Debug, Clone, PartialEq)]
+pub struct TrimmedZScores {
+    /// Trimmed mean used as x_pt
+    pub x_pt: f64,
+    /// Scaled trimmed standard deviation used as sigma_pt
+    pub sigma_pt: f64,
+    /// Number of results trimmed from each end
+    pub n_trimmed: usize,
+    pub z_scores: Array1<f64>,
+}
+
+/// Calculate z-scores with x_pt and sigma_pt from the trimmed results themselves
+/// 
+/// A non-iterative robust alternative to Algorithm A: g = floor(trim_fraction · p)
+/// results are removed from each end, x_pt is the mean of the rest and
+/// sigma_pt is their standard deviation divided by the standard deviation of a
+/// standard normal truncated at ±z, z = Φ⁻¹(1 - g/p), i.e.
+/// sqrt(1 - 2zφ(z) / (1 - 2g/p)), so that it estimates σ for normal data.
+/// All results, including the trimmed ones, are then scored.
+/// 
+/// # Arguments
+/// * `results` - Array view of participant results (x_i)
+/// * `trim_fraction` - Fraction trimmed from each end, in [0, 0.5)
+/// 
+/// # Returns
+/// * `Ok(TrimmedZScores)` - The derived x_pt and sigma_pt with the z-scores
+/// * `Err(CalculationError)` - If fewer than 2 results remain after trimming, they are
+///   identical or an input is invalid
+pub fn calculate_z_scores_trimmed(
+    results: ArrayView1<f64>,
+    trim_fraction: f64,
+) -> Result<TrimmedZScores, CalculationError> {
+    validate_floats(results, "participant results")?;
+    
+    if !(0.0..0.5).contains(&trim_fraction) {
+        return Err(CalculationError::InvalidInput {
+            message: format!("Trim fraction must be in [0, 0.5), got {}", trim_fraction),
+        });
+    }
+    
+    let p = results.len();
+    let n_trimmed = (trim_fraction * p as f64).floor() as usize;
+    if p < 2 * n_trimmed + 2 {
+        return Err(CalculationError::InsufficientData {
+            required: 2 * n_trimmed + 2,
+            actual: p,
+        });
+    }
+    
+    let mut sorted = results.to_vec();
+    sorted.sort_by(|a, b| a.total_cmp(b));
+    let stats = running_stats(&sorted[n_trimmed..p - n_trimmed]);
+    let x_pt = stats.mean().unwrap();
+    
+    let consistency = if n_trimmed == 0 {
+        1.0
+    } else {
+        let gamma = n_trimmed as f64 / p as f64;
+        let z = normal_quantile(1.0 - gamma)?;
+        let density = (-0.5 * z * z).exp() / (2.0 * std::f64::consts::PI).sqrt();
+        (1.0 - 2.0 * z * density / (1.0 - 2.0 * gamma)).sqrt()
+    };
+    let sigma_pt = stats.std_dev().unwrap() / consistency;
+    
+    let z_scores = calculate_z_scores(results, x_pt, sigma_pt)?;
+    
+    Ok(TrimmedZScores { x_pt, sigma_pt, n_trimmed, z_scores })
+}
+
+/// Calculate z-scores with a method-dependent sigma_pt
+/// 
+/// Implements z = (x_i - x_pt) / σ_pt,g where g is the participant's group, for
+/// schemes where the expected precision differs between sub-methods.
+/// 
+/// # Arguments
+/// * `results` - Array view of participant results (x_i)
+/// * `group_ids` - Group label of each participant
+/// * `x_pt` - Assigned value
+/// * `sigma_by_group` - Standard deviation for proficiency assessment of each group
+/// 
+/// # Returns
+/// * `Ok(Array1<f64>)` - Array of z-scores for each participant
+/// * `Err(CalculationError)` - If a group has no sigma_pt or any input is invalid
+pub fn calculate_z_scores_grouped(
+    results: ArrayView1<f64>,
+    group_ids: ArrayView1<i64>,
+    x_pt: f64,
+    sigma_by_group: &HashMap<i64, f64>,
+) -> Result<Array1<f64>, CalculationError> {
+    validate_array_dimensions(results.len(), group_ids.len(), "results", "group_ids")?;
+    validate_floats(results, "participant results")?;
+    
+    if !is_valid_float(x_pt) {
+        return Err(CalculationError::InvalidInput {
+            message: format!("Invalid assigned value x_pt: {}", x_pt),
+        });
+    }
+    
+    for (group, &sigma) in sigma_by_group {
+        if !is_valid_float(sigma) || sigma <= 0.0 {
+            return Err(CalculationError::InvalidInput {
+                message: format!("Invalid or non-positive sigma_pt for group {}: {}", group, sigma),
+            });
+        }
+    }
+    
+    results.iter()
+        .zip(group_ids.iter())
+        .enumerate()
+        .map(|(i, (&x_i, group))| match sigma_by_group.get(group) {
+            Some(&sigma) => Ok((x_i - x_pt) / sigma),
+            None => Err(CalculationError::InvalidInput {
+                message: format!("No sigma_pt for group {} at index {}", group, i),
+            }),
+        })
+        .collect()
+}
+
+/// Calculate z-scores when the assigned value is quoted as an interval
+/// 
+/// Assuming a rectangular distribution over [lower, upper], the assigned value
+/// is the midpoint and u(x_pt) = (upper - lower) / (2√3). The scores include
+/// u(x_pt) in the denominator: z' = (x_i - x_pt) / sqrt(σ_pt² + u(x_pt)²).
+/// 
+/// # Arguments
+/// * `results` - Array view of participant results (x_i)
+/// * `lower` - Lower end of the assigned value interval
+/// * `upper` - Upper end of the assigned value interval
+/// * `sigma_pt` - Standard deviation for proficiency assessment
+/// 
+/// # Returns
+/// * `Ok(Array1<f64>)` - Array of z'-scores for each participant
+/// * `Err(CalculationError)` - If lower >= upper or any input is invalid
+pub fn calculate_z_scores_from_interval(
+    results: ArrayView1<f64>,
+    lower: f64,
+    upper: f64,
+    sigma_pt: f64,
+) -> Result<Array1<f64>, CalculationError> {
+    if !is_valid_float(lower) || !is_valid_float(upper) || lower >= upper {
+        return Err(CalculationError::InvalidInput {
+            message: format!("Invalid assigned value interval [{}, {}]: lower must be below upper", lower, upper),
+        });
+    }
+    
+    if !is_valid_float(sigma_pt) || sigma_pt <= 0.0 {
+        return Err(CalculationError::InvalidInput {
+            message: format!("Invalid or non-positive sigma_pt: {}", sigma_pt),
+        });
+    }
+    
+    let x_pt = 0.5 * (lower + upper);
+    let u_x_pt = (upper - lower) / (2.0 * 3.0_f64.sqrt());
+    
+    calculate_z_scores(results, x_pt, sigma_pt.hypot(u_x_pt))
+}
+
+/// Calculate z-scores on the log10 scale for wide-dynamic-range analytes
+/// 
+/// Implements the formula: z = (log10 x_i - log10 x_pt) / σ_log
+/// 
+/// # Arguments
+/// * `results` - Array view of participant results (x_i), all positive
+/// * `x_pt` - Assigned value (positive, on the original scale)
+/// * `sigma_log` - Standard deviation for proficiency assessment on the log10 scale
+/// 
+/// # Returns
+/// * `Ok(Array1<f64>)` - Array of log-scale z-scores for each participant
+/// * `Err(CalculationError)` - If any result or parameter is invalid
+pub fn calculate_log_z_scores(
+    results: ArrayView1<f64>,
+    x_pt: f64,
+    sigma_log: f64,
+) -> Result<Array1<f64>, CalculationError> {
+    validate_floats(results, "participant results")?;
+    
+    if let Some((i, &x_i)) = results.iter().enumerate().find(|(_, &x_i)| x_i <= 0.0) {
+        return Err(CalculationError::InvalidInput {
+            message: format!("Log-scale scoring requires positive results, got {} at index {}", x_i, i),
+        });
+    }
+    
+    if !is_valid_float(x_pt) || x_pt <= 0.0 {
+        return Err(CalculationError::InvalidInput {
+            message: format!("Log-scale scoring requires a positive assigned value, got {}", x_pt),
+        });
+    }
+    
+    if !is_valid_float(sigma_log) || sigma_log <= 0.0 {
+        return Err(CalculationError::InvalidInput {
+            message: format!("Invalid or non-positive sigma_log: {}", sigma_log),
+        });
+    }
     
-    Ok(Array1::from(z_scores))
+    let log_x_pt = x_pt.log10();
+    Ok(results.mapv(|x_i| (x_i.log10() - log_x_pt) / sigma_log))
+}
+
+/// Convert a log10-scale sigma into multiplicative acceptance limits
+/// 
+/// A log-scale |z| <= z_limit corresponds to results within
+/// [x_pt / factor, x_pt * factor] with factor = 10^(z_limit · σ_log).
+/// 
+/// # Arguments
+/// * `sigma_log` - Standard deviation for proficiency assessment on the log10 scale
+/// * `z_limit` - Score limit, e.g. 2 for the warning limit
+/// 
+/// # Returns
+/// * `Ok((f64, f64))` - Lower and upper multiplicative factors (1/factor, factor)
+/// * `Err(CalculationError)` - If the parameters are invalid
+pub fn log_sigma_acceptance_factors(sigma_log: f64, z_limit: f64) -> Result<(f64, f64), CalculationError> {
+    if !is_valid_float(sigma_log) || sigma_log <= 0.0 {
+        return Err(CalculationError::InvalidInput {
+            message: format!("Invalid or non-positive sigma_log: {}", sigma_log),
+        });
+    }
+    
+    if !is_valid_float(z_limit) || z_limit <= 0.0 {
+        return Err(CalculationError::InvalidInput {
+            message: format!("Invalid or non-positive score limit: {}", z_limit),
+        });
+    }
+    
+    let factor = 10f64.powf(z_limit * sigma_log);
+    Ok((1.0 / factor, factor))
 }
 
 /// Calculate zeta-scores (z'-scores) for participant performance assessment
 /// 
 /// Implements the formula: z' = (x_i - x_pt) / sqrt(u(x_i)^2 + u(x_pt)^2)
 /// 
+/// A participant whose combined uncertainty is zero (u(x_i) = u(x_pt) = 0) gets
+/// a NaN score instead of failing the whole call; use
+/// `calculate_z_prime_scores_masked` to also get the affected participants.
+/// 
 /// # Arguments
 /// * `results` - Array view of participant results (x_i)
 /// * `u_results` - Array view of participant uncertainties (u(x_i))
@@ -65,15 +494,54 @@ pub fn calculate_z_prime_scores(
     x_pt: f64,
     u_x_pt: f64,
 ) -> Result<Array1<f64>, CalculationError> {
-    let data = results.to_vec();
-    let uncertainties = u_results.to_vec();
-    
+    let mut z_prime_scores = Array1::zeros(results.len());
+    calculate_z_prime_scores_into(results, u_results, x_pt, u_x_pt, &mut z_prime_scores.view_mut())?;
+    Ok(z_prime_scores)
+}
+
+/// Calculate zeta-scores and flag participants whose score is undefined
+/// 
+/// Same as `calculate_z_prime_scores`, additionally returning a mask that is
+/// true where u(x_i)^2 + u(x_pt)^2 = 0, i.e. where the score is NaN.
+/// 
+/// # Returns
+/// * `Ok((Array1<f64>, Array1<bool>))` - Zeta-scores and the zero-uncertainty mask
+/// * `Err(CalculationError)` - If calculation fails
+pub fn calculate_z_prime_scores_masked(
+    results: ArrayView1<f64>,
+    u_results: ArrayView1<f64>,
+    x_pt: f64,
+    u_x_pt: f64,
+) -> Result<(Array1<f64>, Array1<bool>), CalculationError> {
+    let z_prime_scores = calculate_z_prime_scores(results, u_results, x_pt, u_x_pt)?;
+    let zero_uncertainty = u_results.mapv(|u_i| u_i.powi(2) + u_x_pt.powi(2) <= 0.0);
+    Ok((z_prime_scores, zero_uncertainty))
+}
+
+/// Calculate zeta-scores into a preallocated output array
+/// 
+/// Same as `calculate_z_prime_scores` but writes the scores into `out`, which
+/// must have the same length as `results`. Nothing is written if validation fails.
+/// Arrays of at least `PARALLEL_THRESHOLD` elements are scored in parallel;
+/// the result is bit-identical to the serial path.
+/// 
+/// # Returns
+/// * `Ok(())` - If the scores were written
+/// * `Err(CalculationError)` - If calculation fails
+pub fn calculate_z_prime_scores_into(
+    results: ArrayView1<f64>,
+    u_results: ArrayView1<f64>,
+    x_pt: f64,
+    u_x_pt: f64,
+    out: &mut ArrayViewMut1<f64>,
+) -> Result<(), CalculationError> {
     // Validate array dimensions
-    validate_array_dimensions(data.len(), uncertainties.len(), "results", "uncertainties")?;
+    validate_array_dimensions(results.len(), u_results.len(), "results", "uncertainties")?;
+    validate_array_dimensions(results.len(), out.len(), "results", "out")?;
     
     // Validate inputs
-    validate_floats(&data, "participant results")?;
-    validate_floats(&uncertainties, "participant uncertainties")?;
+    validate_floats(results, "participant results")?;
+    validate_floats(u_results, "participant uncertainties")?;
     
     if !is_valid_float(x_pt) {
         return Err(CalculationError::InvalidInput {
@@ -87,8 +555,8 @@ pub fn calculate_z_prime_scores(
         });
     }
     
-    // Check for non-negative uncertainties
-    for (i, &u_i) in uncertainties.iter().enumerate() {
+    // Check for non-negative uncertainties before writing any output
+    for (i, &u_i) in u_results.iter().enumerate() {
         if u_i < 0.0 {
             return Err(CalculationError::InvalidInput {
                 message: format!("Negative uncertainty at index {}: {}", i, u_i),
@@ -97,22 +565,82 @@ pub fn calculate_z_prime_scores(
     }
     
     // Calculate zeta-scores
-    let mut z_prime_scores = Vec::with_capacity(data.len());
+    let parallel = results.len() >= PARALLEL_THRESHOLD;
+    z_prime_score_kernel(results, u_results, x_pt, u_x_pt, out, parallel);
     
-    for (i, (&x_i, &u_i)) in data.iter().zip(uncertainties.iter()).enumerate() {
-        let combined_uncertainty_squared = u_i.powi(2) + u_x_pt.powi(2);
-        
-        if combined_uncertainty_squared <= 0.0 {
-            return Err(CalculationError::DivisionByZero);
+    Ok(())
+}
+
+/// Element-wise zeta-score kernel shared by the serial and parallel paths
+fn z_prime_score_kernel(
+    results: ArrayView1<f64>,
+    u_results: ArrayView1<f64>,
+    x_pt: f64,
+    u_x_pt: f64,
+    out: &mut ArrayViewMut1<f64>,
+    parallel: bool,
+) {
+    let u_x_pt_squared = u_x_pt.powi(2);
+    let zip = Zip::from(out.view_mut()).and(results).and(u_results);
+    let score = |z_prime: &mut f64, &x_i: &f64, &u_i: &f64| {
+        let combined_uncertainty_squared = u_i.powi(2) + u_x_pt_squared;
+        *z_prime = if combined_uncertainty_squared > 0.0 {
+            (x_i - x_pt) / combined_uncertainty_squared.sqrt()
+        } else {
+            f64::NAN
+        };
+    };
+    
+    if parallel {
+        zip.par_for_each(score);
+    } else {
+        zip.for_each(score);
+    }
+}
+
+/// Calculate zeta-scores from participant uncertainties stated as percentages
+/// 
+/// Each relative uncertainty is converted to an absolute standard uncertainty
+/// u(x_i) = |x_i| * u_rel,i / 100 before applying the zeta formula, so 2.5 means
+/// 2.5 % of the participant's own result (not 0.025). Use `calculate_z_prime_scores`
+/// for absolute uncertainties; mixed inputs must be converted before calling.
+/// 
+/// # Arguments
+/// * `results` - Array view of participant results (x_i)
+/// * `u_results_percent` - Array view of relative uncertainties in percent
+/// * `x_pt` - Assigned value
+/// * `u_x_pt` - Uncertainty of the assigned value (absolute)
+/// 
+/// # Returns
+/// * `Ok(Array1<f64>)` - Array of zeta-scores for each participant
+/// * `Err(CalculationError)` - If calculation fails
+pub fn calculate_z_prime_scores_relative(
+    results: ArrayView1<f64>,
+    u_results_percent: ArrayView1<f64>,
+    x_pt: f64,
+    u_x_pt: f64,
+) -> Result<Array1<f64>, CalculationError> {
+    let data = results.to_vec();
+    let percentages = u_results_percent.to_vec();
+    
+    validate_array_dimensions(data.len(), percentages.len(), "results", "relative uncertainties")?;
+    validate_floats(&data, "participant results")?;
+    validate_floats(&percentages, "participant relative uncertainties")?;
+    
+    for (i, &percent) in percentages.iter().enumerate() {
+        if percent < 0.0 {
+            return Err(CalculationError::InvalidInput {
+                message: format!("Negative relative uncertainty at index {}: {}", i, percent),
+            });
         }
-        
-        let combined_uncertainty = combined_uncertainty_squared.sqrt();
-        let z_prime = (x_i - x_pt) / combined_uncertainty;
-        
-        z_prime_scores.push(z_prime);
     }
     
-    Ok(Array1::from(z_prime_scores))
+    let absolute: Array1<f64> = data.iter()
+        .zip(percentages.iter())
+        .map(|(&x_i, &percent)| x_i.abs() * percent / 100.0)
+        .collect();
+    
+    calculate_z_prime_scores(results, absolute.view(), x_pt, u_x_pt)
 }
 
 /// Calculate zeta-scores when participant uncertainties are zero or missing
@@ -157,10 +685,470 @@ pub fn calculate_z_prime_scores_no_participant_uncertainties(
     Ok(Array1::from(z_prime_scores))
 }
 
-/// Interpret z-score performance according to ISO 13528:2022
+/// Calculate robust standardized residuals for Algorithm A diagnostics
+/// 
+/// Implements the formula: r_i = (x_i - x*) / s*
+/// 
+/// where x* and s* are the robust average and robust standard deviation from
+/// Algorithm A. Unlike z-scores, which are scaled by the scheme's sigma_pt,
+/// these residuals are scaled by the robust dispersion of the data itself and
+/// are intended for diagnostic plots rather than performance assessment.
 /// 
 /// # Arguments
-/// * `z_score` - The calculated z-score
+/// * `results` - Array view of participant results (x_i)
+/// * `x_pt` - Robust average (x*)
+/// * `s_star` - Robust standard deviation (s*)
+/// 
+/// # Returns
+/// * `Ok(Array1<f64>)` - Array of standardized residuals for each participant
+/// * `Err(CalculationError)` - If calculation fails
+pub fn standardized_residuals(
+    results: ArrayView1<f64>,
+    x_pt: f64,
+    s_star: f64,
+) -> Result<Array1<f64>, CalculationError> {
+    let data = results.to_vec();
+    
+    // Validate inputs
+    validate_floats(&data, "participant results")?;
+    
+    if !is_valid_float(x_pt) {
+        return Err(CalculationError::InvalidInput {
+            message: format!("Invalid robust average x*: {}", x_pt),
+        });
+    }
+    
+    if !is_valid_float(s_star) || s_star <= 0.0 {
+        return Err(CalculationError::InvalidInput {
+            message: format!("Invalid or non-positive robust standard deviation s*: {}", s_star),
+        });
+    }
+    
+    let residuals: Vec<f64> = data.iter()
+        .map(|&x_i| (x_i - x_pt) / s_star)
+        .collect();
+    
+    Ok(Array1::from(residuals))
+}
+
+/// Calculate the propagated standard uncertainty of each z-score
+/// 
+/// First-order propagation of z = (x_i - x_pt) / σ_pt gives
+/// u(z_i)² = (u(x_i)² + u(x_pt)²) / σ_pt² + z_i² · (u(σ_pt) / σ_pt)²
+/// 
+/// # Arguments
+/// * `results` - Array view of participant results (x_i)
+/// * `u_results` - Array view of participant standard uncertainties; NaN marks a missing value
+/// * `x_pt` - Assigned value
+/// * `u_x_pt` - Standard uncertainty of the assigned value
+/// * `sigma_pt` - Standard deviation for proficiency assessment
+/// * `u_sigma_pt` - Optional standard uncertainty of sigma_pt
+/// * `missing_as_zero` - Treat missing participant uncertainties as zero instead of failing
+/// 
+/// # Returns
+/// * `Ok(Array1<f64>)` - Array of u(z) for each participant
+/// * `Err(CalculationError)` - If any input is invalid
+pub fn z_score_uncertainties(
+    results: ArrayView1<f64>,
+    u_results: ArrayView1<f64>,
+    x_pt: f64,
+    u_x_pt: f64,
+    sigma_pt: f64,
+    u_sigma_pt: Option<f64>,
+    missing_as_zero: bool,
+) -> Result<Array1<f64>, CalculationError> {
+    validate_array_dimensions(results.len(), u_results.len(), "results", "u_results")?;
+    validate_floats(results, "participant results")?;
+    
+    if !is_valid_float(x_pt) {
+        return Err(CalculationError::InvalidInput {
+            message: format!("Invalid assigned value x_pt: {}", x_pt),
+        });
+    }
+    
+    if !is_valid_float(u_x_pt) || u_x_pt < 0.0 {
+        return Err(CalculationError::InvalidInput {
+            message: format!("Invalid or negative u(x_pt): {}", u_x_pt),
+        });
+    }
+    
+    if !is_valid_float(sigma_pt) || sigma_pt <= 0.0 {
+        return Err(CalculationError::InvalidInput {
+            message: format!("Invalid or non-positive sigma_pt: {}", sigma_pt),
+        });
+    }
+    
+    let u_sigma_pt = u_sigma_pt.unwrap_or(0.0);
+    if !is_valid_float(u_sigma_pt) || u_sigma_pt < 0.0 {
+        return Err(CalculationError::InvalidInput {
+            message: format!("Invalid or negative u(sigma_pt): {}", u_sigma_pt),
+        });
+    }
+    
+    let mut u_participants = Vec::with_capacity(u_results.len());
+    for (i, &u_i) in u_results.iter().enumerate() {
+        if u_i.is_nan() && missing_as_zero {
+            u_participants.push(0.0);
+        } else if u_i.is_nan() {
+            return Err(CalculationError::InvalidInput {
+                message: format!("Missing participant uncertainty at index {}", i),
+            });
+        } else if u_i.is_infinite() || u_i < 0.0 {
+            return Err(CalculationError::InvalidInput {
+                message: format!("Invalid participant uncertainty at index {}: {}", i, u_i),
+            });
+        } else {
+            u_participants.push(u_i);
+        }
+    }
+    
+    let relative_u_sigma = u_sigma_pt / sigma_pt;
+    let uncertainties: Vec<f64> = results.iter()
+        .zip(u_participants.iter())
+        .map(|(&x_i, &u_i)| {
+            let z = (x_i - x_pt) / sigma_pt;
+            let variance = (u_i.powi(2) + u_x_pt.powi(2)) / sigma_pt.powi(2)
+                + (z * relative_u_sigma).powi(2);
+            variance.sqrt()
+        })
+        .collect();
+    
+    Ok(Array1::from(uncertainties))
+}
+
+/// Summary statistics of a set of z-scores
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct ZScoreDiagnostics {
+    /// Number of scores counted (NaN scores are skipped)
+    pub n: usize,
+    pub mean: f64,
+    /// Sample standard deviation of the scores
+    pub sd: f64,
+    /// Fraction of scores with |z| > 2
+    pub fraction_above_2: f64,
+    /// Fraction of scores with |z| > 3
+    pub fraction_above_3: f64,
+}
+
+/// Summarise the distribution of a round's z-scores
+/// 
+/// For a well-calibrated sigma_pt and normally distributed results the mean is
+/// about 0, the SD about 1, and about 4.6 % and 0.3 % of the scores exceed 2
+/// and 3 in absolute value. An SD well above 1 suggests sigma_pt is too small,
+/// one well below 1 that it is too large. NaN scores are not counted.
+/// 
+/// # Arguments
+/// * `z_scores` - Array view of z-scores
+/// 
+/// # Returns
+/// * `Ok(ZScoreDiagnostics)` - Summary statistics and the number of scores counted
+/// * `Err(CalculationError)` - If a score is infinite or fewer than two are counted
+pub fn z_score_diagnostics(z_scores: ArrayView1<f64>) -> Result<ZScoreDiagnostics, CalculationError> {
+    let scores: Vec<f64> = z_scores.iter().copied().filter(|z| !z.is_nan()).collect();
+    validate_floats(&scores, "z-scores")?;
+    
+    if scores.len() < 2 {
+        return Err(CalculationError::InsufficientData {
+            required: 2,
+            actual: scores.len(),
+        });
+    }
+    
+    let n = scores.len() as f64;
+    let mean = scores.iter().sum::<f64>() / n;
+    let sd = (scores.iter().map(|&z| (z - mean).powi(2)).sum::<f64>() / (n - 1.0)).sqrt();
+    let fraction_above = |limit: f64| scores.iter().filter(|z| z.abs() > limit).count() as f64 / n;
+    
+    Ok(ZScoreDiagnostics {
+        n: scores.len(),
+        mean,
+        sd,
+        fraction_above_2: fraction_above(2.0),
+        fraction_above_3: fraction_above(3.0),
+    })
+}
+
+/// Proportion of unsatisfactory scores with its Wilson score interval
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct UnsatisfactoryRate {
+    /// Number of scores counted (NaN scores are skipped)
+    pub n: usize,
+    /// Number of scores with |z| above the threshold
+    pub count: usize,
+    /// Point estimate count / n
+    pub rate: f64,
+    pub lower: f64,
+    pub upper: f64,
+}
+
+/// Calculate the unsatisfactory rate of a round with a Wilson score interval
+/// 
+/// A score is unsatisfactory when |z| > `threshold`, matching the action
+/// limit convention of `ScoreLimits`. The Wilson interval stays within [0, 1]
+/// and keeps its coverage for rates near 0, unlike the normal approximation.
+/// NaN scores are not counted.
+/// 
+/// # Arguments
+/// * `z_scores` - Array view of z-scores
+/// * `threshold` - Action limit (e.g. 3.0)
+/// * `confidence` - Two-sided confidence level of the interval, in (0, 1)
+/// 
+/// # Returns
+/// * `Ok(UnsatisfactoryRate)` - Count, rate and interval bounds
+/// * `Err(CalculationError)` - If a score is infinite, no score is counted or a parameter is invalid
+pub fn unsatisfactory_rate(
+    z_scores: ArrayView1<f64>,
+    threshold: f64,
+    confidence: f64,
+) -> Result<UnsatisfactoryRate, CalculationError> {
+    if !is_valid_float(threshold) || threshold <= 0.0 {
+        return Err(CalculationError::InvalidInput {
+            message: format!("Invalid or non-positive threshold: {}", threshold),
+        });
+    }
+    
+    if !(confidence > 0.0 && confidence < 1.0) {
+        return Err(CalculationError::InvalidInput {
+            message: format!("Confidence level must be in (0, 1), got {}", confidence),
+        });
+    }
+    
+    let scores: Vec<f64> = z_scores.iter().copied().filter(|z| !z.is_nan()).collect();
+    validate_floats(&scores, "z-scores")?;
+    
+    if scores.is_empty() {
+        return Err(CalculationError::InsufficientData {
+            required: 1,
+            actual: 0,
+        });
+    }
+    
+    let count = scores.iter().filter(|z| z.abs() > threshold).count();
+    let n = scores.len() as f64;
+    let rate = count as f64 / n;
+    
+    let z = normal_quantile(0.5 + confidence / 2.0)?;
+    let z2 = z * z;
+    let denominator = 1.0 + z2 / n;
+    let center = (rate + z2 / (2.0 * n)) / denominator;
+    let half_width = z / denominator * (rate * (1.0 - rate) / n + z2 / (4.0 * n * n)).sqrt();
+    
+    Ok(UnsatisfactoryRate {
+        n: scores.len(),
+        count,
+        rate,
+        lower: (center - half_width).max(0.0),
+        upper: (center + half_width).min(1.0),
+    })
+}
+
+/// Tie-breaking rule used when rounding scores for reporting
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RoundingMode {
+    /// Ties go to the nearest even digit (banker's rounding)
+    #[default]
+    HalfEven,
+    /// Ties go away from zero
+    HalfUp,
+}
+
+impl FromStr for RoundingMode {
+    type Err = CalculationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "half_even" => Ok(RoundingMode::HalfEven),
+            "half_up" => Ok(RoundingMode::HalfUp),
+            _ => Err(CalculationError::InvalidInput {
+                message: format!("Unknown rounding mode '{}': expected 'half_even' or 'half_up'", s),
+            }),
+        }
+    }
+}
+
+/// Floating-point type of score arrays returned to callers
+/// 
+/// Scores are always computed in f64; `Float32` only narrows the output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ScoreDtype {
+    #[default]
+    Float64,
+    Float32,
+}
+
+impl FromStr for ScoreDtype {
+    type Err = CalculationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "float64" => Ok(ScoreDtype::Float64),
+            "float32" => Ok(ScoreDtype::Float32),
+            _ => Err(CalculationError::InvalidInput {
+                message: format!("Unknown dtype '{}': expected 'float64' or 'float32'", s),
+            }),
+        }
+    }
+}
+
+/// Round scores to a reporting precision
+/// 
+/// Values are treated as the decimals they were written as, so 2.005 is a tie
+/// at two decimals even though its binary representation is slightly below it.
+/// NaN scores are passed through unchanged.
+/// 
+/// # Arguments
+/// * `scores` - Array view of scores
+/// * `decimals` - Number of decimal places (at most 9)
+/// * `mode` - Tie-breaking rule
+/// 
+/// # Returns
+/// * `Ok(Array1<f64>)` - Rounded scores
+/// * `Err(CalculationError)` - If `decimals` is too large
+pub fn round_scores(
+    scores: ArrayView1<f64>,
+    decimals: u32,
+    mode: RoundingMode,
+) -> Result<Array1<f64>, CalculationError> {
+    if decimals > 9 {
+        return Err(CalculationError::InvalidInput {
+            message: format!("At most 9 decimals are supported, got {}", decimals),
+        });
+    }
+    
+    let factor = 10f64.powi(decimals as i32);
+    
+    Ok(scores.mapv(|score| {
+        let mut scaled = score * factor;
+        
+        // Snap to the half-step only when the gap is within a few ulps, which
+        // is binary representation error rather than a genuinely different score
+        let half_step = scaled.floor() + 0.5;
+        if (scaled - half_step).abs() <= 4.0 * f64::EPSILON * scaled.abs().max(1.0) {
+            scaled = half_step;
+        }
+        
+        let rounded = match mode {
+            RoundingMode::HalfEven => scaled.round_ties_even(),
+            RoundingMode::HalfUp => scaled.round(),
+        };
+        rounded / factor
+    }))
+}
+
+/// Rank participants by the absolute value of their score
+/// 
+/// Rank 0 is the smallest |score|. Exact ties keep their input order, so the
+/// participant with the lower index gets the better rank, and NaN scores are
+/// ranked after all others (infinite scores rank just before them).
+/// 
+/// # Arguments
+/// * `scores` - Array view of scores
+/// 
+/// # Returns
+/// * `Array1<usize>` - The 0-based rank of each participant, aligned with the input
+pub fn rank_by_abs_score(scores: ArrayView1<f64>) -> Array1<usize> {
+    let mut order: Vec<usize> = (0..scores.len()).collect();
+    
+    // Stable sort, so equal keys stay in index order
+    order.sort_by(|&a, &b| {
+        let (za, zb) = (scores[a], scores[b]);
+        za.is_nan().cmp(&zb.is_nan())
+            .then_with(|| za.abs().total_cmp(&zb.abs()))
+    });
+    
+    let mut ranks = Array1::zeros(scores.len());
+    for (rank, &index) in order.iter().enumerate() {
+        ranks[index] = rank;
+    }
+    ranks
+}
+
+/// Warning and action limits used to classify performance scores
+///
+/// Scores with |score| <= warning are satisfactory, warning < |score| <= action
+/// are questionable, and |score| > action are unsatisfactory.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ScoreLimits {
+    pub warning: f64,
+    pub action: f64,
+}
+
+impl ScoreLimits {
+    /// Create a new set of limits, validating that 0 < warning <= action
+    pub fn new(warning: f64, action: f64) -> Result<Self, CalculationError> {
+        if !is_valid_float(warning) || warning <= 0.0 {
+            return Err(CalculationError::InvalidInput {
+                message: format!("Invalid or non-positive warning limit: {}", warning),
+            });
+        }
+        
+        if !is_valid_float(action) || action < warning {
+            return Err(CalculationError::InvalidInput {
+                message: format!(
+                    "Invalid action limit: {} (must be finite and >= warning limit {})",
+                    action, warning
+                ),
+            });
+        }
+        
+        Ok(ScoreLimits { warning, action })
+    }
+}
+
+impl Default for ScoreLimits {
+    /// The ISO 13528:2022 limits for z-scores (2 and 3)
+    fn default() -> Self {
+        ScoreLimits {
+            warning: 2.0,
+            action: 3.0,
+        }
+    }
+}
+
+/// Performance category assigned to a single score
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScoreCategory {
+    Satisfactory,
+    Questionable,
+    Unsatisfactory,
+    NotEvaluated,
+}
+
+impl ScoreCategory {
+    /// Label used in reports, matching the strings returned by `interpret_z_score`
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ScoreCategory::Satisfactory => "Satisfactory",
+            ScoreCategory::Questionable => "Questionable",
+            ScoreCategory::Unsatisfactory => "Unsatisfactory",
+            ScoreCategory::NotEvaluated => "NotEvaluated",
+        }
+    }
+}
+
+/// Classify a score against the given limits
+///
+/// NaN scores are reported as `NotEvaluated` rather than being classified.
+pub fn classify_score(score: f64, limits: &ScoreLimits) -> ScoreCategory {
+    if score.is_nan() {
+        return ScoreCategory::NotEvaluated;
+    }
+    
+    let abs_score = score.abs();
+    
+    if abs_score <= limits.warning {
+        ScoreCategory::Satisfactory
+    } else if abs_score <= limits.action {
+        ScoreCategory::Questionable
+    } else {
+        ScoreCategory::Unsatisfactory
+    }
+}
+
+/// Interpret z-score performance according to ISO 13528:2022
+/// 
+/// # Arguments
+/// * `z_score` - The calculated z-score
 /// 
 /// # Returns
 /// * String describing the performance level
@@ -174,156 +1162,2640 @@ pub fn interpret_z_score(z_score: f64) -> String {
     } else {
         "Unsatisfactory".to_string()
     }
-}
+}
+
+/// Interpret zeta-score performance according to ISO 13528:2022
+/// 
+/// # Arguments
+/// * `z_prime_score` - The calculated zeta-score
+/// 
+/// # Returns
+/// * String describing the performance level
+pub fn interpret_z_prime_score(z_prime_score: f64) -> String {
+    let abs_z_prime = z_prime_score.abs();
+    
+    if abs_z_prime <= 2.0 {
+        "Satisfactory".to_string()
+    } else {
+        "Unsatisfactory".to_string()
+    }
+}
+
+/// Interpret zeta-score performance with configurable limits
+/// 
+/// With warning < action this gives the three-level structure used for z-scores
+/// (e.g. 2/3); with warning == action it reduces to a two-level pass/fail scheme
+/// (e.g. 1/1 as used for En). `interpret_z_prime_score` corresponds to 2/2.
+/// NaN scores are reported as "NotEvaluated".
+/// 
+/// # Arguments
+/// * `z_prime_score` - The calculated zeta-score
+/// * `warning` - Limit up to which |zeta| is satisfactory
+/// * `action` - Limit above which |zeta| is unsatisfactory
+/// 
+/// # Returns
+/// * `Ok(String)` - Description of the performance level
+/// * `Err(CalculationError)` - If the limits are invalid
+pub fn interpret_zeta_score_with_limits(
+    z_prime_score: f64,
+    warning: f64,
+    action: f64,
+) -> Result<String, CalculationError> {
+    let limits = ScoreLimits::new(warning, action)?;
+    Ok(classify_score(z_prime_score, &limits).as_str().to_string())
+}
+
+/// Interpret an array of zeta-scores with configurable limits
+/// 
+/// Vectorized form of `interpret_zeta_score_with_limits`.
+/// 
+/// # Returns
+/// * `Ok(Vec<String>)` - Performance level for each participant
+/// * `Err(CalculationError)` - If the limits are invalid
+pub fn interpret_zeta_scores_with_limits(
+    z_prime_scores: ArrayView1<f64>,
+    warning: f64,
+    action: f64,
+) -> Result<Vec<String>, CalculationError> {
+    let limits = ScoreLimits::new(warning, action)?;
+    
+    Ok(z_prime_scores.iter()
+        .map(|&score| classify_score(score, &limits).as_str().to_string())
+        .collect())
+}
+
+/// Joint interpretation of a participant's z-score and zeta-score
+/// 
+/// Combines the three-level z-score category with the two-level zeta-score
+/// category into one of six matrix cells, each with an advisory for the report.
+/// For example, a satisfactory z with an unsatisfactory zeta suggests the
+/// participant has underestimated their measurement uncertainty.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct JointInterpretation {
+    pub z_category: ScoreCategory,
+    pub zeta_category: ScoreCategory,
+}
+
+impl JointInterpretation {
+    /// Matrix cell code: 2 * z_row + zeta_column (0-5), or -1 if not evaluated
+    /// 
+    /// Rows are Satisfactory/Questionable/Unsatisfactory z-scores and columns
+    /// are Satisfactory/Unsatisfactory zeta-scores.
+    pub fn code(&self) -> i64 {
+        let row = match self.z_category {
+            ScoreCategory::Satisfactory => 0,
+            ScoreCategory::Questionable => 1,
+            ScoreCategory::Unsatisfactory => 2,
+            ScoreCategory::NotEvaluated => return -1,
+        };
+        let column = match self.zeta_category {
+            ScoreCategory::Satisfactory => 0,
+            ScoreCategory::Unsatisfactory => 1,
+            _ => return -1,
+        };
+        2 * row + column
+    }
+    
+    /// Human-readable advisory for the matrix cell
+    pub fn advisory(&self) -> &'static str {
+        match self.code() {
+            0 => "No action required",
+            1 => "Review stated measurement uncertainty (likely underestimated)",
+            2 => "Monitor result; deviation is covered by stated measurement uncertainty",
+            3 => "Investigate result and review stated measurement uncertainty",
+            4 => "Investigate result; stated measurement uncertainty may be overestimated",
+            5 => "Investigate result; corrective action required",
+            _ => "Not evaluated",
+        }
+    }
+}
+
+/// Jointly interpret z-scores and zeta-scores per ISO 13528 guidance
+/// 
+/// z-scores are classified with the 2/3 limits and zeta-scores with the
+/// two-level |zeta| <= 2 rule. A NaN in either input gives a NotEvaluated result.
+/// 
+/// # Arguments
+/// * `z_scores` - Array view of z-scores
+/// * `zeta_scores` - Array view of zeta-scores for the same participants
+/// 
+/// # Returns
+/// * `Ok(Vec<JointInterpretation>)` - Joint interpretation for each participant
+/// * `Err(CalculationError)` - If the arrays have different lengths
+pub fn joint_interpretation(
+    z_scores: ArrayView1<f64>,
+    zeta_scores: ArrayView1<f64>,
+) -> Result<Vec<JointInterpretation>, CalculationError> {
+    validate_array_dimensions(z_scores.len(), zeta_scores.len(), "z_scores", "zeta_scores")?;
+    
+    let z_limits = ScoreLimits::default();
+    let zeta_limits = ScoreLimits { warning: 2.0, action: 2.0 };
+    
+    Ok(z_scores.iter()
+        .zip(zeta_scores.iter())
+        .map(|(&z, &zeta)| {
+            if z.is_nan() || zeta.is_nan() {
+                JointInterpretation {
+                    z_category: ScoreCategory::NotEvaluated,
+                    zeta_category: ScoreCategory::NotEvaluated,
+                }
+            } else {
+                JointInterpretation {
+                    z_category: classify_score(z, &z_limits),
+                    zeta_category: classify_score(zeta, &zeta_limits),
+                }
+            }
+        })
+        .collect())
+}
+
+/// A participant whose zeta-score and En number disagree
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ScoreDiscrepancy {
+    pub index: usize,
+    /// zeta - k·En
+    pub discrepancy: f64,
+}
+
+/// Cross-check zeta-scores against En numbers
+/// 
+/// With expanded uncertainties U = k·u, zeta = k·En, so a participant with
+/// |zeta - k·En| > tolerance has most likely mixed up standard and expanded
+/// uncertainties. Participants with a NaN in either score are not checked.
+/// 
+/// # Arguments
+/// * `zeta` - Array view of zeta-scores
+/// * `en` - Array view of En numbers for the same participants
+/// * `k` - Coverage factor used for the expanded uncertainties
+/// * `tolerance` - Largest acceptable absolute discrepancy
+/// 
+/// # Returns
+/// * `Ok(Vec<ScoreDiscrepancy>)` - Flagged participants in index order
+/// * `Err(CalculationError)` - If the arrays differ in length or a parameter is invalid
+pub fn cross_check_scores(
+    zeta: ArrayView1<f64>,
+    en: ArrayView1<f64>,
+    k: f64,
+    tolerance: f64,
+) -> Result<Vec<ScoreDiscrepancy>, CalculationError> {
+    validate_array_dimensions(zeta.len(), en.len(), "zeta", "en")?;
+    
+    if !is_valid_float(k) || k <= 0.0 {
+        return Err(CalculationError::InvalidInput {
+            message: format!("Invalid or non-positive coverage factor k: {}", k),
+        });
+    }
+    
+    if !is_valid_float(tolerance) || tolerance < 0.0 {
+        return Err(CalculationError::InvalidInput {
+            message: format!("Invalid or negative tolerance: {}", tolerance),
+        });
+    }
+    
+    Ok(zeta.iter()
+        .zip(en.iter())
+        .enumerate()
+        .filter_map(|(index, (&zeta_i, &en_i))| {
+            let discrepancy = zeta_i - k * en_i;
+            (discrepancy.abs() > tolerance).then_some(ScoreDiscrepancy { index, discrepancy })
+        })
+        .collect())
+}
+
+/// Sensitivity of a participant's classification to the assigned value uncertainty
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BoundarySensitivity {
+    /// Whether the category differs somewhere within the z-score interval
+    pub flagged: bool,
+    /// Lower end of the z-score interval
+    pub z_lower: f64,
+    /// Upper end of the z-score interval
+    pub z_upper: f64,
+}
+
+/// Flag scores whose classification could change within the uncertainty of x_pt
+/// 
+/// For each participant the z-score interval implied by x_pt ± 2·u(x_pt) is
+/// z ± 2·u(x_pt)/σ_pt. A participant is flagged for manual review when the
+/// category is not the same across the whole interval. NaN scores are never
+/// flagged and have NaN interval endpoints.
+/// 
+/// # Arguments
+/// * `z_scores` - Array view of z-scores
+/// * `u_x_pt` - Standard uncertainty of the assigned value
+/// * `sigma_pt` - Standard deviation for proficiency assessment
+/// * `limits` - Warning and action limits
+/// 
+/// # Returns
+/// * `Ok(Vec<BoundarySensitivity>)` - Flag and interval for each participant
+/// * `Err(CalculationError)` - If any parameter is invalid
+pub fn boundary_sensitivity(
+    z_scores: ArrayView1<f64>,
+    u_x_pt: f64,
+    sigma_pt: f64,
+    limits: &ScoreLimits,
+) -> Result<Vec<BoundarySensitivity>, CalculationError> {
+    if !is_valid_float(u_x_pt) || u_x_pt < 0.0 {
+        return Err(CalculationError::InvalidInput {
+            message: format!("Invalid or negative u(x_pt): {}", u_x_pt),
+        });
+    }
+    
+    if !is_valid_float(sigma_pt) || sigma_pt <= 0.0 {
+        return Err(CalculationError::InvalidInput {
+            message: format!("Invalid or non-positive sigma_pt: {}", sigma_pt),
+        });
+    }
+    
+    let half_width = 2.0 * u_x_pt / sigma_pt;
+    let band_edges = [-limits.action, -limits.warning, limits.warning, limits.action];
+    
+    Ok(z_scores.iter()
+        .map(|&z| {
+            let (z_lower, z_upper) = (z - half_width, z + half_width);
+            if z.is_nan() {
+                return BoundarySensitivity { flagged: false, z_lower, z_upper };
+            }
+            
+            // A band edge strictly inside the interval, or endpoints falling in
+            // different categories, means the classification can flip
+            let flagged = band_edges.iter().any(|&edge| z_lower < edge && edge < z_upper)
+                || classify_score(z_lower, limits) != classify_score(z_upper, limits);
+            
+            BoundarySensitivity { flagged, z_lower, z_upper }
+        })
+        .collect())
+}
+
+/// Whether two results agree within a relative tolerance (exact equality when 0)
+fn results_match(a: f64, b: f64, relative_tolerance: f64) -> bool {
+    if relative_tolerance == 0.0 {
+        a == b
+    } else {
+        (a - b).abs() <= relative_tolerance * a.abs().max(b.abs())
+    }
+}
+
+/// Validate a relative tolerance used for identical-result screening
+fn validate_relative_tolerance(relative_tolerance: f64) -> Result<(), CalculationError> {
+    if !is_valid_float(relative_tolerance) || relative_tolerance < 0.0 {
+        return Err(CalculationError::InvalidInput {
+            message: format!("Invalid relative tolerance: {}", relative_tolerance),
+        });
+    }
+    Ok(())
+}
+
+/// Group participants connected by pairwise matches
+/// 
+/// Only groups with at least two participants are returned, each sorted by
+/// index and ordered by their first participant.
+fn matching_groups(n: usize, is_match: impl Fn(usize, usize) -> bool) -> Vec<Vec<usize>> {
+    let mut group_of: Vec<usize> = (0..n).collect();
+    
+    for i in 0..n {
+        for j in (i + 1)..n {
+            if is_match(i, j) {
+                let (old, new) = (group_of[j].max(group_of[i]), group_of[j].min(group_of[i]));
+                for group in group_of.iter_mut().filter(|g| **g == old) {
+                    *group = new;
+                }
+            }
+        }
+    }
+    
+    (0..n)
+        .map(|root| (0..n).filter(|&i| group_of[i] == root).collect::<Vec<usize>>())
+        .filter(|group| group.len() > 1)
+        .collect()
+}
+
+/// Screen for identical or near-identical results between participants
+/// 
+/// Two results match when |a - b| <= relative_tolerance * max(|a|, |b|); a
+/// tolerance of 0 requires exact equality. Matches are transitive, so a chain
+/// of pairwise matches forms a single group.
+/// 
+/// # Arguments
+/// * `results` - Array view of participant results
+/// * `relative_tolerance` - Relative tolerance for a match (0 for exact equality)
+/// 
+/// # Returns
+/// * `Ok(Vec<Vec<usize>>)` - Groups of participant indices with matching results
+/// * `Err(CalculationError)` - If the input is invalid
+pub fn detect_identical_results(
+    results: ArrayView1<f64>,
+    relative_tolerance: f64,
+) -> Result<Vec<Vec<usize>>, CalculationError> {
+    validate_floats(results, "participant results")?;
+    validate_relative_tolerance(relative_tolerance)?;
+    
+    Ok(matching_groups(results.len(), |i, j| {
+        results_match(results[i], results[j], relative_tolerance)
+    }))
+}
+
+/// Screen for identical results across several analytes
+/// 
+/// Each row holds one participant's results and each column one analyte. A pair
+/// of participants is suspicious only when their results match on at least
+/// `min_matches` analytes, so coincidental single-analyte matches are not
+/// flagged when `min_matches` > 1.
+/// 
+/// # Arguments
+/// * `results` - 2D array view of results (participants x analytes)
+/// * `relative_tolerance` - Relative tolerance for a match (0 for exact equality)
+/// * `min_matches` - Number of analytes that must match (1 <= k <= analytes)
+/// 
+/// # Returns
+/// * `Ok(Vec<Vec<usize>>)` - Groups of participant indices with matching results
+/// * `Err(CalculationError)` - If the input is invalid
+pub fn detect_identical_results_multi(
+    results: ArrayView2<f64>,
+    relative_tolerance: f64,
+    min_matches: usize,
+) -> Result<Vec<Vec<usize>>, CalculationError> {
+    validate_floats(results.iter(), "participant results")?;
+    validate_relative_tolerance(relative_tolerance)?;
+    
+    let analytes = results.ncols();
+    if min_matches == 0 || min_matches > analytes {
+        return Err(CalculationError::InvalidInput {
+            message: format!(
+                "min_matches must be between 1 and the number of analytes ({}), got {}",
+                analytes, min_matches
+            ),
+        });
+    }
+    
+    Ok(matching_groups(results.nrows(), |i, j| {
+        let matches = results.row(i).iter()
+            .zip(results.row(j).iter())
+            .filter(|&(&a, &b)| results_match(a, b, relative_tolerance))
+            .count();
+        matches >= min_matches
+    }))
+}
+
+/// Flag results that fail an iterated Grubbs outlier test
+/// 
+/// The Grubbs test is applied repeatedly: each detected outlier is removed and
+/// the test is repeated on the remaining values until no outlier is found or
+/// fewer than three values remain. The flags are for reporting only and do not
+/// change the robust scoring.
+/// 
+/// # Arguments
+/// * `results` - Array view of participant results
+/// * `alpha` - Significance level of each test
+/// * `alternative` - Which extreme value is tested in each pass
+/// 
+/// # Returns
+/// * `Ok(Array1<bool>)` - True for each result removed as an outlier, aligned with the input
+/// * `Err(CalculationError)` - If the input is invalid or has fewer than three values
+pub fn grubbs_flags(
+    results: ArrayView1<f64>,
+    alpha: f64,
+    alternative: GrubbsAlternative,
+) -> Result<Array1<bool>, CalculationError> {
+    let mut flags = Array1::from_elem(results.len(), false);
+    for index in grubbs_iterative(&results.to_vec(), alpha, alternative, results.len())? {
+        flags[index] = true;
+    }
+    
+    Ok(flags)
+}
+
+/// Assigned value and sigma_pt used to score one method group
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct GroupAssigned {
+    pub x_pt: f64,
+    pub sigma_pt: f64,
+}
+
+/// Summary of one method group
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct GroupSummary {
+    /// Group label
+    pub group: i64,
+    /// Number of participants in the group
+    pub n: usize,
+    /// Values the group was scored against, or `None` if the group was not evaluated
+    pub assigned: Option<GroupAssigned>,
+    /// Median of the group's z-scores against the overall consensus
+    pub median_overall_z: f64,
+}
+
+/// z-scores against the overall and the group consensus
+#[derive(Debug, Clone)]
+pub struct GroupScores {
+    /// z-scores against the overall x_pt and sigma_pt
+    pub overall_z: Array1<f64>,
+    /// z-scores against each participant's group, NaN if the group was not evaluated
+    pub group_z: Array1<f64>,
+    /// One summary per group, in ascending label order
+    pub groups: Vec<GroupSummary>,
+}
+
+/// Score participants overall and within their analytical method group
+/// 
+/// Each group is scored against its own consensus: the supplied per-group
+/// values if `per_group_assigned` is given, otherwise x* and s* from Algorithm A
+/// on the group's results. A group is not evaluated (NaN group z-scores) when it
+/// is missing from `per_group_assigned`, has fewer than
+/// `MIN_PARTICIPANTS_ALGORITHM_A` participants, Algorithm A fails on it (e.g.
+/// does not converge) or its s* had to be clamped; the other groups are still
+/// scored.
+/// 
+/// # Arguments
+/// * `results` - Array view of participant results (x_i)
+/// * `group_ids` - Method group label of each participant
+/// * `x_pt` - Overall assigned value
+/// * `sigma_pt` - Overall standard deviation for proficiency assessment
+/// * `per_group_assigned` - Optional x_pt and sigma_pt for each group label
+/// 
+/// # Returns
+/// * `Ok(GroupScores)` - Overall and group z-scores with per-group summaries
+/// * `Err(CalculationError)` - If the inputs are invalid
+pub fn score_by_group(
+    results: ArrayView1<f64>,
+    group_ids: ArrayView1<i64>,
+    x_pt: f64,
+    sigma_pt: f64,
+    per_group_assigned: Option<&BTreeMap<i64, GroupAssigned>>,
+) -> Result<GroupScores, CalculationError> {
+    validate_array_dimensions(results.len(), group_ids.len(), "results", "group_ids")?;
+    let overall_z = calculate_z_scores(results, x_pt, sigma_pt)?;
+    
+    let mut members: BTreeMap<i64, Vec<usize>> = BTreeMap::new();
+    for (i, &group) in group_ids.iter().enumerate() {
+        members.entry(group).or_default().push(i);
+    }
+    
+    let mut group_z = Array1::from_elem(results.len(), f64::NAN);
+    let mut groups = Vec::with_capacity(members.len());
+    
+    for (group, indices) in members {
+        let values: Array1<f64> = indices.iter().map(|&i| results[i]).collect();
+        
+        let assigned = match per_group_assigned {
+            Some(per_group) => per_group.get(&group).copied(),
+            None if indices.len() < MIN_PARTICIPANTS_ALGORITHM_A => None,
+            None => calculate_algorithm_a(values.view(), DEFAULT_TOLERANCE, DEFAULT_MAX_ITERATIONS)
+                .ok()
+                .filter(|result| !result.scale_clamped)
+                .map(|result| GroupAssigned {
+                    x_pt: result.x_pt,
+                    sigma_pt: result.s_star,
+                }),
+        };
+        
+        if let Some(assigned) = assigned {
+            let z_scores = calculate_z_scores(values.view(), assigned.x_pt, assigned.sigma_pt)?;
+            for (&i, &z) in indices.iter().zip(z_scores.iter()) {
+                group_z[i] = z;
+            }
+        }
+        
+        let mut group_overall_z: Vec<f64> = indices.iter().map(|&i| overall_z[i]).collect();
+        groups.push(GroupSummary {
+            group,
+            n: indices.len(),
+            assigned,
+            median_overall_z: median(&mut group_overall_z).unwrap(),
+        });
+    }
+    
+    Ok(GroupScores { overall_z, group_z, groups })
+}
+
+/// z-scores of the participants against one candidate assigned value
+#[derive(Debug, Clone)]
+pub struct AssignedValueComparison {
+    /// Name of the candidate (e.g. "consensus", "crm")
+    pub name: String,
+    pub z_scores: Array1<f64>,
+    /// Number of |z| > 3
+    pub n_unsatisfactory: usize,
+}
+
+/// Score the same results against several candidate assigned values
+/// 
+/// Each candidate is (name, x_pt, u(x_pt)); the third element is the standard
+/// uncertainty of the candidate value, not a sigma_pt of its own. All candidates
+/// share `sigma_pt`, and a candidate with u(x_pt) > 0 is scored with
+/// z' = (x_i - x_pt) / sqrt(sigma_pt^2 + u(x_pt)^2), as in
+/// `calculate_z_scores_from_interval`, so a CRM and a consensus value are
+/// compared with their own uncertainties. The scores are therefore z'-scores in
+/// the ISO 13528 sense (not zeta-scores); u(x_pt) = 0 gives plain z-scores.
+/// 
+/// # Arguments
+/// * `results` - Array view of participant results (x_i)
+/// * `candidates` - Name, assigned value and its standard uncertainty of each candidate
+/// * `sigma_pt` - Standard deviation for proficiency assessment
+/// 
+/// # Returns
+/// * `Ok(Vec<AssignedValueComparison>)` - Scores and unsatisfactory counts in candidate order
+/// * `Err(CalculationError)` - If there are no candidates, a name repeats or an input is invalid
+pub fn compare_assigned_values(
+    results: ArrayView1<f64>,
+    candidates: &[(String, f64, f64)],
+    sigma_pt: f64,
+) -> Result<Vec<AssignedValueComparison>, CalculationError> {
+    if candidates.is_empty() {
+        return Err(CalculationError::InsufficientData {
+            required: 1,
+            actual: 0,
+        });
+    }
+    
+    // Checked here because sqrt(sigma_pt^2 + u^2) hides its sign and a zero value
+    if !is_valid_float(sigma_pt) || sigma_pt <= 0.0 {
+        return Err(CalculationError::InvalidInput {
+            message: format!("Invalid or non-positive sigma_pt: {}", sigma_pt),
+        });
+    }
+    
+    let limits = ScoreLimits::default();
+    let mut comparisons: Vec<AssignedValueComparison> = Vec::with_capacity(candidates.len());
+    
+    for (name, x_pt, u_x_pt) in candidates {
+        if comparisons.iter().any(|comparison| comparison.name == *name) {
+            return Err(CalculationError::InvalidInput {
+                message: format!("Duplicate candidate name: {}", name),
+            });
+        }
+        
+        if !is_valid_float(*u_x_pt) || *u_x_pt < 0.0 {
+            return Err(CalculationError::InvalidInput {
+                message: format!("Invalid uncertainty of candidate '{}': {}", name, u_x_pt),
+            });
+        }
+        
+        let z_scores = calculate_z_scores(results, *x_pt, sigma_pt.hypot(*u_x_pt))?;
+        let n_unsatisfactory = z_scores.iter()
+            .filter(|&&z| classify_score(z, &limits) == ScoreCategory::Unsatisfactory)
+            .count();
+        
+        comparisons.push(AssignedValueComparison {
+            name: name.clone(),
+            z_scores,
+            n_unsatisfactory,
+        });
+    }
+    
+    Ok(comparisons)
+}
+
+/// Scores of a single participant looked up from a `ScoreSet`
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ParticipantScore {
+    pub index: usize,
+    pub result: f64,
+    pub z_score: f64,
+    pub zeta_score: Option<f64>,
+}
+
+/// Scores for a set of participants, optionally keyed by participant identifier
+/// 
+/// Built from the participant results with `ScoreSet::new`, then extended with
+/// zeta-scores and participant identifiers. Identifiers are validated against
+/// the number of results so scores cannot drift out of alignment with lab codes.
+#[pyclass]
+#[derive(Debug, Clone)]
+pub struct ScoreSet {
+    x_pt: f64,
+    sigma_pt: f64,
+    results: Vec<f64>,
+    z_scores: Vec<f64>,
+    zeta_scores: Option<Vec<f64>>,
+    participant_ids: Option<Vec<String>>,
+}
+
+impl ScoreSet {
+    /// Create a score set by calculating z-scores for the results
+    pub fn new(
+        results: ArrayView1<f64>,
+        x_pt: f64,
+        sigma_pt: f64,
+    ) -> Result<Self, CalculationError> {
+        let z_scores = calculate_z_scores(results, x_pt, sigma_pt)?;
+        
+        Ok(ScoreSet {
+            x_pt,
+            sigma_pt,
+            results: results.to_vec(),
+            z_scores: z_scores.to_vec(),
+            zeta_scores: None,
+            participant_ids: None,
+        })
+    }
+    
+    /// Add zeta-scores calculated from participant uncertainties and u(x_pt)
+    pub fn with_zeta_scores(
+        mut self,
+        u_results: ArrayView1<f64>,
+        u_x_pt: f64,
+    ) -> Result<Self, CalculationError> {
+        let results = ArrayView1::from(&self.results);
+        let zeta_scores = calculate_z_prime_scores(results, u_results, self.x_pt, u_x_pt)?;
+        self.zeta_scores = Some(zeta_scores.to_vec());
+        Ok(self)
+    }
+    
+    /// Attach participant identifiers, which must be unique and match the number of results
+    pub fn with_participant_ids(mut self, ids: Vec<String>) -> Result<Self, CalculationError> {
+        validate_array_dimensions(self.results.len(), ids.len(), "results", "participant_ids")?;
+        
+        let mut seen = HashSet::with_capacity(ids.len());
+        for (i, id) in ids.iter().enumerate() {
+            if !seen.insert(id) {
+                return Err(CalculationError::InvalidInput {
+                    message: format!("Duplicate participant ID '{}' at index {}", id, i),
+                });
+            }
+        }
+        
+        self.participant_ids = Some(ids);
+        Ok(self)
+    }
+    
+    /// Create a score set from structured round data
+    /// 
+    /// Participant identifiers are always attached. Zeta-scores are added when
+    /// `u_x_pt` is given, which requires every participant to have reported an
+    /// uncertainty.
+    pub fn from_round_data(
+        round: &RoundData,
+        x_pt: f64,
+        sigma_pt: f64,
+        u_x_pt: Option<f64>,
+    ) -> Result<Self, CalculationError> {
+        let arrays = round.finalize()?;
+        let mut score_set = ScoreSet::new(arrays.results.view(), x_pt, sigma_pt)?;
+        
+        if let Some(u_x_pt) = u_x_pt {
+            let u_results = arrays.uncertainties.ok_or_else(|| CalculationError::InvalidInput {
+                message: "Zeta-scores require an uncertainty for every participant".to_string(),
+            })?;
+            score_set = score_set.with_zeta_scores(u_results.view(), u_x_pt)?;
+        }
+        
+        score_set.with_participant_ids(arrays.participant_ids)
+    }
+    
+    /// Number of participants in the set
+    pub fn len(&self) -> usize {
+        self.results.len()
+    }
+    
+    /// Whether the set contains no participants
+    pub fn is_empty(&self) -> bool {
+        self.results.is_empty()
+    }
+    
+    pub fn x_pt(&self) -> f64 {
+        self.x_pt
+    }
+    
+    pub fn sigma_pt(&self) -> f64 {
+        self.sigma_pt
+    }
+    
+    pub fn results(&self) -> &[f64] {
+        &self.results
+    }
+    
+    pub fn z_scores(&self) -> &[f64] {
+        &self.z_scores
+    }
+    
+    pub fn zeta_scores(&self) -> Option<&[f64]> {
+        self.zeta_scores.as_deref()
+    }
+    
+    pub fn participant_ids(&self) -> Option<&[String]> {
+        self.participant_ids.as_deref()
+    }
+    
+    /// Scores of the participant at the given position
+    pub fn at(&self, index: usize) -> Option<ParticipantScore> {
+        if index >= self.len() {
+            return None;
+        }
+        
+        Some(ParticipantScore {
+            index,
+            result: self.results[index],
+            z_score: self.z_scores[index],
+            zeta_score: self.zeta_scores.as_ref().map(|zeta| zeta[index]),
+        })
+    }
+    
+    /// Scores of the participant with the given identifier
+    /// 
+    /// Returns `None` if the set has no identifiers or the ID is unknown.
+    pub fn get(&self, participant_id: &str) -> Option<ParticipantScore> {
+        let index = self.participant_ids.as_ref()?
+            .iter()
+            .position(|id| id == participant_id)?;
+        self.at(index)
+    }
+    
+    /// Identifier used as export key: the participant ID if present, otherwise the index
+    fn key(&self, index: usize) -> String {
+        match &self.participant_ids {
+            Some(ids) => ids[index].clone(),
+            None => index.to_string(),
+        }
+    }
+}
+
+/// One participant entry of the `ScoreSet` JSON export
+#[derive(Serialize)]
+struct ParticipantRecord<'a> {
+    participant_id: Option<&'a str>,
+    #[serde(flatten)]
+    score: ParticipantScore,
+}
+
+/// Top-level object of the `ScoreSet` JSON export
+#[derive(Serialize)]
+struct ScoreSetRecord<'a> {
+    x_pt: f64,
+    sigma_pt: f64,
+    participants: Vec<ParticipantRecord<'a>>,
+}
+
+impl Serialize for ScoreSet {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let participants = (0..self.len())
+            .filter_map(|index| {
+                Some(ParticipantRecord {
+                    participant_id: self.participant_ids.as_ref().map(|ids| ids[index].as_str()),
+                    score: self.at(index)?,
+                })
+            })
+            .collect();
+        
+        ScoreSetRecord {
+            x_pt: self.x_pt,
+            sigma_pt: self.sigma_pt,
+            participants,
+        }.serialize(serializer)
+    }
+}
+
+impl ScoreSet {
+    /// Export the scores as JSON for the reporting pipeline
+    /// 
+    /// The report templates key on these field names, so they must not change:
+    /// 
+    /// ```text
+    /// {
+    ///   "x_pt": number,
+    ///   "sigma_pt": number,
+    ///   "participants": [
+    ///     {
+    ///       "participant_id": string | null,
+    ///       "index": integer,
+    ///       "result": number,
+    ///       "z_score": number | null,
+    ///       "zeta_score": number | null
+    ///     }
+    ///   ]
+    /// }
+    /// ```
+    /// 
+    /// Participants are in input order. `participant_id` is null when no IDs
+    /// were attached and `zeta_score` is null when no zeta-scores were added;
+    /// NaN or infinite numbers are written as null.
+    pub fn to_json(&self) -> Result<String, CalculationError> {
+        serde_json::to_string(self).map_err(|e| CalculationError::InternalError {
+            message: format!("Failed to serialize scores to JSON: {}", e),
+        })
+    }
+}
+
+fn participant_score_to_dict<'py>(
+    py: Python<'py>,
+    key: String,
+    score: &ParticipantScore,
+) -> PyResult<&'py PyDict> {
+    let dict = PyDict::new(py);
+    dict.set_item("participant_id", key)?;
+    dict.set_item("result", score.result)?;
+    dict.set_item("z_score", score.z_score)?;
+    dict.set_item("zeta_score", score.zeta_score)?;
+    Ok(dict)
+}
+
+#[pymethods]
+impl ScoreSet {
+    fn __len__(&self) -> usize {
+        self.len()
+    }
+    
+    #[getter(x_pt)]
+    fn py_x_pt(&self) -> f64 {
+        self.x_pt
+    }
+    
+    #[getter(sigma_pt)]
+    fn py_sigma_pt(&self) -> f64 {
+        self.sigma_pt
+    }
+    
+    #[getter(z_scores)]
+    fn py_z_scores(&self, py: Python) -> Py<PyArray1<f64>> {
+        PyArray1::from_slice(py, &self.z_scores).to_owned()
+    }
+    
+    #[getter(zeta_scores)]
+    fn py_zeta_scores(&self, py: Python) -> Option<Py<PyArray1<f64>>> {
+        self.zeta_scores.as_ref()
+            .map(|zeta| PyArray1::from_slice(py, zeta).to_owned())
+    }
+    
+    #[getter(participant_ids)]
+    fn py_participant_ids(&self) -> Option<Vec<String>> {
+        self.participant_ids.clone()
+    }
+    
+    /// Look up a participant's scores by ID, raising KeyError if unknown
+    #[pyo3(name = "get")]
+    fn py_get<'py>(&self, py: Python<'py>, participant_id: &PyAny) -> PyResult<&'py PyDict> {
+        let key = extract_participant_id(participant_id)?;
+        match self.get(&key) {
+            Some(score) => participant_score_to_dict(py, key, &score),
+            None => Err(PyKeyError::new_err(format!("Unknown participant ID '{}'", key))),
+        }
+    }
+    
+    /// Export scores as a dict keyed by participant ID (or index if no IDs were given)
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<&'py PyDict> {
+        let dict = PyDict::new(py);
+        for index in 0..self.len() {
+            if let Some(score) = self.at(index) {
+                let key = self.key(index);
+                dict.set_item(key.clone(), participant_score_to_dict(py, key, &score)?)?;
+            }
+        }
+        Ok(dict)
+    }
+}
+
+/// Assigned value and the optional quantities that some diagnostics need
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct AssignedValue {
+    pub x_pt: f64,
+    /// Standard uncertainty u(x_pt), needed for zeta-scores and En numbers
+    pub u_x_pt: Option<f64>,
+    /// Robust standard deviation s*, needed for standardized residuals and weights
+    pub s_star: Option<f64>,
+    /// Coverage factor k of the expanded uncertainties U = k·u, needed for En numbers
+    pub coverage_factor: Option<f64>,
+}
+
+impl AssignedValue {
+    /// Create an assigned value without uncertainty or scale information
+    pub fn new(x_pt: f64) -> Result<Self, CalculationError> {
+        if !is_valid_float(x_pt) {
+            return Err(CalculationError::InvalidInput {
+                message: format!("Invalid assigned value x_pt: {}", x_pt),
+            });
+        }
+        
+        Ok(AssignedValue {
+            x_pt,
+            u_x_pt: None,
+            s_star: None,
+            coverage_factor: None,
+        })
+    }
+    
+    /// Add the standard uncertainty u(x_pt), which must be non-negative
+    pub fn with_uncertainty(mut self, u_x_pt: f64) -> Result<Self, CalculationError> {
+        if !is_valid_float(u_x_pt) || u_x_pt < 0.0 {
+            return Err(CalculationError::InvalidInput {
+                message: format!("Invalid uncertainty of assigned value: {}", u_x_pt),
+            });
+        }
+        
+        self.u_x_pt = Some(u_x_pt);
+        Ok(self)
+    }
+    
+    /// Add the robust standard deviation s*, which must be positive
+    pub fn with_s_star(mut self, s_star: f64) -> Result<Self, CalculationError> {
+        if !is_valid_float(s_star) || s_star <= 0.0 {
+            return Err(CalculationError::InvalidInput {
+                message: format!("Invalid or non-positive robust standard deviation: {}", s_star),
+            });
+        }
+        
+        self.s_star = Some(s_star);
+        Ok(self)
+    }
+    
+    /// Add the coverage factor k of the expanded uncertainties, which must be positive
+    pub fn with_coverage_factor(mut self, k: f64) -> Result<Self, CalculationError> {
+        if !is_valid_float(k) || k <= 0.0 {
+            return Err(CalculationError::InvalidInput {
+                message: format!("Invalid or non-positive coverage factor: {}", k),
+            });
+        }
+        
+        self.coverage_factor = Some(k);
+        Ok(self)
+    }
+}
+
+impl From<&RobustConsensus> for AssignedValue {
+    fn from(consensus: &RobustConsensus) -> Self {
+        AssignedValue {
+            x_pt: consensus.x_pt,
+            u_x_pt: Some(consensus.u_x_pt),
+            s_star: Some(consensus.s_star),
+            coverage_factor: None,
+        }
+    }
+}
+
+/// All per-participant diagnostics of a round in one flat record
+/// 
+/// Quantities that cannot be computed from the inputs are `None`: zeta-scores
+/// need participant uncertainties and u(x_pt), En numbers additionally need a
+/// coverage factor, and standardized residuals and weights need s*.
+#[pyclass]
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ParticipantDiagnostics {
+    pub participant_id: String,
+    pub value: f64,
+    pub uncertainty: Option<f64>,
+    pub z_score: f64,
+    pub zeta_score: Option<f64>,
+    /// En = (x_i - x_pt) / sqrt(U_i^2 + U(x_pt)^2) with U = k·u
+    pub en_number: Option<f64>,
+    /// Robust standardized residual (x_i - x_pt) / s*
+    pub standardized_residual: Option<f64>,
+    /// Algorithm A (Huber) weight of the result at x_pt and s*
+    pub weight: Option<f64>,
+    /// Interpretation of the z-score, as returned by `interpret_z_score`
+    pub label: String,
+}
+
+/// Calculate z-scores for the participants of a round
+/// 
+/// Same as `calculate_z_scores` on the round's results, in insertion order.
+/// 
+/// # Returns
+/// * `Ok(Array1<f64>)` - z-score of each participant
+/// * `Err(CalculationError)` - If the round is empty or inconsistent, or sigma_pt is invalid
+pub fn calculate_z_scores_for_round(
+    round: &RoundData,
+    x_pt: f64,
+    sigma_pt: f64,
+) -> Result<Array1<f64>, CalculationError> {
+    let arrays = round.finalize()?;
+    calculate_z_scores(arrays.results.view(), x_pt, sigma_pt)
+}
+
+/// Calculate zeta-scores for the participants of a round
+/// 
+/// Same as `calculate_z_prime_scores` on the round's results and reported
+/// uncertainties, in insertion order.
+/// 
+/// # Returns
+/// * `Ok(Array1<f64>)` - Zeta-score of each participant
+/// * `Err(CalculationError)` - If the round is empty or inconsistent, a participant
+///   reported no uncertainty, or u(x_pt) is invalid
+pub fn calculate_z_prime_scores_for_round(
+    round: &RoundData,
+    x_pt: f64,
+    u_x_pt: f64,
+) -> Result<Array1<f64>, CalculationError> {
+    let arrays = round.finalize()?;
+    let u_results = arrays.uncertainties.ok_or_else(|| CalculationError::InvalidInput {
+        message: "Zeta-scores require an uncertainty for every participant".to_string(),
+    })?;
+    calculate_z_prime_scores(arrays.results.view(), u_results.view(), x_pt, u_x_pt)
+}
+
+/// Calculate every per-participant diagnostic of a round in one call
+/// 
+/// # Arguments
+/// * `round` - Participant identifiers, results and optional uncertainties
+/// * `assigned` - Assigned value with the optional u(x_pt), s* and coverage factor
+/// * `sigma_pt` - Standard deviation for proficiency assessment
+/// 
+/// # Returns
+/// * `Ok(Vec<ParticipantDiagnostics>)` - One record per participant in insertion order
+/// * `Err(CalculationError)` - If the round is empty or inconsistent, or sigma_pt is invalid
+pub fn full_diagnostics(
+    round: &RoundData,
+    assigned: &AssignedValue,
+    sigma_pt: f64,
+) -> Result<Vec<ParticipantDiagnostics>, CalculationError> {
+    let arrays = round.finalize()?;
+    let z_scores = calculate_z_scores(arrays.results.view(), assigned.x_pt, sigma_pt)?;
+    
+    let zeta_scores = match (&arrays.uncertainties, assigned.u_x_pt) {
+        (Some(u_results), Some(u_x_pt)) => Some(
+            calculate_z_prime_scores(arrays.results.view(), u_results.view(), assigned.x_pt, u_x_pt)?
+        ),
+        _ => None,
+    };
+    
+    Ok(arrays.participant_ids.into_iter()
+        .enumerate()
+        .map(|(i, participant_id)| {
+            let value = arrays.results[i];
+            let zeta_score = zeta_scores.as_ref().map(|zeta| zeta[i]);
+            let standardized_residual = assigned.s_star.map(|s_star| (value - assigned.x_pt) / s_star);
+            
+            ParticipantDiagnostics {
+                participant_id,
+                value,
+                uncertainty: arrays.uncertainties.as_ref().map(|u| u[i]),
+                z_score: z_scores[i],
+                zeta_score,
+                // With U = k·u for both, En = zeta / k
+                en_number: zeta_score.zip(assigned.coverage_factor).map(|(zeta, k)| zeta / k),
+                standardized_residual,
+                weight: standardized_residual.map(|u| huber_weight(u, HUBER_C)),
+                label: interpret_z_score(z_scores[i]),
+            }
+        })
+        .collect())
+}
+
+#[pymethods]
+impl ParticipantDiagnostics {
+    #[getter(participant_id)]
+    fn py_participant_id(&self) -> String {
+        self.participant_id.clone()
+    }
+    
+    #[getter(value)]
+    fn py_value(&self) -> f64 {
+        self.value
+    }
+    
+    #[getter(uncertainty)]
+    fn py_uncertainty(&self) -> Option<f64> {
+        self.uncertainty
+    }
+    
+    #[getter(z_score)]
+    fn py_z_score(&self) -> f64 {
+        self.z_score
+    }
+    
+    #[getter(zeta_score)]
+    fn py_zeta_score(&self) -> Option<f64> {
+        self.zeta_score
+    }
+    
+    #[getter(en_number)]
+    fn py_en_number(&self) -> Option<f64> {
+        self.en_number
+    }
+    
+    #[getter(standardized_residual)]
+    fn py_standardized_residual(&self) -> Option<f64> {
+        self.standardized_residual
+    }
+    
+    #[getter(weight)]
+    fn py_weight(&self) -> Option<f64> {
+        self.weight
+    }
+    
+    #[getter(label)]
+    fn py_label(&self) -> String {
+        self.label.clone()
+    }
+}
+
+/// Mean deviation of one participant over several rounds, tested against zero
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct SystematicBias {
+    /// Mean signed deviation from the consensus
+    pub mean: f64,
+    /// Standard error of the mean, s / sqrt(n)
+    pub standard_error: f64,
+    /// t = mean / standard error
+    pub t_statistic: f64,
+    /// Two-sided p-value of t with n - 1 degrees of freedom
+    pub p_value: f64,
+    /// Number of rounds used (NaN rounds excluded)
+    pub n_rounds: usize,
+}
+
+/// Estimate a participant's systematic bias from its deviations over several rounds
+/// 
+/// A deviation consistently on one side of the consensus across rounds points
+/// to a systematic bias even when each single-round score is satisfactory.
+/// Rounds the participant missed are NaN and skipped. Deviations should be on
+/// a common scale across rounds (e.g. z-scores or relative deviations).
+/// 
+/// # Arguments
+/// * `deviations` - Signed deviation from the consensus in each round
+/// 
+/// # Returns
+/// * `Ok(SystematicBias)` - Mean deviation, its standard error, t-statistic and p-value
+/// * `Err(CalculationError)` - If fewer than 3 rounds remain or a deviation is infinite
+pub fn estimate_systematic_bias(deviations: ArrayView1<f64>) -> Result<SystematicBias, CalculationError> {
+    if let Some((i, &d)) = deviations.iter().enumerate().find(|(_, d)| d.is_infinite()) {
+        return Err(CalculationError::InvalidInput {
+            message: format!("Infinite deviation in round {}: {}", i, d),
+        });
+    }
+    
+    let rounds: Vec<f64> = deviations.iter().copied().filter(|d| !d.is_nan()).collect();
+    if rounds.len() < 3 {
+        return Err(CalculationError::InsufficientData {
+            required: 3,
+            actual: rounds.len(),
+        });
+    }
+    
+    let stats = running_stats(&rounds);
+    let mean = stats.mean().unwrap();
+    let n = rounds.len() as f64;
+    let standard_error = stats.std_dev().unwrap() / n.sqrt();
+    
+    // Identical deviations: no scatter, so any non-zero mean is conclusive
+    let (t_statistic, p_value) = if standard_error > 0.0 {
+        let t = mean / standard_error;
+        (t, 2.0 * (1.0 - t_cdf(t.abs(), n - 1.0)))
+    } else if mean == 0.0 {
+        (0.0, 1.0)
+    } else {
+        (mean.signum() * f64::INFINITY, 0.0)
+    };
+    
+    Ok(SystematicBias {
+        mean,
+        standard_error,
+        t_statistic,
+        p_value,
+        n_rounds: rounds.len(),
+    })
+}
+
+/// Multi-round score history of one participant
+/// 
+/// Rounds are kept in the order they were added, which is taken to be
+/// chronological for the exponentially weighted index.
+#[pyclass]
+#[derive(Debug, Clone, Serialize)]
+pub struct ParticipantHistory {
+    participant_id: String,
+    round_ids: Vec<String>,
+    z_scores: Vec<f64>,
+}
+
+impl ParticipantHistory {
+    /// Create an empty history for a participant
+    pub fn new(participant_id: String) -> Self {
+        ParticipantHistory {
+            participant_id,
+            round_ids: Vec::new(),
+            z_scores: Vec::new(),
+        }
+    }
+    
+    /// Append the z-score of a round; round IDs must be unique and z finite
+    pub fn add_round(&mut self, round_id: String, z_score: f64) -> Result<(), CalculationError> {
+        if !is_valid_float(z_score) {
+            return Err(CalculationError::InvalidInput {
+                message: format!("Invalid z-score for round '{}': {}", round_id, z_score),
+            });
+        }
+        
+        if self.round_ids.contains(&round_id) {
+            return Err(CalculationError::InvalidInput {
+                message: format!("Duplicate round ID '{}'", round_id),
+            });
+        }
+        
+        self.round_ids.push(round_id);
+        self.z_scores.push(z_score);
+        Ok(())
+    }
+    
+    /// Exponentially weighted moving average of |z|
+    /// 
+    /// E_1 = |z_1| and E_t = λ·|z_t| + (1 - λ)·E_(t-1), so λ = 1 gives the
+    /// latest |z| and smaller λ gives older rounds more weight.
+    /// 
+    /// # Arguments
+    /// * `lambda` - Weight of the most recent round, in (0, 1]
+    /// 
+    /// # Returns
+    /// * `Ok(f64)` - The weighted index after the latest round
+    /// * `Err(CalculationError)` - If lambda is invalid or the history is empty
+    pub fn weighted_index(&self, lambda: f64) -> Result<f64, CalculationError> {
+        if !(lambda > 0.0 && lambda <= 1.0) {
+            return Err(CalculationError::InvalidInput {
+                message: format!("EWMA weight lambda must be in (0, 1], got {}", lambda),
+            });
+        }
+        
+        let (first, rest) = self.z_scores.split_first().ok_or(CalculationError::InsufficientData {
+            required: 1,
+            actual: 0,
+        })?;
+        
+        Ok(rest.iter().fold(first.abs(), |index, &z| lambda * z.abs() + (1.0 - lambda) * index))
+    }
+    
+    /// Number of rounds with a signal, i.e. |z| above the warning limit
+    pub fn signal_count(&self, limits: &ScoreLimits) -> usize {
+        self.z_scores.iter()
+            .filter(|&&z| classify_score(z, limits) != ScoreCategory::Satisfactory)
+            .count()
+    }
+    
+    /// Number of rounds in the history
+    pub fn len(&self) -> usize {
+        self.z_scores.len()
+    }
+    
+    /// Whether the history contains no rounds
+    pub fn is_empty(&self) -> bool {
+        self.z_scores.is_empty()
+    }
+    
+    pub fn participant_id(&self) -> &str {
+        &self.participant_id
+    }
+    
+    pub fn round_ids(&self) -> &[String] {
+        &self.round_ids
+    }
+    
+    pub fn z_scores(&self) -> &[f64] {
+        &self.z_scores
+    }
+}
+
+#[pymethods]
+impl ParticipantHistory {
+    fn __len__(&self) -> usize {
+        self.len()
+    }
+    
+    #[getter(participant_id)]
+    fn py_participant_id(&self) -> String {
+        self.participant_id.clone()
+    }
+    
+    /// Append the z-score of a round, raising ValueError for duplicates or non-finite z
+    #[pyo3(name = "add_round")]
+    fn py_add_round(&mut self, round_id: String, z_score: f64) -> PyResult<()> {
+        Ok(self.add_round(round_id, z_score)?)
+    }
+    
+    /// Exponentially weighted mean |z| with weight `lambda_` on the latest round
+    #[pyo3(name = "weighted_index")]
+    fn py_weighted_index(&self, lambda_: f64) -> PyResult<f64> {
+        Ok(self.weighted_index(lambda_)?)
+    }
+    
+    /// Number of rounds with |z| above the warning limit (default 2)
+    #[pyo3(name = "signal_count")]
+    fn py_signal_count(&self, warning: Option<f64>, action: Option<f64>) -> PyResult<usize> {
+        let defaults = ScoreLimits::default();
+        let limits = ScoreLimits::new(
+            warning.unwrap_or(defaults.warning),
+            action.unwrap_or(defaults.action),
+        )?;
+        Ok(self.signal_count(&limits))
+    }
+    
+    /// Export as a dict with keys participant_id, round_ids and z_scores
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<&'py PyDict> {
+        let dict = PyDict::new(py);
+        dict.set_item("participant_id", &self.participant_id)?;
+        dict.set_item("round_ids", &self.round_ids)?;
+        dict.set_item("z_scores", &self.z_scores)?;
+        Ok(dict)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+    use ndarray::{array, Array2};
+
+    #[test]
+    fn test_z_scores_calculation() {
+        let results = array![9.8, 10.0, 10.2, 9.9, 10.1];
+        let x_pt = 10.0;
+        let sigma_pt = 0.1;
+        
+        let z_scores = calculate_z_scores(results.view(), x_pt, sigma_pt).unwrap();
+        
+        assert_eq!(z_scores.len(), 5);
+        assert_abs_diff_eq!(z_scores[0], -2.0, epsilon = 1e-10); // (9.8 - 10.0) / 0.1
+        assert_abs_diff_eq!(z_scores[1], 0.0, epsilon = 1e-10);  // (10.0 - 10.0) / 0.1
+        assert_abs_diff_eq!(z_scores[2], 2.0, epsilon = 1e-10);  // (10.2 - 10.0) / 0.1
+    }
+
+    #[test]
+    fn test_z_scores_invalid_sigma() {
+        let results = array![9.8, 10.0, 10.2];
+        let x_pt = 10.0;
+        let sigma_pt = 0.0; // Invalid
+        
+        let result = calculate_z_scores(results.view(), x_pt, sigma_pt);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_z_prime_scores_calculation() {
+        let results = array![9.8, 10.0, 10.2];
+        let u_results = array![0.05, 0.05, 0.05];
+        let x_pt = 10.0;
+        let u_x_pt = 0.03;
+        
+        let z_prime_scores = calculate_z_prime_scores(
+            results.view(), 
+            u_results.view(), 
+            x_pt, 
+            u_x_pt
+        ).unwrap();
+        
+        assert_eq!(z_prime_scores.len(), 3);
+        
+        // Combined uncertainty = sqrt(0.05^2 + 0.03^2) = sqrt(0.0034) ≈ 0.0583
+        let combined_u = (0.05_f64.powi(2) + 0.03_f64.powi(2)).sqrt();
+        assert_abs_diff_eq!(z_prime_scores[0], -0.2 / combined_u, epsilon = 1e-6);
+        assert_abs_diff_eq!(z_prime_scores[1], 0.0, epsilon = 1e-10);
+        assert_abs_diff_eq!(z_prime_scores[2], 0.2 / combined_u, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn test_z_prime_scores_zero_uncertainty_participant() {
+        let results = array![9.8, 10.3, 10.2];
+        let u_results = array![0.05, 0.0, 0.05];
+        
+        let (z_prime_scores, zero_uncertainty) = calculate_z_prime_scores_masked(
+            results.view(),
+            u_results.view(),
+            10.0,
+            0.0
+        ).unwrap();
+        
+        // Only the participant without uncertainty is left unscored
+        assert_abs_diff_eq!(z_prime_scores[0], -4.0, epsilon = 1e-10);
+        assert!(z_prime_scores[1].is_nan());
+        assert_abs_diff_eq!(z_prime_scores[2], 4.0, epsilon = 1e-10);
+        assert_eq!(zero_uncertainty, array![false, true, false]);
+        
+        // With u(x_pt) > 0 every participant has a score
+        let (z_prime_scores, zero_uncertainty) = calculate_z_prime_scores_masked(
+            results.view(),
+            u_results.view(),
+            10.0,
+            0.1
+        ).unwrap();
+        assert_abs_diff_eq!(z_prime_scores[1], 3.0, epsilon = 1e-10);
+        assert!(!zero_uncertainty.iter().any(|&flag| flag));
+    }
+
+    #[test]
+    fn test_z_prime_scores_dimension_mismatch() {
+        let results = array![9.8, 10.0, 10.2];
+        let u_results = array![0.05, 0.05]; // Wrong size
+        let x_pt = 10.0;
+        let u_x_pt = 0.03;
+        
+        let result = calculate_z_prime_scores(
+            results.view(), 
+            u_results.view(), 
+            x_pt, 
+            u_x_pt
+        );
+        let err = result.unwrap_err();
+        assert!(matches!(err, CalculationError::DimensionMismatch { len1: 3, len2: 2, .. }));
+        assert_eq!(
+            err.to_string(),
+            "Array dimension mismatch: results has 3 elements but uncertainties has 2"
+        );
+    }
+
+    #[test]
+    fn test_z_prime_scores_negative_uncertainty() {
+        let results = array![9.8, 10.0, 10.2];
+        let u_results = array![0.05, -0.05, 0.05]; // Negative uncertainty
+        let x_pt = 10.0;
+        let u_x_pt = 0.03;
+        
+        let result = calculate_z_prime_scores(
+            results.view(), 
+            u_results.view(), 
+            x_pt, 
+            u_x_pt
+        );
+        assert!(result.is_err());
+        matches!(result.unwrap_err(), CalculationError::InvalidInput { .. });
+    }
+
+    #[test]
+    fn test_z_prime_scores_relative() {
+        let results = array![9.0, 10.0, 12.0];
+        let u_percent = array![5.0, 1.0, 2.5];
+        let x_pt = 10.0;
+        let u_x_pt = 0.4;
+        
+        let relative = calculate_z_prime_scores_relative(
+            results.view(),
+            u_percent.view(),
+            x_pt,
+            u_x_pt,
+        ).unwrap();
+        
+        // 5 % of 9.0 = 0.45, 1 % of 10.0 = 0.1, 2.5 % of 12.0 = 0.3
+        let absolute = array![0.45, 0.1, 0.3];
+        let expected = calculate_z_prime_scores(results.view(), absolute.view(), x_pt, u_x_pt).unwrap();
+        for (a, b) in relative.iter().zip(expected.iter()) {
+            assert_abs_diff_eq!(*a, *b, epsilon = 1e-12);
+        }
+        assert_abs_diff_eq!(relative[2], 2.0 / 0.5, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_z_prime_scores_relative_invalid() {
+        let results = array![9.0, 10.0];
+        let negative = array![5.0, -1.0];
+        assert!(calculate_z_prime_scores_relative(results.view(), negative.view(), 10.0, 0.1).is_err());
+        
+        let wrong_length = array![5.0];
+        let result = calculate_z_prime_scores_relative(results.view(), wrong_length.view(), 10.0, 0.1);
+        assert!(matches!(result, Err(CalculationError::DimensionMismatch { .. })));
+    }
+
+    #[test]
+    fn test_z_prime_scores_no_participant_uncertainties() {
+        let results = array![9.8, 10.0, 10.2];
+        let x_pt = 10.0;
+        let u_x_pt = 0.1;
+        
+        let z_prime_scores = calculate_z_prime_scores_no_participant_uncertainties(
+            results.view(), 
+            x_pt, 
+            u_x_pt
+        ).unwrap();
+        
+        assert_eq!(z_prime_scores.len(), 3);
+        assert_abs_diff_eq!(z_prime_scores[0], -2.0, epsilon = 1e-10); // (9.8 - 10.0) / 0.1
+        assert_abs_diff_eq!(z_prime_scores[1], 0.0, epsilon = 1e-10);  // (10.0 - 10.0) / 0.1
+        assert_abs_diff_eq!(z_prime_scores[2], 2.0, epsilon = 1e-10);  // (10.2 - 10.0) / 0.1
+    }
+
+    #[test]
+    fn test_z_score_interpretation() {
+        assert_eq!(interpret_z_score(1.5), "Satisfactory");
+        assert_eq!(interpret_z_score(-1.8), "Satisfactory");
+        assert_eq!(interpret_z_score(2.5), "Questionable");
+        assert_eq!(interpret_z_score(-2.7), "Questionable");
+        assert_eq!(interpret_z_score(3.2), "Unsatisfactory");
+        assert_eq!(interpret_z_score(-4.0), "Unsatisfactory");
+    }
+
+    #[test]
+    fn test_z_prime_score_interpretation() {
+        assert_eq!(interpret_z_prime_score(1.5), "Satisfactory");
+        assert_eq!(interpret_z_prime_score(-1.9), "Satisfactory");
+        assert_eq!(interpret_z_prime_score(2.1), "Unsatisfactory");
+        assert_eq!(interpret_z_prime_score(-3.0), "Unsatisfactory");
+    }
+
+    #[test]
+    fn test_standardized_residuals() {
+        let results = array![9.0, 10.0, 11.5];
+        let residuals = standardized_residuals(results.view(), 10.0, 0.5).unwrap();
+        
+        assert_abs_diff_eq!(residuals[0], -2.0, epsilon = 1e-10);
+        assert_abs_diff_eq!(residuals[1], 0.0, epsilon = 1e-10);
+        assert_abs_diff_eq!(residuals[2], 3.0, epsilon = 1e-10);
+        
+        assert!(standardized_residuals(results.view(), 10.0, 0.0).is_err());
+    }
+
+    #[test]
+    fn test_score_limits_validation() {
+        assert!(ScoreLimits::new(2.0, 3.0).is_ok());
+        assert!(ScoreLimits::new(1.0, 1.0).is_ok());
+        assert!(ScoreLimits::new(0.0, 3.0).is_err());
+        assert!(ScoreLimits::new(3.0, 2.0).is_err());
+        assert!(ScoreLimits::new(2.0, f64::NAN).is_err());
+    }
+
+    #[test]
+    fn test_classify_score_matches_interpretation() {
+        let limits = ScoreLimits::default();
+        for &z in &[0.0, 1.5, -2.0, 2.0001, -2.7, 3.0, -3.0001, 5.0] {
+            assert_eq!(classify_score(z, &limits).as_str(), interpret_z_score(z));
+        }
+        assert_eq!(classify_score(f64::NAN, &limits), ScoreCategory::NotEvaluated);
+    }
+
+    #[test]
+    fn test_zeta_interpretation_three_level() {
+        assert_eq!(interpret_zeta_score_with_limits(2.0, 2.0, 3.0).unwrap(), "Satisfactory");
+        assert_eq!(interpret_zeta_score_with_limits(-2.0001, 2.0, 3.0).unwrap(), "Questionable");
+        assert_eq!(interpret_zeta_score_with_limits(3.0, 2.0, 3.0).unwrap(), "Questionable");
+        assert_eq!(interpret_zeta_score_with_limits(-3.0001, 2.0, 3.0).unwrap(), "Unsatisfactory");
+    }
+
+    #[test]
+    fn test_zeta_interpretation_two_level() {
+        assert_eq!(interpret_zeta_score_with_limits(1.0, 1.0, 1.0).unwrap(), "Satisfactory");
+        assert_eq!(interpret_zeta_score_with_limits(-1.0001, 1.0, 1.0).unwrap(), "Unsatisfactory");
+        
+        // 2/2 reproduces the default two-level interpretation
+        for &score in &[0.0, 1.9, 2.0, -2.0, 2.1, -3.5] {
+            assert_eq!(
+                interpret_zeta_score_with_limits(score, 2.0, 2.0).unwrap(),
+                interpret_z_prime_score(score)
+            );
+        }
+    }
+
+    #[test]
+    fn test_zeta_interpretation_vectorized() {
+        let scores = array![0.5, 2.5, -3.5, f64::NAN];
+        let labels = interpret_zeta_scores_with_limits(scores.view(), 2.0, 3.0).unwrap();
+        assert_eq!(labels, vec!["Satisfactory", "Questionable", "Unsatisfactory", "NotEvaluated"]);
+        
+        assert!(interpret_zeta_scores_with_limits(scores.view(), 3.0, 2.0).is_err());
+    }
+
+    #[test]
+    fn test_joint_interpretation_matrix() {
+        let z_scores = array![1.0, 1.0, 2.5, 2.5, 3.5, -3.5];
+        let zeta_scores = array![1.0, 2.5, -1.0, 2.5, 1.0, -2.5];
+        let joint = joint_interpretation(z_scores.view(), zeta_scores.view()).unwrap();
+        
+        let codes: Vec<i64> = joint.iter().map(|j| j.code()).collect();
+        assert_eq!(codes, vec![0, 1, 2, 3, 4, 5]);
+        
+        assert_eq!(joint[0].z_category, ScoreCategory::Satisfactory);
+        assert_eq!(joint[1].zeta_category, ScoreCategory::Unsatisfactory);
+        assert_eq!(joint[2].z_category, ScoreCategory::Questionable);
+        assert_eq!(joint[5].z_category, ScoreCategory::Unsatisfactory);
+        assert_eq!(joint[1].advisory(), "Review stated measurement uncertainty (likely underestimated)");
+        
+        // All six cells have distinct advisories
+        let mut advisories: Vec<&str> = joint.iter().map(|j| j.advisory()).collect();
+        advisories.sort_unstable();
+        advisories.dedup();
+        assert_eq!(advisories.len(), 6);
+    }
+
+    #[test]
+    fn test_joint_interpretation_not_evaluated() {
+        let z_scores = array![f64::NAN, 1.0];
+        let zeta_scores = array![1.0, f64::NAN];
+        let joint = joint_interpretation(z_scores.view(), zeta_scores.view()).unwrap();
+        
+        for j in &joint {
+            assert_eq!(j.code(), -1);
+            assert_eq!(j.advisory(), "Not evaluated");
+        }
+    }
+
+    #[test]
+    fn test_joint_interpretation_dimension_mismatch() {
+        let z_scores = array![1.0, 2.0];
+        let zeta_scores = array![1.0];
+        let result = joint_interpretation(z_scores.view(), zeta_scores.view());
+        assert!(matches!(result, Err(CalculationError::DimensionMismatch { .. })));
+    }
+
+    #[test]
+    fn test_score_set_lookup_by_id() {
+        let results = array![9.8, 10.0, 10.3];
+        let u_results = array![0.1, 0.1, 0.1];
+        let ids = vec!["LAB001".to_string(), "LAB042".to_string(), "LAB007".to_string()];
+        
+        let score_set = ScoreSet::new(results.view(), 10.0, 0.1).unwrap()
+            .with_zeta_scores(u_results.view(), 0.0).unwrap()
+            .with_participant_ids(ids).unwrap();
+        
+        let score = score_set.get("LAB042").unwrap();
+        assert_eq!(score.index, 1);
+        assert_eq!(score.result, 10.0);
+        assert_abs_diff_eq!(score.z_score, 0.0, epsilon = 1e-10);
+        
+        let score = score_set.get("LAB007").unwrap();
+        assert_abs_diff_eq!(score.z_score, 3.0, epsilon = 1e-10);
+        assert_abs_diff_eq!(score.zeta_score.unwrap(), 3.0, epsilon = 1e-10);
+    }
+
+    #[test]
+    fn test_score_set_missing_id() {
+        let results = array![9.8, 10.0];
+        let score_set = ScoreSet::new(results.view(), 10.0, 0.1).unwrap();
+        assert!(score_set.get("LAB001").is_none());
+        
+        let score_set = score_set
+            .with_participant_ids(vec!["LAB001".to_string(), "LAB002".to_string()])
+            .unwrap();
+        assert!(score_set.get("LAB001").is_some());
+        assert!(score_set.get("LAB999").is_none());
+        assert!(score_set.zeta_scores().is_none());
+    }
+
+    #[test]
+    fn test_score_set_id_length_mismatch() {
+        let results = array![9.8, 10.0, 10.2];
+        let result = ScoreSet::new(results.view(), 10.0, 0.1).unwrap()
+            .with_participant_ids(vec!["LAB001".to_string(), "LAB002".to_string()]);
+        assert!(matches!(result, Err(CalculationError::DimensionMismatch { .. })));
+    }
+
+    #[test]
+    fn test_score_set_duplicate_ids() {
+        let results = array![9.8, 10.0];
+        let result = ScoreSet::new(results.view(), 10.0, 0.1).unwrap()
+            .with_participant_ids(vec!["42".to_string(), "42".to_string()]);
+        assert!(matches!(result, Err(CalculationError::InvalidInput { .. })));
+    }
+
+    #[test]
+    fn test_z_scores_into_matches_allocating() {
+        let results = array![9.8, 10.0, 10.2, 9.9, 10.1];
+        let expected = calculate_z_scores(results.view(), 10.0, 0.1).unwrap();
+        
+        let mut out = Array1::from_elem(5, f64::NAN);
+        calculate_z_scores_into(results.view(), 10.0, 0.1, &mut out.view_mut()).unwrap();
+        assert_eq!(out, expected);
+    }
+
+    #[test]
+    fn test_z_prime_scores_into_matches_allocating() {
+        let results = array![9.8, 10.0, 10.2];
+        let u_results = array![0.05, 0.06, 0.04];
+        let expected = calculate_z_prime_scores(results.view(), u_results.view(), 10.0, 0.03).unwrap();
+        
+        let mut out = Array1::from_elem(3, f64::NAN);
+        calculate_z_prime_scores_into(
+            results.view(),
+            u_results.view(),
+            10.0,
+            0.03,
+            &mut out.view_mut(),
+        ).unwrap();
+        assert_eq!(out, expected);
+    }
+
+    #[test]
+    fn test_parallel_kernels_bit_identical() {
+        let n = PARALLEL_THRESHOLD + 1_234;
+        let results: Array1<f64> = (0..n).map(|i| 10.0 + ((i * 7919) % 1000) as f64 / 3000.0).collect();
+        let u_results: Array1<f64> = (0..n).map(|i| 0.01 + (i % 17) as f64 / 1000.0).collect();
+        
+        let mut serial = Array1::zeros(n);
+        let mut parallel = Array1::zeros(n);
+        z_score_kernel(results.view(), 10.1, 0.07, &mut serial.view_mut(), false);
+        z_score_kernel(results.view(), 10.1, 0.07, &mut parallel.view_mut(), true);
+        assert!(serial.iter().zip(parallel.iter()).all(|(a, b)| a.to_bits() == b.to_bits()));
+        
+        z_prime_score_kernel(results.view(), u_results.view(), 10.1, 0.02, &mut serial.view_mut(), false);
+        z_prime_score_kernel(results.view(), u_results.view(), 10.1, 0.02, &mut parallel.view_mut(), true);
+        assert!(serial.iter().zip(parallel.iter()).all(|(a, b)| a.to_bits() == b.to_bits()));
+    }
+
+    #[test]
+    fn test_large_array_scores_match_reference_formula() {
+        let n = PARALLEL_THRESHOLD * 2;
+        let results: Array1<f64> = (0..n).map(|i| (i % 101) as f64 * 0.01).collect();
+        let z_scores = calculate_z_scores(results.view(), 0.5, 0.2).unwrap();
+        
+        for (&z, &x_i) in z_scores.iter().zip(results.iter()) {
+            assert_eq!(z.to_bits(), ((x_i - 0.5) / 0.2).to_bits());
+        }
+    }
+
+    #[test]
+    fn test_scores_into_rejects_wrong_shape() {
+        let results = array![9.8, 10.0, 10.2];
+        let u_results = array![0.05, 0.06, 0.04];
+        let mut out = Array1::from_elem(2, -1.0);
+        
+        let result = calculate_z_scores_into(results.view(), 10.0, 0.1, &mut out.view_mut());
+        assert!(matches!(result, Err(CalculationError::DimensionMismatch { .. })));
+        
+        let result = calculate_z_prime_scores_into(
+            results.view(),
+            u_results.view(),
+            10.0,
+            0.03,
+            &mut out.view_mut(),
+        );
+        assert!(matches!(result, Err(CalculationError::DimensionMismatch { .. })));
+        
+        // Output is untouched on error
+        assert_eq!(out, array![-1.0, -1.0]);
+    }
+
+    #[test]
+    fn test_z_scores_with_invalid_data() {
+        let results = array![9.8, f64::NAN, 10.2];
+        let x_pt = 10.0;
+        let sigma_pt = 0.1;
+        
+        let result = calculate_z_scores(results.view(), x_pt, sigma_pt);
+        assert!(result.is_err());
+        matches!(result.unwrap_err(), CalculationError::InvalidInput { .. });
+    }
+
+    #[test]
+    fn test_boundary_sensitivity() {
+        let z_scores = array![1.95, 0.5, f64::NAN];
+        let limits = ScoreLimits::default();
+        let sensitivity = boundary_sensitivity(z_scores.view(), 0.1, 1.0, &limits).unwrap();
+        
+        // 1.95 ± 0.2 straddles the warning limit
+        assert!(sensitivity[0].flagged);
+        assert_abs_diff_eq!(sensitivity[0].z_lower, 1.75, epsilon = 1e-12);
+        assert_abs_diff_eq!(sensitivity[0].z_upper, 2.15, epsilon = 1e-12);
+        
+        assert!(!sensitivity[1].flagged);
+        assert_abs_diff_eq!(sensitivity[1].z_lower, 0.3, epsilon = 1e-12);
+        assert_abs_diff_eq!(sensitivity[1].z_upper, 0.7, epsilon = 1e-12);
+        
+        assert!(!sensitivity[2].flagged);
+        assert!(sensitivity[2].z_lower.is_nan());
+        
+        // Interval spanning both warning limits has the same category at each end
+        let wide = boundary_sensitivity(array![0.0].view(), 1.25, 1.0, &limits).unwrap();
+        assert!(wide[0].flagged);
+        
+        // Without uncertainty nothing is flagged
+        let exact = boundary_sensitivity(z_scores.view(), 0.0, 1.0, &limits).unwrap();
+        assert!(exact.iter().all(|s| !s.flagged));
+        
+        assert!(boundary_sensitivity(z_scores.view(), -0.1, 1.0, &limits).is_err());
+        assert!(boundary_sensitivity(z_scores.view(), 0.1, 0.0, &limits).is_err());
+    }
+
+    #[test]
+    fn test_z_score_uncertainties() {
+        let results = array![12.0, 10.0];
+        let u_results = array![0.3, 0.0];
+        
+        // z = 2 for the first participant:
+        // u(z)^2 = (0.09 + 0.16) / 1 + 4 * (0.1 / 1)^2 = 0.29
+        let u_z = z_score_uncertainties(
+            results.view(), u_results.view(), 10.0, 0.4, 1.0, Some(0.1), false,
+        ).unwrap();
+        assert_abs_diff_eq!(u_z[0], 0.29_f64.sqrt(), epsilon = 1e-12);
+        // z = 0, so the sigma_pt term vanishes
+        assert_abs_diff_eq!(u_z[1], 0.4, epsilon = 1e-12);
+        
+        // Without u(sigma_pt) and with sigma_pt = 2:
+        // u(z)^2 = (0.09 + 0.16) / 4
+        let u_z = z_score_uncertainties(
+            results.view(), u_results.view(), 10.0, 0.4, 2.0, None, false,
+        ).unwrap();
+        assert_abs_diff_eq!(u_z[0], 0.25, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_z_score_uncertainties_missing_and_invalid() {
+        let results = array![12.0, 10.0];
+        let missing = array![f64::NAN, 0.3];
+        
+        assert!(z_score_uncertainties(
+            results.view(), missing.view(), 10.0, 0.4, 1.0, None, false,
+        ).is_err());
+        
+        let u_z = z_score_uncertainties(
+            results.view(), missing.view(), 10.0, 0.4, 1.0, None, true,
+        ).unwrap();
+        assert_abs_diff_eq!(u_z[0], 0.4, epsilon = 1e-12);
+        assert_abs_diff_eq!(u_z[1], 0.5, epsilon = 1e-12);
+        
+        let negative = array![-0.1, 0.3];
+        assert!(z_score_uncertainties(
+            results.view(), negative.view(), 10.0, 0.4, 1.0, None, true,
+        ).is_err());
+        assert!(z_score_uncertainties(
+            results.view(), missing.view(), 10.0, -0.4, 1.0, None, true,
+        ).is_err());
+        assert!(z_score_uncertainties(
+            results.view(), missing.view(), 10.0, 0.4, 1.0, Some(-0.1), true,
+        ).is_err());
+        assert!(z_score_uncertainties(
+            results.view(), array![0.1].view(), 10.0, 0.4, 1.0, None, true,
+        ).is_err());
+    }
+
+    #[test]
+    fn test_z_scores_asymmetric() {
+        let results = array![8.0, 10.0, 14.0];
+        let z_scores = calculate_z_scores_asymmetric(results.view(), 10.0, 0.5, 4.0).unwrap();
+        
+        assert_abs_diff_eq!(z_scores[0], -4.0, epsilon = 1e-12);
+        assert_eq!(z_scores[1], 0.0);
+        assert!(z_scores[1].is_sign_positive());
+        assert_abs_diff_eq!(z_scores[2], 1.0, epsilon = 1e-12);
+        
+        // Equal sigmas reproduce the symmetric z-score
+        let symmetric = calculate_z_scores(results.view(), 10.0, 2.0).unwrap();
+        let asymmetric = calculate_z_scores_asymmetric(results.view(), 10.0, 2.0, 2.0).unwrap();
+        assert_eq!(symmetric, asymmetric);
+        
+        assert!(calculate_z_scores_asymmetric(results.view(), 10.0, 0.0, 4.0).is_err());
+        assert!(calculate_z_scores_asymmetric(results.view(), 10.0, 0.5, f64::INFINITY).is_err());
+    }
+
+    #[test]
+    fn test_negative_policy() {
+        let results = array![-1.0, 0.5, 2.0];
+        
+        let (allowed, negatives) = apply_negative_policy(results.view(), NegativePolicy::Allow).unwrap();
+        assert_eq!(negatives, 1);
+        let z_scores = calculate_z_scores(allowed.view(), 1.0, 1.0).unwrap();
+        assert_eq!(z_scores, array![-2.0, -0.5, 1.0]);
+        
+        let (clamped, negatives) = apply_negative_policy(results.view(), NegativePolicy::ClampZero).unwrap();
+        assert_eq!(negatives, 1);
+        let z_scores = calculate_z_scores(clamped.view(), 1.0, 1.0).unwrap();
+        assert_eq!(z_scores, array![-1.0, -0.5, 1.0]);
+        
+        assert!(apply_negative_policy(results.view(), NegativePolicy::Reject).is_err());
+        let (accepted, negatives) = apply_negative_policy(array![0.0, 2.0].view(), NegativePolicy::Reject).unwrap();
+        assert_eq!(negatives, 0);
+        assert_eq!(accepted, array![0.0, 2.0]);
+        
+        // NaN is rejected rather than clamped to zero
+        for policy in [NegativePolicy::Allow, NegativePolicy::ClampZero, NegativePolicy::Reject] {
+            assert!(apply_negative_policy(array![-1.0, f64::NAN].view(), policy).is_err());
+        }
+    }
+
+    #[test]
+    fn test_negative_policy_parsing() {
+        assert_eq!("allow".parse::<NegativePolicy>().unwrap(), NegativePolicy::Allow);
+        assert_eq!("clamp_zero".parse::<NegativePolicy>().unwrap(), NegativePolicy::ClampZero);
+        assert_eq!("reject".parse::<NegativePolicy>().unwrap(), NegativePolicy::Reject);
+        assert_eq!(NegativePolicy::default(), NegativePolicy::Allow);
+        assert!("ignore".parse::<NegativePolicy>().is_err());
+    }
+
+    /// Deterministic pseudo-random values in [low, high) for screening tests
+    fn scattered_values(n: usize, low: f64, high: f64, seed: u64) -> Vec<f64> {
+        let mut state = seed;
+        (0..n)
+            .map(|_| {
+                state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+                low + (high - low) * ((state >> 11) as f64 / (1u64 << 53) as f64)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_detect_identical_results() {
+        let mut results = scattered_values(30, 9.0, 11.0, 42);
+        results[7] = 10.123456;
+        results[21] = 10.123456;
+        let results = Array1::from(results);
+        
+        assert_eq!(detect_identical_results(results.view(), 0.0).unwrap(), vec![vec![7, 21]]);
+        
+        // Near-identical submissions only match with a tolerance
+        let near = array![10.0, 12.5, 10.0001, 15.0];
+        assert!(detect_identical_results(near.view(), 0.0).unwrap().is_empty());
+        assert_eq!(detect_identical_results(near.view(), 1e-4).unwrap(), vec![vec![0, 2]]);
+        
+        assert!(detect_identical_results(near.view(), -1e-4).is_err());
+    }
+
+    #[test]
+    fn test_detect_identical_results_multi() {
+        let participants = 20;
+        let analytes = 4;
+        let mut results = Array2::from_shape_vec(
+            (participants, analytes),
+            scattered_values(participants * analytes, 1.0, 100.0, 7),
+        ).unwrap();
+        
+        // Colluding pair copies every analyte
+        let copied = results.row(3).to_owned();
+        results.row_mut(15).assign(&copied);
+        // Coincidental match on a single analyte
+        results[[5, 2]] = results[[11, 2]];
+        
+        let groups = detect_identical_results_multi(results.view(), 0.0, 3).unwrap();
+        assert_eq!(groups, vec![vec![3, 15]]);
+        
+        let groups = detect_identical_results_multi(results.view(), 0.0, 1).unwrap();
+        assert_eq!(groups, vec![vec![3, 15], vec![5, 11]]);
+        
+        assert!(detect_identical_results_multi(results.view(), 0.0, 0).is_err());
+        assert!(detect_identical_results_multi(results.view(), 0.0, 5).is_err());
+    }
+
+    #[test]
+    fn test_grubbs_flags_clear_outlier() {
+        let results = array![10.1, 9.9, 10.0, 10.2, 9.8, 10.1, 25.0, 9.9, 10.0, 10.05];
+        let flags = grubbs_flags(results.view(), 0.05, GrubbsAlternative::TwoSided).unwrap();
+        
+        assert_eq!(flags.len(), results.len());
+        assert!(flags[6]);
+        assert_eq!(flags.iter().filter(|&&f| f).count(), 1);
+        
+        assert!(grubbs_flags(array![1.0, 2.0].view(), 0.05, GrubbsAlternative::TwoSided).is_err());
+    }
+
+    #[test]
+    fn test_grubbs_flags_clean_normal_data() {
+        let mut rng = crate::utils::SplitMix64::new(2024);
+        let simulations = 500;
+        
+        let flagged = (0..simulations)
+            .filter(|_| {
+                let data: Array1<f64> = (0..20).map(|_| 10.0 + rng.next_normal()).collect();
+                grubbs_flags(data.view(), 0.05, GrubbsAlternative::TwoSided).unwrap().iter().any(|&f| f)
+            })
+            .count();
+        
+        // Nominal false-positive rate is 5%
+        assert!(flagged < simulations / 10, "{} of {} clean data sets flagged", flagged, simulations);
+    }
+
+    #[test]
+    fn test_round_scores_modes() {
+        let scores = array![1.985, -1.985, 1.234, f64::NAN];
+        
+        let even = round_scores(scores.view(), 2, RoundingMode::HalfEven).unwrap();
+        assert_eq!(even[0], 1.98);
+        assert_eq!(even[1], -1.98);
+        assert_eq!(even[2], 1.23);
+        assert!(even[3].is_nan());
+        
+        let up = round_scores(scores.view(), 2, RoundingMode::HalfUp).unwrap();
+        assert_eq!(up[0], 1.99);
+        assert_eq!(up[1], -1.99);
+        
+        assert!(round_scores(scores.view(), 10, RoundingMode::HalfEven).is_err());
+    }
 
-/// Interpret zeta-score performance according to ISO 13528:2022
-/// 
-/// # Arguments
-/// * `z_prime_score` - The calculated zeta-score
-/// 
-/// # Returns
-/// * String describing the performance level
-pub fn interpret_z_prime_score(z_prime_score: f64) -> String {
-    let abs_z_prime = z_prime_score.abs();
-    
-    if abs_z_prime <= 2.0 {
-        "Satisfactory".to_string()
-    } else {
-        "Unsatisfactory".to_string()
+    #[test]
+    fn test_round_scores_borderline_classification() {
+        // |z| = 1.995 rounds to 2.00 under both modes (9 is odd, so the even
+        // neighbour is also the upper one) and stays satisfactory
+        let scores = array![1.995];
+        for mode in [RoundingMode::HalfEven, RoundingMode::HalfUp] {
+            let rounded = round_scores(scores.view(), 2, mode).unwrap();
+            assert_eq!(rounded[0], 2.0);
+            assert_eq!(interpret_z_score(rounded[0]), "Satisfactory");
+        }
+        
+        // |z| = 2.005 is where the two modes classify differently
+        let scores = array![2.005];
+        let even = round_scores(scores.view(), 2, RoundingMode::HalfEven).unwrap();
+        let up = round_scores(scores.view(), 2, RoundingMode::HalfUp).unwrap();
+        assert_eq!(even[0], 2.0);
+        assert_eq!(up[0], 2.01);
+        assert_eq!(interpret_z_score(even[0]), "Satisfactory");
+        assert_eq!(interpret_z_score(up[0]), "Questionable");
+        
+        // A score genuinely just below the half-step is not pulled onto it
+        let scores = array![2.004999999999, 2.005];
+        let up = round_scores(scores.view(), 2, RoundingMode::HalfUp).unwrap();
+        assert_eq!(up[0], 2.0);
+        assert_eq!(up[1], 2.01);
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use approx::assert_abs_diff_eq;
-    use ndarray::array;
+    #[test]
+    fn test_score_dtype_from_str() {
+        assert_eq!("float64".parse::<ScoreDtype>().unwrap(), ScoreDtype::Float64);
+        assert_eq!("float32".parse::<ScoreDtype>().unwrap(), ScoreDtype::Float32);
+        assert_eq!(ScoreDtype::default(), ScoreDtype::Float64);
+        
+        let err = "float16".parse::<ScoreDtype>().unwrap_err();
+        assert_eq!(err.to_string(), "Invalid input: Unknown dtype 'float16': expected 'float64' or 'float32'");
+    }
 
     #[test]
-    fn test_z_scores_calculation() {
-        let results = array![9.8, 10.0, 10.2, 9.9, 10.1];
-        let x_pt = 10.0;
-        let sigma_pt = 0.1;
+    fn test_log_z_scores() {
+        // Results spanning four decades around x_pt = 1
+        let results = array![0.01, 0.1, 1.0, 10.0, 100.0];
+        let z_scores = calculate_log_z_scores(results.view(), 1.0, 0.5).unwrap();
         
-        let z_scores = calculate_z_scores(results.view(), x_pt, sigma_pt).unwrap();
+        for (z, expected) in z_scores.iter().zip([-4.0, -2.0, 0.0, 2.0, 4.0]) {
+            assert_abs_diff_eq!(*z, expected, epsilon = 1e-12);
+        }
         
-        assert_eq!(z_scores.len(), 5);
-        assert_abs_diff_eq!(z_scores[0], -2.0, epsilon = 1e-10); // (9.8 - 10.0) / 0.1
-        assert_abs_diff_eq!(z_scores[1], 0.0, epsilon = 1e-10);  // (10.0 - 10.0) / 0.1
-        assert_abs_diff_eq!(z_scores[2], 2.0, epsilon = 1e-10);  // (10.2 - 10.0) / 0.1
+        // Scores depend only on the ratio to x_pt
+        let scaled = calculate_log_z_scores((&results * 1000.0).view(), 1000.0, 0.5).unwrap();
+        for (a, b) in z_scores.iter().zip(scaled.iter()) {
+            assert_abs_diff_eq!(*a, *b, epsilon = 1e-12);
+        }
     }
 
     #[test]
-    fn test_z_scores_invalid_sigma() {
-        let results = array![9.8, 10.0, 10.2];
-        let x_pt = 10.0;
-        let sigma_pt = 0.0; // Invalid
+    fn test_log_z_scores_invalid() {
+        match calculate_log_z_scores(array![1.0, 2.0, -0.5].view(), 1.0, 0.1) {
+            Err(CalculationError::InvalidInput { message }) => assert!(message.contains("index 2")),
+            other => panic!("Expected InvalidInput, got {:?}", other),
+        }
+        assert!(calculate_log_z_scores(array![0.0].view(), 1.0, 0.1).is_err());
+        assert!(calculate_log_z_scores(array![1.0].view(), 0.0, 0.1).is_err());
+        assert!(calculate_log_z_scores(array![1.0].view(), 1.0, 0.0).is_err());
+    }
+
+    #[test]
+    fn test_log_sigma_acceptance_factors() {
+        let (lower, upper) = log_sigma_acceptance_factors(0.15, 2.0).unwrap();
+        assert_abs_diff_eq!(upper, 10f64.powf(0.3), epsilon = 1e-12);
+        assert_abs_diff_eq!(lower * upper, 1.0, epsilon = 1e-12);
         
-        let result = calculate_z_scores(results.view(), x_pt, sigma_pt);
-        assert!(result.is_err());
+        // A result at x_pt * upper scores exactly the limit
+        let z = calculate_log_z_scores(array![5.0 * upper].view(), 5.0, 0.15).unwrap();
+        assert_abs_diff_eq!(z[0], 2.0, epsilon = 1e-12);
+        
+        assert!(log_sigma_acceptance_factors(0.15, 0.0).is_err());
     }
 
     #[test]
-    fn test_z_prime_scores_calculation() {
-        let results = array![9.8, 10.0, 10.2];
-        let u_results = array![0.05, 0.05, 0.05];
-        let x_pt = 10.0;
-        let u_x_pt = 0.03;
+    fn test_score_by_group_systematic_offset() {
+        // Method 1 reads about 1.0 higher than method 0
+        let method_0 = [9.8, 10.1, 10.0, 9.9, 10.2, 10.0];
+        let method_1 = [10.9, 11.0, 11.2, 10.8, 11.1];
+        let results: Array1<f64> = method_0.iter().chain(method_1.iter()).copied().collect();
+        let group_ids: Array1<i64> = [0; 6].iter().chain([1; 5].iter()).copied().collect();
         
-        let z_prime_scores = calculate_z_prime_scores(
-            results.view(), 
-            u_results.view(), 
-            x_pt, 
-            u_x_pt
-        ).unwrap();
+        let scores = score_by_group(results.view(), group_ids.view(), 10.5, 0.5, None).unwrap();
         
-        assert_eq!(z_prime_scores.len(), 3);
+        assert_eq!(scores.overall_z, calculate_z_scores(results.view(), 10.5, 0.5).unwrap());
+        assert_eq!(scores.groups.len(), 2);
+        assert_eq!((scores.groups[0].group, scores.groups[0].n), (0, 6));
+        assert_eq!((scores.groups[1].group, scores.groups[1].n), (1, 5));
         
-        // Combined uncertainty = sqrt(0.05^2 + 0.03^2) = sqrt(0.0034) ≈ 0.0583
-        let combined_u = (0.05_f64.powi(2) + 0.03_f64.powi(2)).sqrt();
-        assert_abs_diff_eq!(z_prime_scores[0], -0.2 / combined_u, epsilon = 1e-6);
-        assert_abs_diff_eq!(z_prime_scores[1], 0.0, epsilon = 1e-10);
-        assert_abs_diff_eq!(z_prime_scores[2], 0.2 / combined_u, epsilon = 1e-6);
+        // Against the overall consensus the groups sit on opposite sides...
+        assert!(scores.groups[0].median_overall_z < -0.8);
+        assert!(scores.groups[1].median_overall_z > 0.8);
+        
+        // ...but each group is centred on its own consensus
+        for (summary, values) in scores.groups.iter().zip([&method_0[..], &method_1[..]]) {
+            let expected = calculate_algorithm_a(
+                ArrayView1::from(values),
+                DEFAULT_TOLERANCE,
+                DEFAULT_MAX_ITERATIONS,
+            ).unwrap();
+            let assigned = summary.assigned.unwrap();
+            assert_eq!(assigned.x_pt, expected.x_pt);
+            assert_eq!(assigned.sigma_pt, expected.s_star);
+        }
+        let group_1 = scores.groups[1].assigned.unwrap();
+        assert_abs_diff_eq!(scores.group_z[6], (10.9 - group_1.x_pt) / group_1.sigma_pt, epsilon = 1e-12);
+        assert!(scores.group_z.iter().all(|z| z.abs() < 2.0));
     }
 
     #[test]
-    fn test_z_prime_scores_dimension_mismatch() {
-        let results = array![9.8, 10.0, 10.2];
-        let u_results = array![0.05, 0.05]; // Wrong size
-        let x_pt = 10.0;
-        let u_x_pt = 0.03;
+    fn test_score_by_group_small_group_not_evaluated() {
+        let results = array![9.8, 10.1, 10.0, 9.9, 10.2, 12.0, 12.4];
+        let group_ids = array![3, 3, 3, 3, 3, 7, 7];
         
-        let result = calculate_z_prime_scores(
-            results.view(), 
-            u_results.view(), 
-            x_pt, 
-            u_x_pt
+        let scores = score_by_group(results.view(), group_ids.view(), 10.0, 0.5, None).unwrap();
+        
+        assert!(scores.groups[0].assigned.is_some());
+        assert_eq!(scores.groups[1].group, 7);
+        assert!(scores.groups[1].assigned.is_none());
+        assert!(scores.group_z.iter().take(5).all(|z| z.is_finite()));
+        assert!(scores.group_z[5].is_nan() && scores.group_z[6].is_nan());
+        assert_eq!(classify_score(scores.group_z[5], &ScoreLimits::default()), ScoreCategory::NotEvaluated);
+        // Overall scores are still available for the small group
+        assert_abs_diff_eq!(scores.overall_z[6], 4.8, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_score_by_group_failed_group_not_evaluated() {
+        // The squared residuals of group 1 overflow, so Algorithm A cannot converge on it
+        let results = array![9.8, 10.1, 10.0, 9.9, 10.2, 1e160, 2e160, 3e160, 4e160, 5e160];
+        let group_ids = array![0, 0, 0, 0, 0, 1, 1, 1, 1, 1];
+        assert!(matches!(
+            calculate_algorithm_a(results.slice(ndarray::s![5..]), DEFAULT_TOLERANCE, DEFAULT_MAX_ITERATIONS),
+            Err(CalculationError::NonConvergence { .. })
+        ));
+        
+        let scores = score_by_group(results.view(), group_ids.view(), 0.0, 1e160, None).unwrap();
+        
+        assert!(scores.groups[0].assigned.is_some());
+        assert!(scores.group_z.iter().take(5).all(|z| z.is_finite()));
+        assert!(scores.groups[1].assigned.is_none());
+        assert!(scores.group_z.iter().skip(5).all(|z| z.is_nan()));
+    }
+
+    #[test]
+    fn test_score_by_group_supplied_values() {
+        let results = array![10.0, 11.0, 12.0, 13.0];
+        let group_ids = array![0, 0, 1, 2];
+        let per_group = BTreeMap::from([
+            (0, GroupAssigned { x_pt: 10.5, sigma_pt: 0.25 }),
+            (1, GroupAssigned { x_pt: 11.0, sigma_pt: 0.5 }),
+        ]);
+        
+        let scores = score_by_group(results.view(), group_ids.view(), 11.0, 1.0, Some(&per_group)).unwrap();
+        
+        assert_eq!(scores.group_z[0], -2.0);
+        assert_eq!(scores.group_z[1], 2.0);
+        assert_eq!(scores.group_z[2], 2.0);
+        // Group 2 has no supplied values
+        assert!(scores.group_z[3].is_nan());
+        assert!(scores.groups[2].assigned.is_none());
+        
+        let invalid = BTreeMap::from([(0, GroupAssigned { x_pt: 10.5, sigma_pt: 0.0 })]);
+        assert!(score_by_group(results.view(), group_ids.view(), 11.0, 1.0, Some(&invalid)).is_err());
+        assert!(matches!(
+            score_by_group(results.view(), array![0, 1].view(), 11.0, 1.0, None),
+            Err(CalculationError::DimensionMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn test_z_score_diagnostics() {
+        let z_scores = array![-1.0, 0.0, 1.0, 2.5, -3.5, f64::NAN];
+        let diagnostics = z_score_diagnostics(z_scores.view()).unwrap();
+        
+        assert_eq!(diagnostics.n, 5);
+        assert_abs_diff_eq!(diagnostics.mean, -0.2, epsilon = 1e-12);
+        // Σ(z - mean)^2 = 0.64 + 0.04 + 1.44 + 7.29 + 10.89 = 20.3
+        assert_abs_diff_eq!(diagnostics.sd, (20.3f64 / 4.0).sqrt(), epsilon = 1e-12);
+        assert_abs_diff_eq!(diagnostics.fraction_above_2, 0.4, epsilon = 1e-12);
+        assert_abs_diff_eq!(diagnostics.fraction_above_3, 0.2, epsilon = 1e-12);
+        
+        // Exactly 2 is not above the limit
+        let boundary = z_score_diagnostics(array![2.0, -2.0].view()).unwrap();
+        assert_eq!(boundary.fraction_above_2, 0.0);
+    }
+
+    #[test]
+    fn test_z_score_diagnostics_calibrated_design() {
+        let mut rng = crate::utils::SplitMix64::new(99);
+        let z_scores: Array1<f64> = (0..20_000).map(|_| rng.next_normal()).collect();
+        let diagnostics = z_score_diagnostics(z_scores.view()).unwrap();
+        
+        assert_abs_diff_eq!(diagnostics.mean, 0.0, epsilon = 0.03);
+        assert_abs_diff_eq!(diagnostics.sd, 1.0, epsilon = 0.03);
+        assert_abs_diff_eq!(diagnostics.fraction_above_2, 0.0455, epsilon = 0.006);
+        assert_abs_diff_eq!(diagnostics.fraction_above_3, 0.0027, epsilon = 0.0015);
+    }
+
+    #[test]
+    fn test_z_score_diagnostics_invalid() {
+        assert!(matches!(
+            z_score_diagnostics(array![1.0, f64::NAN].view()),
+            Err(CalculationError::InsufficientData { required: 2, actual: 1 })
+        ));
+        assert!(z_score_diagnostics(array![1.0, f64::INFINITY].view()).is_err());
+    }
+
+    #[test]
+    fn test_score_set_json_schema() {
+        let results = array![9.8, 10.3];
+        let u_results = array![0.1, 0.1];
+        let score_set = ScoreSet::new(results.view(), 10.0, 0.1).unwrap()
+            .with_zeta_scores(u_results.view(), 0.0).unwrap()
+            .with_participant_ids(vec!["LAB001".to_string(), "LAB007".to_string()]).unwrap();
+        
+        let json: serde_json::Value = serde_json::from_str(&score_set.to_json().unwrap()).unwrap();
+        
+        let mut keys: Vec<&String> = json.as_object().unwrap().keys().collect();
+        keys.sort();
+        assert_eq!(keys, vec!["participants", "sigma_pt", "x_pt"]);
+        assert_eq!(json["x_pt"], 10.0);
+        assert_eq!(json["sigma_pt"], 0.1);
+        
+        let participant = &json["participants"][1];
+        let mut keys: Vec<&String> = participant.as_object().unwrap().keys().collect();
+        keys.sort();
+        assert_eq!(keys, vec!["index", "participant_id", "result", "z_score", "zeta_score"]);
+        assert_eq!(participant["participant_id"], "LAB007");
+        assert_eq!(participant["index"], 1);
+        assert_eq!(participant["result"], 10.3);
+        assert_abs_diff_eq!(participant["z_score"].as_f64().unwrap(), 3.0, epsilon = 1e-10);
+        assert_abs_diff_eq!(participant["zeta_score"].as_f64().unwrap(), 3.0, epsilon = 1e-10);
+    }
+
+    #[test]
+    fn test_score_set_json_nulls() {
+        let score_set = ScoreSet::new(array![9.8].view(), 10.0, 0.1).unwrap();
+        let json: serde_json::Value = serde_json::from_str(&score_set.to_json().unwrap()).unwrap();
+        
+        let participant = &json["participants"][0];
+        assert!(participant["participant_id"].is_null());
+        assert!(participant["zeta_score"].is_null());
+        assert_eq!(participant["index"], 0);
+        
+        // NaN scores are encoded as null rather than producing invalid JSON
+        let score = ParticipantScore { index: 0, result: 1.0, z_score: f64::NAN, zeta_score: Some(f64::NAN) };
+        let json = serde_json::to_value(&score).unwrap();
+        assert!(json["z_score"].is_null());
+        assert!(json["zeta_score"].is_null());
+    }
+
+    #[test]
+    fn test_summary_json_field_names() {
+        let diagnostics = z_score_diagnostics(array![-1.0, 1.0].view()).unwrap();
+        let json = serde_json::to_value(diagnostics).unwrap();
+        let mut keys: Vec<&String> = json.as_object().unwrap().keys().collect();
+        keys.sort();
+        assert_eq!(keys, vec!["fraction_above_2", "fraction_above_3", "mean", "n", "sd"]);
+        
+        let summary = GroupSummary { group: 2, n: 3, assigned: None, median_overall_z: 0.5 };
+        let json = serde_json::to_value(summary).unwrap();
+        assert!(json["assigned"].is_null());
+        assert_eq!(json["group"], 2);
+    }
+
+    #[test]
+    fn test_z_scores_grouped() {
+        let results = array![10.2, 10.2, 9.0, 10.0];
+        let group_ids = array![1, 2, 2, 1];
+        let sigma_by_group = HashMap::from([(1, 0.1), (2, 0.5)]);
+        
+        let z_scores = calculate_z_scores_grouped(results.view(), group_ids.view(), 10.0, &sigma_by_group).unwrap();
+        
+        assert_abs_diff_eq!(z_scores[0], 2.0, epsilon = 1e-10);
+        assert_abs_diff_eq!(z_scores[1], 0.4, epsilon = 1e-10);
+        assert_abs_diff_eq!(z_scores[2], -2.0, epsilon = 1e-10);
+        assert_eq!(z_scores[3], 0.0);
+        
+        // A single group reproduces the ungrouped z-scores
+        let single = HashMap::from([(1, 0.1), (2, 0.1)]);
+        assert_eq!(
+            calculate_z_scores_grouped(results.view(), group_ids.view(), 10.0, &single).unwrap(),
+            calculate_z_scores(results.view(), 10.0, 0.1).unwrap()
         );
-        assert!(result.is_err());
-        matches!(result.unwrap_err(), CalculationError::DimensionMismatch { .. });
     }
 
     #[test]
-    fn test_z_prime_scores_negative_uncertainty() {
-        let results = array![9.8, 10.0, 10.2];
-        let u_results = array![0.05, -0.05, 0.05]; // Negative uncertainty
-        let x_pt = 10.0;
-        let u_x_pt = 0.03;
+    fn test_z_scores_grouped_invalid() {
+        let results = array![10.2, 9.0];
+        let group_ids = array![1, 3];
         
-        let result = calculate_z_prime_scores(
-            results.view(), 
-            u_results.view(), 
-            x_pt, 
-            u_x_pt
+        match calculate_z_scores_grouped(results.view(), group_ids.view(), 10.0, &HashMap::from([(1, 0.1)])) {
+            Err(CalculationError::InvalidInput { message }) => assert!(message.contains("group 3")),
+            other => panic!("Expected InvalidInput, got {:?}", other),
+        }
+        
+        // Every supplied sigma is validated, even for groups without participants
+        let non_positive = HashMap::from([(1, 0.1), (3, 0.2), (4, 0.0)]);
+        assert!(calculate_z_scores_grouped(results.view(), group_ids.view(), 10.0, &non_positive).is_err());
+        
+        let sigma_by_group = HashMap::from([(1, 0.1), (3, 0.2)]);
+        assert!(matches!(
+            calculate_z_scores_grouped(results.view(), array![1].view(), 10.0, &sigma_by_group),
+            Err(CalculationError::DimensionMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn test_participant_history_weighted_index() {
+        let mut history = ParticipantHistory::new("LAB001".to_string());
+        history.add_round("2024-1".to_string(), 1.0).unwrap();
+        history.add_round("2024-2".to_string(), -3.0).unwrap();
+        history.add_round("2024-3".to_string(), 2.0).unwrap();
+        
+        // E1 = 1, E2 = 0.4 * 3 + 0.6 * 1 = 1.8, E3 = 0.4 * 2 + 0.6 * 1.8 = 1.88
+        assert_abs_diff_eq!(history.weighted_index(0.4).unwrap(), 1.88, epsilon = 1e-12);
+        assert_abs_diff_eq!(history.weighted_index(1.0).unwrap(), 2.0, epsilon = 1e-12);
+        
+        assert!(history.weighted_index(0.0).is_err());
+        assert!(history.weighted_index(1.5).is_err());
+        assert!(ParticipantHistory::new("LAB002".to_string()).weighted_index(0.4).is_err());
+    }
+
+    #[test]
+    fn test_participant_history_signal_count() {
+        let mut history = ParticipantHistory::new("LAB001".to_string());
+        for (round, z) in [("r1", 0.5), ("r2", 2.0), ("r3", -2.5), ("r4", 3.5)] {
+            history.add_round(round.to_string(), z).unwrap();
+        }
+        
+        assert_eq!(history.len(), 4);
+        assert_eq!(history.signal_count(&ScoreLimits::default()), 2);
+        assert_eq!(history.signal_count(&ScoreLimits::new(1.0, 3.0).unwrap()), 3);
+    }
+
+    #[test]
+    fn test_participant_history_rejects_invalid_rounds() {
+        let mut history = ParticipantHistory::new("LAB001".to_string());
+        history.add_round("r1".to_string(), 0.5).unwrap();
+        
+        match history.add_round("r1".to_string(), 1.0) {
+            Err(CalculationError::InvalidInput { message }) => assert!(message.contains("r1")),
+            other => panic!("Expected InvalidInput, got {:?}", other),
+        }
+        assert!(history.add_round("r2".to_string(), f64::NAN).is_err());
+        assert!(history.add_round("r3".to_string(), f64::INFINITY).is_err());
+        
+        // Rejected rounds leave the history unchanged
+        assert_eq!(history.round_ids(), &["r1".to_string()]);
+        assert_eq!(history.z_scores(), &[0.5]);
+    }
+
+    #[test]
+    fn test_cross_check_scores() {
+        let zeta = array![2.0, -1.0, 4.0, f64::NAN, 0.5];
+        // Participant 2 reported a standard uncertainty as expanded, so En = zeta / k^2
+        let en = array![1.0, -0.5, 1.0, 0.3, f64::NAN];
+        let flagged = cross_check_scores(zeta.view(), en.view(), 2.0, 0.1).unwrap();
+        
+        assert_eq!(flagged, vec![ScoreDiscrepancy { index: 2, discrepancy: 2.0 }]);
+        
+        // A larger tolerance accepts the mismatch
+        assert!(cross_check_scores(zeta.view(), en.view(), 2.0, 2.5).unwrap().is_empty());
+        
+        assert!(cross_check_scores(zeta.view(), en.view(), 0.0, 0.1).is_err());
+        assert!(cross_check_scores(zeta.view(), en.view(), 2.0, -0.1).is_err());
+        assert!(matches!(
+            cross_check_scores(zeta.view(), array![1.0].view(), 2.0, 0.1),
+            Err(CalculationError::DimensionMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn test_rank_by_abs_score() {
+        let scores = array![1.5, f64::NAN, -0.5, 0.5, -3.2, f64::INFINITY, 0.0];
+        let ranks = rank_by_abs_score(scores.view());
+        
+        // -0.5 and 0.5 tie on |z|; the lower index ranks first
+        assert_eq!(ranks, array![3, 6, 1, 2, 4, 5, 0]);
+        
+        assert_eq!(rank_by_abs_score(array![f64::NAN, f64::NAN, 2.0].view()), array![1, 2, 0]);
+        assert!(rank_by_abs_score(Array1::<f64>::zeros(0).view()).is_empty());
+    }
+
+    #[test]
+    fn test_score_set_from_round_data() {
+        let mut round = RoundData::new();
+        round.add_participant("LAB001".to_string(), 10.2, Some(0.1)).unwrap()
+            .add_participant("LAB002".to_string(), 9.7, Some(0.2)).unwrap();
+        
+        let score_set = ScoreSet::from_round_data(&round, 10.0, 0.1, Some(0.05)).unwrap();
+        let positional = ScoreSet::new(array![10.2, 9.7].view(), 10.0, 0.1).unwrap()
+            .with_zeta_scores(array![0.1, 0.2].view(), 0.05).unwrap();
+        
+        assert_eq!(score_set.participant_ids(), Some(&["LAB001".to_string(), "LAB002".to_string()][..]));
+        assert_eq!(score_set.z_scores(), positional.z_scores());
+        assert_eq!(score_set.zeta_scores(), positional.zeta_scores());
+        assert!(ScoreSet::from_round_data(&round, 10.0, 0.1, None).unwrap().zeta_scores().is_none());
+        
+        let mut no_u = RoundData::new();
+        no_u.add_participant("LAB001".to_string(), 10.2, None).unwrap();
+        assert!(ScoreSet::from_round_data(&no_u, 10.0, 0.1, Some(0.05)).is_err());
+    }
+
+    #[test]
+    fn test_scores_for_round() {
+        let mut round = RoundData::new();
+        round.add_participant("LAB001".to_string(), 10.2, Some(0.1)).unwrap()
+            .add_participant("LAB002".to_string(), 9.7, Some(0.2)).unwrap();
+        
+        assert_eq!(
+            calculate_z_scores_for_round(&round, 10.0, 0.1).unwrap(),
+            calculate_z_scores(array![10.2, 9.7].view(), 10.0, 0.1).unwrap()
         );
-        assert!(result.is_err());
-        matches!(result.unwrap_err(), CalculationError::InvalidInput { .. });
+        assert_eq!(
+            calculate_z_prime_scores_for_round(&round, 10.0, 0.05).unwrap(),
+            calculate_z_prime_scores(array![10.2, 9.7].view(), array![0.1, 0.2].view(), 10.0, 0.05).unwrap()
+        );
+        
+        let mut no_u = RoundData::new();
+        no_u.add_participant("LAB001".to_string(), 10.2, None).unwrap();
+        assert!(calculate_z_scores_for_round(&no_u, 10.0, 0.1).is_ok());
+        assert!(calculate_z_prime_scores_for_round(&no_u, 10.0, 0.05).is_err());
+        assert!(calculate_z_scores_for_round(&RoundData::new(), 10.0, 0.1).is_err());
     }
 
     #[test]
-    fn test_z_prime_scores_no_participant_uncertainties() {
-        let results = array![9.8, 10.0, 10.2];
-        let x_pt = 10.0;
-        let u_x_pt = 0.1;
+    fn test_unsatisfactory_rate() {
+        let mut z_scores = vec![0.5; 16];
+        z_scores.extend([3.5, -4.0, 3.0, f64::NAN, f64::NAN]);
+        let z_scores = Array1::from(z_scores);
         
-        let z_prime_scores = calculate_z_prime_scores_no_participant_uncertainties(
-            results.view(), 
-            x_pt, 
-            u_x_pt
-        ).unwrap();
+        // 3.0 is on the limit and not unsatisfactory
+        let result = unsatisfactory_rate(z_scores.view(), 3.0, 0.95).unwrap();
+        assert_eq!(result.n, 19);
+        assert_eq!(result.count, 2);
+        assert_abs_diff_eq!(result.rate, 2.0 / 19.0, epsilon = 1e-15);
         
-        assert_eq!(z_prime_scores.len(), 3);
-        assert_abs_diff_eq!(z_prime_scores[0], -2.0, epsilon = 1e-10); // (9.8 - 10.0) / 0.1
-        assert_abs_diff_eq!(z_prime_scores[1], 0.0, epsilon = 1e-10);  // (10.0 - 10.0) / 0.1
-        assert_abs_diff_eq!(z_prime_scores[2], 2.0, epsilon = 1e-10);  // (10.2 - 10.0) / 0.1
+        // Wilson interval for 2 / 19 at 95 %
+        assert_abs_diff_eq!(result.lower, 0.029358560, epsilon = 1e-8);
+        assert_abs_diff_eq!(result.upper, 0.313940827, epsilon = 1e-8);
+        
+        let none = unsatisfactory_rate(array![0.1, -0.2, 1.0].view(), 3.0, 0.95).unwrap();
+        assert_eq!(none.rate, 0.0);
+        assert_eq!(none.lower, 0.0);
+        assert!(none.upper > 0.0 && none.upper < 1.0);
     }
 
     #[test]
-    fn test_z_score_interpretation() {
-        assert_eq!(interpret_z_score(1.5), "Satisfactory");
-        assert_eq!(interpret_z_score(-1.8), "Satisfactory");
-        assert_eq!(interpret_z_score(2.5), "Questionable");
-        assert_eq!(interpret_z_score(-2.7), "Questionable");
-        assert_eq!(interpret_z_score(3.2), "Unsatisfactory");
-        assert_eq!(interpret_z_score(-4.0), "Unsatisfactory");
+    fn test_unsatisfactory_rate_invalid() {
+        let z_scores = array![0.5, 3.5];
+        assert!(unsatisfactory_rate(z_scores.view(), 0.0, 0.95).is_err());
+        assert!(unsatisfactory_rate(z_scores.view(), 3.0, 1.0).is_err());
+        assert!(unsatisfactory_rate(array![f64::NAN].view(), 3.0, 0.95).is_err());
+        assert!(unsatisfactory_rate(array![f64::INFINITY].view(), 3.0, 0.95).is_err());
     }
 
     #[test]
-    fn test_z_prime_score_interpretation() {
-        assert_eq!(interpret_z_prime_score(1.5), "Satisfactory");
-        assert_eq!(interpret_z_prime_score(-1.9), "Satisfactory");
-        assert_eq!(interpret_z_prime_score(2.1), "Unsatisfactory");
-        assert_eq!(interpret_z_prime_score(-3.0), "Unsatisfactory");
+    fn test_z_scores_from_interval() {
+        let results = array![10.0, 10.5, 9.2];
+        // Midpoint 10.0, u(x_pt) = 0.6 / (2√3) = √0.03
+        let z_scores = calculate_z_scores_from_interval(results.view(), 9.7, 10.3, 0.1).unwrap();
+        let denominator = (0.01_f64 + 0.03).sqrt();
+        
+        assert_abs_diff_eq!(z_scores[0], 0.0, epsilon = 1e-12);
+        assert_abs_diff_eq!(z_scores[1], 0.5 / denominator, epsilon = 1e-12);
+        assert_abs_diff_eq!(z_scores[2], -0.8 / denominator, epsilon = 1e-12);
+        
+        assert!(calculate_z_scores_from_interval(results.view(), 10.3, 9.7, 0.1).is_err());
+        assert!(calculate_z_scores_from_interval(results.view(), 10.0, 10.0, 0.1).is_err());
+        assert!(calculate_z_scores_from_interval(results.view(), 9.7, 10.3, 0.0).is_err());
     }
 
     #[test]
-    fn test_z_scores_with_invalid_data() {
-        let results = array![9.8, f64::NAN, 10.2];
-        let x_pt = 10.0;
-        let sigma_pt = 0.1;
+    fn test_z_scores_with_spec() {
+        let results = array![10.0, 10.5, 9.2, 11.0];
+        let (z_scores, within_spec) = calculate_z_scores_with_spec(results.view(), 10.0, 0.4, 0.5).unwrap();
         
-        let result = calculate_z_scores(results.view(), x_pt, sigma_pt);
-        assert!(result.is_err());
-        matches!(result.unwrap_err(), CalculationError::InvalidInput { .. });
+        assert_eq!(z_scores, calculate_z_scores(results.view(), 10.0, 0.4).unwrap());
+        // The tolerance itself is within specification
+        assert_eq!(within_spec, array![true, true, false, false]);
+        
+        assert!(calculate_z_scores_with_spec(results.view(), 10.0, 0.4, 0.0).is_err());
+        assert!(calculate_z_scores_with_spec(results.view(), 10.0, 0.4, f64::NAN).is_err());
+        assert!(calculate_z_scores_with_spec(results.view(), 10.0, 0.0, 0.5).is_err());
+    }
+
+    #[test]
+    fn test_z_scores_trimmed() {
+        // 10 results, 10 % trimming drops 0.0 and 100.0
+        let results = array![0.0, 9.0, 9.5, 10.0, 10.0, 10.0, 10.0, 10.5, 11.0, 100.0];
+        let trimmed = calculate_z_scores_trimmed(results.view(), 0.1).unwrap();
+        
+        assert_eq!(trimmed.n_trimmed, 1);
+        assert_abs_diff_eq!(trimmed.x_pt, 10.0, epsilon = 1e-12);
+        
+        // SD of the 8 retained values is sqrt(2.5 / 7); Φ⁻¹(0.9) = 1.2815516
+        let z = 1.281_551_565_544_600_4_f64;
+        let density = (-0.5 * z * z).exp() / (2.0 * std::f64::consts::PI).sqrt();
+        let expected_sigma = (2.5_f64 / 7.0).sqrt() / (1.0 - 2.0 * z * density / 0.8).sqrt();
+        assert_abs_diff_eq!(trimmed.sigma_pt, expected_sigma, epsilon = 1e-9);
+        
+        // Trimmed results are still scored
+        assert_eq!(trimmed.z_scores.len(), results.len());
+        assert_abs_diff_eq!(trimmed.z_scores[9], 90.0 / trimmed.sigma_pt, epsilon = 1e-9);
+        
+        // Without trimming the classical mean and SD are used
+        let untrimmed = calculate_z_scores_trimmed(results.view(), 0.05).unwrap();
+        assert_eq!(untrimmed.n_trimmed, 0);
+        assert_abs_diff_eq!(untrimmed.x_pt, results.mean().unwrap(), epsilon = 1e-12);
+        assert_abs_diff_eq!(untrimmed.sigma_pt, results.std(1.0), epsilon = 1e-12);
     }
-}
\ No newline at end of file
+    
+    #[test]
+    fn test_z_scores_trimmed_invalid() {
+        let results = array![9.0, 10.0, 11.0, 12.0];
+        assert!(calculate_z_scores_trimmed(results.view(), 0.5).is_err());
+        assert!(calculate_z_scores_trimmed(results.view(), -0.1).is_err());
+        assert!(calculate_z_scores_trimmed(results.view(), f64::NAN).is_err());
+        assert!(calculate_z_scores_trimmed(array![1.0].view(), 0.0).is_err());
+        assert!(calculate_z_scores_trimmed(array![5.0, 5.0, 5.0].view(), 0.0).is_err());
+        assert!(calculate_z_scores_trimmed(array![1.0, f64::NAN, 2.0].view(), 0.0).is_err());
+    }
+
+    #[test]
+    fn test_estimate_systematic_bias() {
+        let deviations = array![0.5, 0.3, 0.4, 0.6, f64::NAN, 0.2];
+        let bias = estimate_systematic_bias(deviations.view()).unwrap();
+        
+        assert_eq!(bias.n_rounds, 5);
+        assert_abs_diff_eq!(bias.mean, 0.4, epsilon = 1e-12);
+        // s = sqrt(0.1 / 4), SE = s / sqrt(5)
+        assert_abs_diff_eq!(bias.standard_error, (0.025_f64 / 5.0).sqrt(), epsilon = 1e-12);
+        assert_abs_diff_eq!(bias.t_statistic, 5.656_854_249_492_381, epsilon = 1e-9);
+        // Two-sided tail of t with 4 degrees of freedom, integrated numerically
+        assert_abs_diff_eq!(bias.p_value, 0.004_812_678, epsilon = 1e-8);
+        
+        // Deviations scattered around zero show no bias
+        let unbiased = estimate_systematic_bias(array![0.5, -0.4, 0.1, -0.2].view()).unwrap();
+        assert!(unbiased.p_value > 0.5);
+        
+        let constant = estimate_systematic_bias(array![-0.3, -0.3, -0.3].view()).unwrap();
+        assert_eq!(constant.t_statistic, f64::NEG_INFINITY);
+        assert_eq!(constant.p_value, 0.0);
+    }
+    
+    #[test]
+    fn test_estimate_systematic_bias_invalid() {
+        assert!(matches!(
+            estimate_systematic_bias(array![0.1, f64::NAN, 0.2].view()),
+            Err(CalculationError::InsufficientData { required: 3, actual: 2 })
+        ));
+        assert!(estimate_systematic_bias(array![0.1, f64::INFINITY, 0.2, 0.3].view()).is_err());
+    }
+
+    #[test]
+    fn test_full_diagnostics() {
+        let mut round = RoundData::new();
+        round.add_participant("LAB001".to_string(), 10.2, Some(0.1)).unwrap()
+            .add_participant("LAB002".to_string(), 10.9, Some(0.2)).unwrap();
+        
+        let assigned = AssignedValue::new(10.0).unwrap()
+            .with_uncertainty(0.05).unwrap()
+            .with_s_star(0.4).unwrap()
+            .with_coverage_factor(2.0).unwrap();
+        let diagnostics = full_diagnostics(&round, &assigned, 0.25).unwrap();
+        
+        assert_eq!(diagnostics.len(), 2);
+        let lab2 = &diagnostics[1];
+        assert_eq!(lab2.participant_id, "LAB002");
+        assert_eq!(lab2.uncertainty, Some(0.2));
+        assert_abs_diff_eq!(lab2.z_score, 3.6, epsilon = 1e-12);
+        
+        let zeta = 0.9 / (0.04_f64 + 0.0025).sqrt();
+        assert_abs_diff_eq!(lab2.zeta_score.unwrap(), zeta, epsilon = 1e-12);
+        assert_abs_diff_eq!(lab2.en_number.unwrap(), 0.9 / (0.16_f64 + 0.01).sqrt(), epsilon = 1e-12);
+        assert_abs_diff_eq!(lab2.standardized_residual.unwrap(), 2.25, epsilon = 1e-12);
+        // Huber weight psi(2.25) / 2.25 = 1.5 / 2.25
+        assert_abs_diff_eq!(lab2.weight.unwrap(), 1.5 / 2.25, epsilon = 1e-12);
+        assert_eq!(lab2.label, interpret_z_score(3.6));
+        assert_eq!(diagnostics[0].weight, Some(1.0));
+    }
+
+    #[test]
+    fn test_full_diagnostics_missing_inputs() {
+        let mut round = RoundData::new();
+        round.add_participant("A".to_string(), 10.2, None).unwrap();
+        
+        // Without participant uncertainties, s* or k only z and the label are available
+        let assigned = AssignedValue::new(10.0).unwrap().with_uncertainty(0.05).unwrap();
+        let diagnostics = full_diagnostics(&round, &assigned, 0.25).unwrap();
+        assert_abs_diff_eq!(diagnostics[0].z_score, 0.8, epsilon = 1e-12);
+        assert_eq!(diagnostics[0].uncertainty, None);
+        assert_eq!(diagnostics[0].zeta_score, None);
+        assert_eq!(diagnostics[0].en_number, None);
+        assert_eq!(diagnostics[0].standardized_residual, None);
+        assert_eq!(diagnostics[0].weight, None);
+        
+        // Zeta is available without a coverage factor, En is not
+        let mut round = RoundData::new();
+        round.add_participant("A".to_string(), 10.2, Some(0.1)).unwrap();
+        let diagnostics = full_diagnostics(&round, &assigned, 0.25).unwrap();
+        assert!(diagnostics[0].zeta_score.is_some());
+        assert_eq!(diagnostics[0].en_number, None);
+        
+        assert!(full_diagnostics(&round, &assigned, 0.0).is_err());
+        assert!(full_diagnostics(&RoundData::new(), &assigned, 0.25).is_err());
+        assert!(AssignedValue::new(f64::NAN).is_err());
+        assert!(AssignedValue::new(10.0).unwrap().with_s_star(0.0).is_err());
+        assert!(AssignedValue::new(10.0).unwrap().with_coverage_factor(-2.0).is_err());
+    }
+
+    #[test]
+    fn test_compare_assigned_values() {
+        let results = array![10.0, 10.5, 11.0, 13.2, 6.5];
+        let candidates = vec![
+            ("consensus".to_string(), 10.5, 0.0),
+            ("crm".to_string(), 10.0, 0.75),
+        ];
+        let comparisons = compare_assigned_values(results.view(), &candidates, 1.0).unwrap();
+        
+        assert_eq!(comparisons.len(), 2);
+        assert_eq!(comparisons[0].name, "consensus");
+        let expected = calculate_z_scores(results.view(), 10.5, 1.0).unwrap();
+        assert_eq!(comparisons[0].z_scores, expected);
+        assert_eq!(comparisons[0].n_unsatisfactory, 1);
+        
+        // u(x_pt) = 0.75 widens the denominator to 1.25
+        assert_abs_diff_eq!(comparisons[1].z_scores[3], 3.2 / 1.25, epsilon = 1e-12);
+        assert_abs_diff_eq!(comparisons[1].z_scores[4], -3.5 / 1.25, epsilon = 1e-12);
+        assert_eq!(comparisons[1].n_unsatisfactory, 0);
+        
+        assert!(compare_assigned_values(results.view(), &[], 1.0).is_err());
+        let duplicate = vec![("a".to_string(), 10.0, 0.0), ("a".to_string(), 11.0, 0.0)];
+        assert!(compare_assigned_values(results.view(), &duplicate, 1.0).is_err());
+        let negative = vec![("a".to_string(), 10.0, -0.1)];
+        assert!(compare_assigned_values(results.view(), &negative, 1.0).is_err());
+        assert!(compare_assigned_values(results.view(), &candidates, 0.0).is_err());
+        
+        // Zero or negative sigma_pt is rejected even when u(x_pt) > 0 keeps the denominator positive
+        let uncertain = vec![("crm".to_string(), 10.0, 0.75)];
+        assert!(compare_assigned_values(results.view(), &uncertain, 0.0).is_err());
+        assert!(compare_assigned_values(results.view(), &uncertain, -1.0).is_err());
+    }
+}
@@ -0,0 +1,134 @@
+//! Standard deviation for proficiency assessment (sigma_pt) module
+//!
+//! This module implements the rules used to set sigma_pt, the dispersion
+//! against which participant z-scores are calculated.
+
+use crate::utils::{CalculationError, is_valid_float};
+
+/// Result of applying a minimum floor to a data-derived sigma_pt
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FlooredSigma {
+    pub sigma_pt: f64,
+    pub floor_applied: bool,
+}
+
+/// Derive sigma_pt from a data-based scale estimate with a minimum floor
+/// 
+/// Returns max(data_scale, floor). The floor prevents an absurdly small sigma_pt
+/// (and thus inflated z-scores) when participants agree very closely, and is
+/// typically tied to the capability of the measurement method.
+/// 
+/// # Arguments
+/// * `data_scale` - Scale estimate derived from the data (e.g. s* from Algorithm A)
+/// * `floor` - Minimum acceptable sigma_pt
+/// 
+/// # Returns
+/// * `Ok(FlooredSigma)` - The sigma_pt and whether the floor was applied
+/// * `Err(CalculationError)` - If either input is negative or not finite
+pub fn sigma_pt_with_floor(data_scale: f64, floor: f64) -> Result<FlooredSigma, CalculationError> {
+    if !is_valid_float(data_scale) || data_scale < 0.0 {
+        return Err(CalculationError::InvalidInput {
+            message: format!("Invalid data scale estimate: {}", data_scale),
+        });
+    }
+    
+    if !is_valid_float(floor) || floor < 0.0 {
+        return Err(CalculationError::InvalidInput {
+            message: format!("Invalid sigma_pt floor: {}", floor),
+        });
+    }
+    
+    if data_scale < floor {
+        Ok(FlooredSigma { sigma_pt: floor, floor_applied: true })
+    } else {
+        Ok(FlooredSigma { sigma_pt: data_scale, floor_applied: false })
+    }
+}
+
+/// Validate a sigma_pt set by perception (expert judgment) against its documented range
+/// 
+/// Schemes that set sigma_pt by expert judgment document the range it may take;
+/// a value outside [lower_bound, upper_bound] is rejected rather than clamped,
+/// so the deviation from the documented procedure has to be resolved explicitly.
+/// 
+/// # Arguments
+/// * `value` - sigma_pt chosen by the expert (positive)
+/// * `lower_bound` - Lowest documented sigma_pt (non-negative)
+/// * `upper_bound` - Highest documented sigma_pt (at least `lower_bound`)
+/// 
+/// # Returns
+/// * `Ok(f64)` - The expert sigma_pt
+/// * `Err(CalculationError)` - If the range is invalid or the value is not positive or lies outside it
+pub fn sigma_pt_by_perception(value: f64, lower_bound: f64, upper_bound: f64) -> Result<f64, CalculationError> {
+    if !is_valid_float(lower_bound) || !is_valid_float(upper_bound)
+        || lower_bound < 0.0 || upper_bound < lower_bound
+    {
+        return Err(CalculationError::InvalidInput {
+            message: format!("Invalid sigma_pt range [{}, {}]", lower_bound, upper_bound),
+        });
+    }
+    
+    if !is_valid_float(value) || value <= 0.0 {
+        return Err(CalculationError::InvalidInput {
+            message: format!("Invalid or non-positive expert sigma_pt: {}", value),
+        });
+    }
+    
+    if value < lower_bound || value > upper_bound {
+        return Err(CalculationError::InvalidInput {
+            message: format!(
+                "Expert sigma_pt {} is outside the documented range [{}, {}]",
+                value, lower_bound, upper_bound
+            ),
+        });
+    }
+    
+    Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sigma_pt_above_floor() {
+        let result = sigma_pt_with_floor(0.5, 0.2).unwrap();
+        assert_eq!(result.sigma_pt, 0.5);
+        assert!(!result.floor_applied);
+    }
+
+    #[test]
+    fn test_sigma_pt_floor_applied() {
+        let result = sigma_pt_with_floor(0.05, 0.2).unwrap();
+        assert_eq!(result.sigma_pt, 0.2);
+        assert!(result.floor_applied);
+        
+        // Equal to the floor is not an adjustment
+        let result = sigma_pt_with_floor(0.2, 0.2).unwrap();
+        assert!(!result.floor_applied);
+    }
+
+    #[test]
+    fn test_sigma_pt_with_floor_invalid_inputs() {
+        assert!(sigma_pt_with_floor(-0.1, 0.2).is_err());
+        assert!(sigma_pt_with_floor(0.1, -0.2).is_err());
+        assert!(sigma_pt_with_floor(f64::NAN, 0.2).is_err());
+        assert!(sigma_pt_with_floor(0.1, f64::INFINITY).is_err());
+    }
+
+    #[test]
+    fn test_sigma_pt_by_perception() {
+        assert_eq!(sigma_pt_by_perception(0.3, 0.2, 0.5).unwrap(), 0.3);
+        
+        // The bounds themselves are allowed
+        assert_eq!(sigma_pt_by_perception(0.2, 0.2, 0.5).unwrap(), 0.2);
+        assert_eq!(sigma_pt_by_perception(0.5, 0.2, 0.5).unwrap(), 0.5);
+        
+        assert!(sigma_pt_by_perception(0.19, 0.2, 0.5).is_err());
+        assert!(sigma_pt_by_perception(0.51, 0.2, 0.5).is_err());
+        assert!(sigma_pt_by_perception(0.0, 0.0, 0.5).is_err());
+        assert!(sigma_pt_by_perception(0.3, 0.5, 0.2).is_err());
+        assert!(sigma_pt_by_perception(0.3, -0.1, 0.5).is_err());
+        assert!(sigma_pt_by_perception(f64::NAN, 0.2, 0.5).is_err());
+    }
+}
@@ -0,0 +1,82 @@
+//! Stability module
+//!
+//! This module implements corrections for instability of the proficiency
+//! test item over the round window, as established by a stability study.
+
+use crate::utils::{CalculationError, is_valid_float, validate_array_dimensions, validate_floats};
+use ndarray::{Array1, ArrayView1, Zip};
+
+/// Correct participant results for linear drift to a common reference time
+///
+/// Implements x_i' = x_i - drift_rate · (t_i - t_ref). The sign convention of
+/// `drift_rate` is that of the stability study slope: a positive rate means the
+/// measured value increases with time, so results measured after the reference
+/// time are corrected downwards and results measured before it upwards.
+/// Times and rate must use the same time unit (e.g. days and units per day).
+///
+/// # Arguments
+/// * `results` - Array view of participant results (x_i)
+/// * `measurement_times` - Time at which each result was measured (t_i)
+/// * `drift_rate` - Change of the measured value per unit time
+/// * `reference_time` - Time to which all results are corrected (t_ref)
+///
+/// # Returns
+/// * `Ok(Array1<f64>)` - Drift-corrected results
+/// * `Err(CalculationError)` - If the lengths differ or any input is not finite
+pub fn drift_correct(
+    results: ArrayView1<f64>,
+    measurement_times: ArrayView1<f64>,
+    drift_rate: f64,
+    reference_time: f64,
+) -> Result<Array1<f64>, CalculationError> {
+    validate_array_dimensions(results.len(), measurement_times.len(), "results", "measurement_times")?;
+    validate_floats(results, "participant results")?;
+    validate_floats(measurement_times, "measurement times")?;
+
+    if !is_valid_float(drift_rate) {
+        return Err(CalculationError::InvalidInput {
+            message: format!("Invalid drift rate: {}", drift_rate),
+        });
+    }
+
+    if !is_valid_float(reference_time) {
+        return Err(CalculationError::InvalidInput {
+            message: format!("Invalid reference time: {}", reference_time),
+        });
+    }
+
+    Ok(Zip::from(&results)
+        .and(&measurement_times)
+        .map_collect(|&x_i, &t_i| x_i - drift_rate * (t_i - reference_time)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+    use ndarray::array;
+
+    #[test]
+    fn test_drift_correct() {
+        // Item gains 0.02 per day; results corrected to day 10
+        let results = array![10.0, 10.2, 9.9];
+        let times = array![10.0, 20.0, 0.0];
+        let corrected = drift_correct(results.view(), times.view(), 0.02, 10.0).unwrap();
+
+        assert_abs_diff_eq!(corrected[0], 10.0, epsilon = 1e-12);
+        assert_abs_diff_eq!(corrected[1], 10.0, epsilon = 1e-12);
+        assert_abs_diff_eq!(corrected[2], 10.1, epsilon = 1e-12);
+
+        // No drift leaves results unchanged
+        assert_eq!(drift_correct(results.view(), times.view(), 0.0, 10.0).unwrap(), results);
+    }
+
+    #[test]
+    fn test_drift_correct_invalid() {
+        let results = array![10.0, 10.2];
+        assert!(drift_correct(results.view(), array![1.0].view(), 0.02, 0.0).is_err());
+        assert!(drift_correct(results.view(), array![1.0, f64::NAN].view(), 0.02, 0.0).is_err());
+        assert!(drift_correct(results.view(), array![1.0, 2.0].view(), f64::INFINITY, 0.0).is_err());
+        assert!(drift_correct(results.view(), array![1.0, 2.0].view(), 0.02, f64::NAN).is_err());
+    }
+}
@@ -3,17 +3,33 @@
 //! This module implements the logic for calculating the standard uncertainty 
 //! of the assigned value (u(x_pt)) corresponding to different methods.
 
-use crate::utils::{CalculationError, constants::UNCERTAINTY_FACTOR, is_valid_float};
+use crate::utils::{CalculationError, CalculationWarning, constants::*, is_valid_float, validate_floats, t_quantile, normal_quantile,
+                   validate_array_dimensions, validate_mad_consistency, mad_of, percentile, effective_sample_size, running_stats,
+                   bootstrap_std_dev, SplitMix64};
+use crate::estimators::calculate_algorithm_a;
+use ndarray::{Array1, ArrayView1, ArrayView2};
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList};
+use serde::{Serialize, Serializer};
+use std::str::FromStr;
 
 /// Calculate uncertainty for consensus values (Algorithm A)
 /// 
-/// Implements u(x_pt) = 1.25 * s* / sqrt(p) where:
-/// - s* is the robust standard deviation from Algorithm A
+/// Implements u(x_pt) = f * s / sqrt(p) where:
+/// - s is the robust standard deviation, by default s* from Algorithm A
 /// - p is the number of participants included in the calculation
+/// - f is the multiplier of the scale method (see `ScaleKind::consensus_multiplier`),
+///   1.25 for Algorithm A s*
+/// 
+/// The formula underestimates u(x_pt) for small p. With `small_sample_correction`
+/// the result is multiplied by the Student t-factor for p - 1 degrees of freedom
+/// at 68.27 % two-sided coverage (the coverage of ±1 standard deviation).
 /// 
 /// # Arguments
-/// * `robust_std_dev` - The robust standard deviation (s*) from Algorithm A
+/// * `robust_std_dev` - The robust standard deviation (s*, nIQR, MADe or Qn)
 /// * `num_participants` - Number of participants included in the robust calculation
+/// * `small_sample_correction` - Apply the t-factor correction (requires p >= 2)
+/// * `scale_method` - Which estimator produced `robust_std_dev`
 /// 
 /// # Returns
 /// * `Ok(f64)` - The calculated uncertainty u(x_pt)
@@ -21,6 +37,8 @@ use crate::utils::{CalculationError, constants::UNCERTAINTY_FACTOR, is_valid_flo
 pub fn calculate_uncertainty_consensus(
     robust_std_dev: f64,
     num_participants: usize,
+    small_sample_correction: bool,
+    scale_method: ScaleKind,
 ) -> Result<f64, CalculationError> {
     if !is_valid_float(robust_std_dev) || robust_std_dev < 0.0 {
         return Err(CalculationError::InvalidInput {
@@ -35,11 +53,589 @@ pub fn calculate_uncertainty_consensus(
         });
     }
     
-    let uncertainty = UNCERTAINTY_FACTOR * robust_std_dev / (num_participants as f64).sqrt();
+    let uncertainty = scale_method.consensus_multiplier() * robust_std_dev / (num_participants as f64).sqrt();
+    
+    if small_sample_correction {
+        if num_participants < 2 {
+            return Err(CalculationError::InsufficientData {
+                required: 2,
+                actual: num_participants,
+            });
+        }
+        
+        let t_factor = t_quantile(ONE_SIGMA_UPPER_PROBABILITY, (num_participants - 1) as f64)?;
+        return Ok(uncertainty * t_factor);
+    }
     
     Ok(uncertainty)
 }
 
+/// Consensus uncertainty from the raw and the effective number of participants
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct WeightedConsensusUncertainty {
+    /// 1.25 * s* / sqrt(p) with p the number of weights
+    pub classical: f64,
+    /// 1.25 * s* / sqrt(p_eff)
+    pub weighted: f64,
+    /// Effective number of participants (Σw)² / Σw²
+    pub p_eff: f64,
+}
+
+/// Calculate the consensus uncertainty using the effective number of participants
+/// 
+/// Algorithm A down-weights outlying results, so fewer than p results carry
+/// the estimate and 1.25 * s* / sqrt(p) understates u(x_pt). This replaces p
+/// by Kish's effective sample size p_eff = (Σw)² / Σw² of the final weights,
+/// which equals p when no result is down-weighted.
+/// 
+/// # Arguments
+/// * `s_star` - Robust standard deviation s* from Algorithm A
+/// * `weights` - Final Algorithm A weight of each participant
+/// 
+/// # Returns
+/// * `Ok(WeightedConsensusUncertainty)` - Classical and weight-adjusted u(x_pt) and p_eff
+/// * `Err(CalculationError)` - If s* or the weights are invalid
+pub fn calculate_uncertainty_consensus_weighted(
+    s_star: f64,
+    weights: &[f64],
+) -> Result<WeightedConsensusUncertainty, CalculationError> {
+    let p_eff = effective_sample_size(weights)?;
+    let classical = calculate_uncertainty_consensus(s_star, weights.len(), false, ScaleKind::SStar)?;
+    
+    Ok(WeightedConsensusUncertainty {
+        classical,
+        weighted: UNCERTAINTY_FACTOR * s_star / p_eff.sqrt(),
+        p_eff,
+    })
+}
+
+/// Robust scale estimator behind a consensus or median assigned value
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScaleKind {
+    /// Scaled median absolute deviation, 1.4826 * MAD
+    MADe,
+    /// Normalized interquartile range, 0.7413 * IQR
+    NIQR,
+    /// Robust standard deviation s* from Algorithm A
+    SStar,
+    /// Rousseeuw-Croux Qn estimator, as used by the Q/Hampel method
+    Qn,
+}
+
+impl ScaleKind {
+    /// Multiplier f in u(x_pt) = f * s / sqrt(p) for a consensus value
+    /// 
+    /// The multiplier reflects the efficiency of the location estimator that
+    /// each scale is used with (all scales are consistent for σ at the normal):
+    /// - `SStar`: 1.25, ISO 13528:2022 7.7.3 for Algorithm A
+    /// - `Qn`: 1.25, the same ISO 13528 factor, which also covers the Q/Hampel
+    ///   method (Annex C.5)
+    /// - `NIQR`, `MADe`: sqrt(π/2) ≈ 1.2533, the standard error of the median
+    ///   that these scales accompany in the simple robust estimators (Annex C.2)
+    pub fn consensus_multiplier(&self) -> f64 {
+        match self {
+            ScaleKind::SStar | ScaleKind::Qn => UNCERTAINTY_FACTOR,
+            ScaleKind::NIQR | ScaleKind::MADe => MEDIAN_UNCERTAINTY_FACTOR,
+        }
+    }
+}
+
+impl FromStr for ScaleKind {
+    type Err = CalculationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "made" => Ok(ScaleKind::MADe),
+            "niqr" => Ok(ScaleKind::NIQR),
+            "s_star" => Ok(ScaleKind::SStar),
+            "qn" => Ok(ScaleKind::Qn),
+            _ => Err(CalculationError::InvalidInput {
+                message: format!("Unknown scale kind '{}': expected 'made', 'niqr', 's_star' or 'qn'", s),
+            }),
+        }
+    }
+}
+
+/// Finite-sample correction b_p of the MAD (Croux and Rousseeuw, 1992)
+fn mad_small_sample_correction(num_participants: usize) -> f64 {
+    const CORRECTIONS: [f64; 8] = [1.196, 1.495, 1.363, 1.206, 1.200, 1.140, 1.129, 1.107];
+    
+    match num_participants {
+        0 | 1 => 1.0,
+        2..=9 => CORRECTIONS[num_participants - 2],
+        _ => num_participants as f64 / (num_participants as f64 - 0.8),
+    }
+}
+
+/// Calculate uncertainty for a median assigned value
+/// 
+/// For normal data the standard error of the median is sqrt(π/2)·σ/sqrt(p)
+/// ≈ 1.2533·σ/sqrt(p), which replaces the 1.25 factor of the Algorithm A
+/// formula. σ is estimated by the supplied scale:
+/// 
+/// - `MADe`: u = 1.2533 · b_p · MADe / sqrt(p), where b_p corrects the MAD's
+///   small-sample bias (1.196, 1.495, 1.363, 1.206, 1.200, 1.140, 1.129, 1.107
+///   for p = 2 to 9, and p / (p - 0.8) above; 1 for p = 1)
+/// - `NIQR`: u = 1.2533 · nIQR / sqrt(p)
+/// - `SStar`: u = 1.2533 · s* / sqrt(p)
+/// - `Qn`: u = 1.2533 · Qn / sqrt(p)
+/// 
+/// # Arguments
+/// * `scale_estimate` - The robust standard deviation estimate
+/// * `num_participants` - Number of results the median was calculated from
+/// * `scale_kind` - Which estimator produced `scale_estimate`
+/// 
+/// # Returns
+/// * `Ok(f64)` - The calculated uncertainty u(x_pt)
+/// * `Err(CalculationError)` - If inputs are invalid
+pub fn calculate_uncertainty_median(
+    scale_estimate: f64,
+    num_participants: usize,
+    scale_kind: ScaleKind,
+) -> Result<f64, CalculationError> {
+    if !is_valid_float(scale_estimate) || scale_estimate < 0.0 {
+        return Err(CalculationError::InvalidInput {
+            message: format!("Invalid robust scale estimate: {}", scale_estimate),
+        });
+    }
+    
+    if num_participants == 0 {
+        return Err(CalculationError::InsufficientData {
+            required: 1,
+            actual: 0,
+        });
+    }
+    
+    let correction = match scale_kind {
+        ScaleKind::MADe => mad_small_sample_correction(num_participants),
+        ScaleKind::NIQR | ScaleKind::SStar | ScaleKind::Qn => 1.0,
+    };
+    
+    Ok(MEDIAN_UNCERTAINTY_FACTOR * correction * scale_estimate / (num_participants as f64).sqrt())
+}
+
+/// Coverage interval value ± k·u of an assigned value
+/// 
+/// Carries the standard uncertainty and the coverage factor together with the
+/// interval, so standard and expanded uncertainties cannot be mixed up.
+#[pyclass]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct CoverageInterval {
+    pub value: f64,
+    pub standard_uncertainty: f64,
+    /// Coverage factor k applied to the standard uncertainty
+    pub coverage_factor: f64,
+    pub lower: f64,
+    pub upper: f64,
+    /// Nominal coverage probability associated with k (e.g. 0.95 for k = 2)
+    pub confidence_level: f64,
+}
+
+impl CoverageInterval {
+    /// Create the interval value ± k·u
+    /// 
+    /// # Returns
+    /// * `Ok(CoverageInterval)` - The interval with lower and upper derived from k·u
+    /// * `Err(CalculationError)` - If the value is not finite, u < 0, k < 1 or the
+    ///   confidence level is not in (0, 1)
+    pub fn new(
+        value: f64,
+        standard_uncertainty: f64,
+        coverage_factor: f64,
+        confidence_level: f64,
+    ) -> Result<Self, CalculationError> {
+        if !is_valid_float(value) {
+            return Err(CalculationError::InvalidInput {
+                message: format!("Invalid value: {}", value),
+            });
+        }
+        
+        if !is_valid_float(standard_uncertainty) || standard_uncertainty < 0.0 {
+            return Err(CalculationError::InvalidInput {
+                message: format!("Invalid or negative standard uncertainty: {}", standard_uncertainty),
+            });
+        }
+        
+        if !is_valid_float(coverage_factor) || coverage_factor < 1.0 {
+            return Err(CalculationError::InvalidInput {
+                message: format!("Coverage factor must be at least 1, got {}", coverage_factor),
+            });
+        }
+        
+        if !(confidence_level > 0.0 && confidence_level < 1.0) {
+            return Err(CalculationError::InvalidInput {
+                message: format!("Confidence level must be in (0, 1), got {}", confidence_level),
+            });
+        }
+        
+        let expanded = coverage_factor * standard_uncertainty;
+        Ok(CoverageInterval {
+            value,
+            standard_uncertainty,
+            coverage_factor,
+            lower: value - expanded,
+            upper: value + expanded,
+            confidence_level,
+        })
+    }
+    
+    /// Expanded uncertainty U = k·u
+    pub fn expanded_uncertainty(&self) -> f64 {
+        self.coverage_factor * self.standard_uncertainty
+    }
+    
+    /// Whether `x` lies in the interval, boundaries included
+    pub fn contains(&self, x: f64) -> bool {
+        x >= self.lower && x <= self.upper
+    }
+}
+
+#[pymethods]
+impl CoverageInterval {
+    #[getter(value)]
+    fn py_value(&self) -> f64 {
+        self.value
+    }
+    
+    #[getter(standard_uncertainty)]
+    fn py_standard_uncertainty(&self) -> f64 {
+        self.standard_uncertainty
+    }
+    
+    #[getter(coverage_factor)]
+    fn py_coverage_factor(&self) -> f64 {
+        self.coverage_factor
+    }
+    
+    #[getter(lower)]
+    fn py_lower(&self) -> f64 {
+        self.lower
+    }
+    
+    #[getter(upper)]
+    fn py_upper(&self) -> f64 {
+        self.upper
+    }
+    
+    #[getter(confidence_level)]
+    fn py_confidence_level(&self) -> f64 {
+        self.confidence_level
+    }
+    
+    /// Whether `x` lies in the interval, boundaries included
+    #[pyo3(name = "contains")]
+    fn py_contains(&self, x: f64) -> bool {
+        self.contains(x)
+    }
+    
+    /// Export as a dict with keys value, standard_uncertainty, coverage_factor,
+    /// lower, upper and confidence_level
+    #[pyo3(name = "to_dict")]
+    fn py_to_dict<'py>(&self, py: Python<'py>) -> PyResult<&'py PyDict> {
+        let dict = PyDict::new(py);
+        dict.set_item("value", self.value)?;
+        dict.set_item("standard_uncertainty", self.standard_uncertainty)?;
+        dict.set_item("coverage_factor", self.coverage_factor)?;
+        dict.set_item("lower", self.lower)?;
+        dict.set_item("upper", self.upper)?;
+        dict.set_item("confidence_level", self.confidence_level)?;
+        Ok(dict)
+    }
+}
+
+/// Expand the assigned value uncertainty for reporting x_pt ± U
+/// 
+/// Returns U = k·u(x_pt) with the interval ends in one step. Unlike
+/// `CoverageInterval::new`, k is only required to be positive, so report
+/// templates can also quote e.g. k = 0.5.
+/// 
+/// # Arguments
+/// * `x_pt` - Assigned value
+/// * `u_x_pt` - Standard uncertainty of the assigned value
+/// * `k` - Coverage factor (typically 2)
+/// 
+/// # Returns
+/// * `Ok((f64, f64, f64, f64))` - Tuple of (x_pt, U, x_pt - U, x_pt + U)
+/// * `Err(CalculationError)` - If x_pt is not finite, u(x_pt) is negative or k is not positive
+pub fn assigned_value_expanded(
+    x_pt: f64,
+    u_x_pt: f64,
+    k: f64,
+) -> Result<(f64, f64, f64, f64), CalculationError> {
+    if !is_valid_float(x_pt) {
+        return Err(CalculationError::InvalidInput {
+            message: format!("Invalid assigned value x_pt: {}", x_pt),
+        });
+    }
+    
+    if !is_valid_float(u_x_pt) || u_x_pt < 0.0 {
+        return Err(CalculationError::InvalidInput {
+            message: format!("Invalid uncertainty of assigned value: {}", u_x_pt),
+        });
+    }
+    
+    if !is_valid_float(k) || k <= 0.0 {
+        return Err(CalculationError::InvalidInput {
+            message: format!("Coverage factor must be positive, got {}", k),
+        });
+    }
+    
+    let expanded = k * u_x_pt;
+    Ok((x_pt, expanded, x_pt - expanded, x_pt + expanded))
+}
+
+/// Calculate a coverage interval for a consensus value with few participants
+/// 
+/// Expands u(x_pt) = 1.25 * s* / sqrt(p) with the Student t quantile for
+/// p - 1 degrees of freedom instead of k = 2, which understates the interval
+/// for small p (k is 2.57 at p = 6 and 2.00 at p = 60 for 95 %).
+/// 
+/// # Arguments
+/// * `x_pt` - Consensus assigned value
+/// * `s_star` - Robust standard deviation from Algorithm A
+/// * `num_participants` - Number of participants p (at least 2)
+/// * `confidence` - Two-sided coverage probability, in (0, 1)
+/// 
+/// # Returns
+/// * `Ok(CoverageInterval)` - x_pt ± k * u(x_pt) with the t-based coverage factor k
+/// * `Err(CalculationError)` - If p < 2 or an input is invalid
+pub fn coverage_interval_consensus(
+    x_pt: f64,
+    s_star: f64,
+    num_participants: usize,
+    confidence: f64,
+) -> Result<CoverageInterval, CalculationError> {
+    if !is_valid_float(x_pt) {
+        return Err(CalculationError::InvalidInput {
+            message: format!("Invalid assigned value x_pt: {}", x_pt),
+        });
+    }
+    
+    if num_participants < 2 {
+        return Err(CalculationError::InsufficientData {
+            required: 2,
+            actual: num_participants,
+        });
+    }
+    
+    if !(confidence > 0.0 && confidence < 1.0) {
+        return Err(CalculationError::InvalidInput {
+            message: format!("Confidence level must be in (0, 1), got {}", confidence),
+        });
+    }
+    
+    let u_x_pt = calculate_uncertainty_consensus(s_star, num_participants, false, ScaleKind::SStar)?;
+    let k_effective = t_quantile(0.5 + confidence / 2.0, (num_participants - 1) as f64)?;
+    
+    CoverageInterval::new(x_pt, u_x_pt, k_effective, confidence)
+}
+
+/// Calculate the standard uncertainty of the robust standard deviation s*
+/// 
+/// Used to justify acceptance ranges when sigma_pt = s*. For normal data the
+/// Algorithm A scale estimate has u(s*) ≈ c · s* / sqrt(p) with
+/// c = `S_STAR_UNCERTAINTY_FACTOR` = 0.83, the asymptotic standard deviation of
+/// Huber's proposal 2 scale (c = 1.5). With `classical` the formula for a
+/// sample standard deviation, u(s) = s / sqrt(2(p - 1)), is used instead.
+/// 
+/// # Arguments
+/// * `s_star` - The robust standard deviation (s*)
+/// * `num_participants` - Number of participants s* was calculated from (p >= 2)
+/// * `classical` - Use the classical 1/sqrt(2(p - 1)) relationship
+/// 
+/// # Returns
+/// * `Ok(f64)` - The standard uncertainty u(s*)
+/// * `Err(CalculationError)` - If s* is negative or not finite, or p < 2
+pub fn uncertainty_of_s_star(
+    s_star: f64,
+    num_participants: usize,
+    classical: bool,
+) -> Result<f64, CalculationError> {
+    if !is_valid_float(s_star) || s_star < 0.0 {
+        return Err(CalculationError::InvalidInput {
+            message: format!("Invalid robust standard deviation: {}", s_star),
+        });
+    }
+    
+    if num_participants < 2 {
+        return Err(CalculationError::InsufficientData {
+            required: 2,
+            actual: num_participants,
+        });
+    }
+    
+    let p = num_participants as f64;
+    if classical {
+        Ok(s_star / (2.0 * (p - 1.0)).sqrt())
+    } else {
+        Ok(S_STAR_UNCERTAINTY_FACTOR * s_star / p.sqrt())
+    }
+}
+
+/// Homogeneity contribution to the uncertainty of the assigned value
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct HomogeneityUncertainty {
+    /// Between-sample standard deviation s_s, used as u_hom
+    pub u_hom: f64,
+    /// ANOVA estimate s_x̄² - s_w²/m of the between-sample variance, possibly negative
+    pub between_sample_variance: f64,
+    /// Whether the variance estimate was negative and u_hom was set to zero
+    pub negative_variance: bool,
+}
+
+/// Calculate the homogeneity uncertainty u_hom from homogeneity ANOVA output
+/// 
+/// Following ISO 13528 Annex B, the between-sample standard deviation is
+/// s_s = sqrt(s_x̄² - s_w²/m), where s_x̄ is the standard deviation of the
+/// sample averages, s_w the within-sample standard deviation and m the number
+/// of replicates per sample. The within-sample term removes the repeatability
+/// contribution from the scatter of the averages. When it exceeds s_x̄² the
+/// estimate is negative, i.e. no heterogeneity is detectable, and u_hom = 0
+/// with `negative_variance` set.
+/// 
+/// # Arguments
+/// * `between_sample_sd` - Standard deviation of the sample averages s_x̄
+/// * `within_sample_sd` - Within-sample (repeatability) standard deviation s_w
+/// * `replicates_per_sample` - Number of replicate measurements per sample (m >= 2)
+/// 
+/// # Returns
+/// * `Ok(HomogeneityUncertainty)` - u_hom, the raw variance estimate and the negative-variance flag
+/// * `Err(CalculationError)` - If a standard deviation is invalid or m < 2
+pub fn uncertainty_from_homogeneity(
+    between_sample_sd: f64,
+    within_sample_sd: f64,
+    replicates_per_sample: usize,
+) -> Result<HomogeneityUncertainty, CalculationError> {
+    for (name, sd) in [("between-sample", between_sample_sd), ("within-sample", within_sample_sd)] {
+        if !is_valid_float(sd) || sd < 0.0 {
+            return Err(CalculationError::InvalidInput {
+                message: format!("Invalid {} standard deviation: {}", name, sd),
+            });
+        }
+    }
+    
+    if replicates_per_sample < 2 {
+        return Err(CalculationError::InsufficientData {
+            required: 2,
+            actual: replicates_per_sample,
+        });
+    }
+    
+    let between_sample_variance = between_sample_sd.powi(2)
+        - within_sample_sd.powi(2) / replicates_per_sample as f64;
+    let negative_variance = between_sample_variance < 0.0;
+    
+    Ok(HomogeneityUncertainty {
+        u_hom: if negative_variance { 0.0 } else { between_sample_variance.sqrt() },
+        between_sample_variance,
+        negative_variance,
+    })
+}
+
+/// Express u(x_pt) as a percentage of the assigned value
+/// 
+/// Implements 100 * u(x_pt) / |x_pt|, so the result is non-negative also for
+/// negative assigned values (e.g. bias measurands).
+/// 
+/// # Arguments
+/// * `x_pt` - Assigned value (non-zero)
+/// * `u_x_pt` - Standard uncertainty of the assigned value
+/// 
+/// # Returns
+/// * `Ok(f64)` - The relative uncertainty in percent
+/// * `Err(CalculationError)` - `DivisionByZero` if x_pt is zero, or if an input is invalid
+pub fn relative_uncertainty(x_pt: f64, u_x_pt: f64) -> Result<f64, CalculationError> {
+    if !is_valid_float(x_pt) {
+        return Err(CalculationError::InvalidInput {
+            message: format!("Invalid assigned value x_pt: {}", x_pt),
+        });
+    }
+    
+    if !is_valid_float(u_x_pt) || u_x_pt < 0.0 {
+        return Err(CalculationError::InvalidInput {
+            message: format!("Invalid uncertainty of assigned value: {}", u_x_pt),
+        });
+    }
+    
+    if x_pt == 0.0 {
+        return Err(CalculationError::DivisionByZero);
+    }
+    
+    Ok(100.0 * u_x_pt / x_pt.abs())
+}
+
+/// A result corrected for recovery with its combined uncertainty
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct RecoveryCorrection {
+    /// Corrected value x / R
+    pub value: f64,
+    /// Combined standard uncertainty of the corrected value
+    pub standard_uncertainty: f64,
+    /// Combined relative standard uncertainty u(x / R) / |x / R| as a fraction
+    /// (NaN when the corrected value is zero)
+    pub relative_uncertainty: f64,
+}
+
+/// Correct a result for recovery and propagate the uncertainty of the recovery
+/// 
+/// For the quotient x_corr = x / R the relative uncertainties add in quadrature:
+/// u(x_corr) / |x_corr| = sqrt((u(x) / x)^2 + (u(R) / R)^2), assuming x and R
+/// are uncorrelated. A recovery near 1 with a small u(R) therefore mostly
+/// passes u(x) through, while a poorly known recovery dominates the budget.
+/// The absolute uncertainty is computed directly as
+/// sqrt(u(x)^2 + (x_corr·u(R))^2) / R, so a zero result is supported.
+/// 
+/// # Arguments
+/// * `value` - Uncorrected result x
+/// * `u_value` - Standard uncertainty of the result
+/// * `recovery` - Recovery R as a fraction (e.g. 0.92)
+/// * `u_recovery` - Standard uncertainty of the recovery
+/// 
+/// # Returns
+/// * `Ok(RecoveryCorrection)` - The corrected value with its absolute and relative uncertainty
+/// * `Err(CalculationError)` - If the value is not finite, the recovery is not positive
+///   and finite or an uncertainty is negative
+pub fn apply_recovery_correction(
+    value: f64,
+    u_value: f64,
+    recovery: f64,
+    u_recovery: f64,
+) -> Result<RecoveryCorrection, CalculationError> {
+    if !is_valid_float(value) {
+        return Err(CalculationError::InvalidInput {
+            message: format!("Invalid result: {}", value),
+        });
+    }
+    
+    if !is_valid_float(recovery) || recovery <= 0.0 {
+        return Err(CalculationError::InvalidInput {
+            message: format!("Recovery must be positive and finite, got {}", recovery),
+        });
+    }
+    
+    for (u, name) in [(u_value, "result"), (u_recovery, "recovery")] {
+        if !is_valid_float(u) || u < 0.0 {
+            return Err(CalculationError::InvalidInput {
+                message: format!("Invalid uncertainty of the {}: {}", name, u),
+            });
+        }
+    }
+    
+    let corrected = value / recovery;
+    let standard_uncertainty = u_value.hypot(corrected * u_recovery) / recovery;
+    let relative_uncertainty = if corrected == 0.0 {
+        f64::NAN
+    } else {
+        standard_uncertainty / corrected.abs()
+    };
+    
+    Ok(RecoveryCorrection {
+        value: corrected,
+        standard_uncertainty,
+        relative_uncertainty,
+    })
+}
+
 /// Calculate uncertainty for CRM values
 /// 
 /// For CRM-based assigned values, the uncertainty is taken directly from
@@ -139,15 +735,8 @@ pub fn calculate_uncertainty_expert_from_results(
         return Ok(0.0);
     }
     
-    // Calculate mean
-    let mean = expert_results.iter().sum::<f64>() / expert_results.len() as f64;
-    
-    // Calculate sample standard deviation
-    let variance = expert_results.iter()
-        .map(|&x| (x - mean).powi(2))
-        .sum::<f64>() / (expert_results.len() - 1) as f64;
-    
-    let std_dev = variance.sqrt();
+    // Sample standard deviation
+    let std_dev = running_stats(expert_results).std_dev().unwrap();
     
     // Standard error of the mean
     let uncertainty = std_dev / (expert_results.len() as f64).sqrt();
@@ -155,38 +744,1340 @@ pub fn calculate_uncertainty_expert_from_results(
     Ok(uncertainty)
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use approx::assert_abs_diff_eq;
-
-    #[test]
-    fn test_uncertainty_consensus() {
-        let robust_std = 1.0;
-        let participants = 25;
-        let result = calculate_uncertainty_consensus(robust_std, participants).unwrap();
-        
-        // u(x_pt) = 1.25 * 1.0 / sqrt(25) = 1.25 / 5 = 0.25
-        assert_abs_diff_eq!(result, 0.25, epsilon = 1e-10);
-    }
+/// Classical and robust uncertainties of an expert consensus value
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct ExpertUncertainty {
+    /// 1.25 * MADe / sqrt(p), insensitive to a single wild expert result
+    pub robust: f64,
+    /// Standard error of the mean, s / sqrt(p)
+    pub classical: f64,
+    /// False for a single expert result, for which no uncertainty can be
+    /// estimated and both values are reported as 0
+    pub sufficient: bool,
+}
 
-    #[test]
-    fn test_uncertainty_consensus_invalid_inputs() {
-        // Invalid standard deviation
-        assert!(calculate_uncertainty_consensus(f64::NAN, 10).is_err());
-        assert!(calculate_uncertainty_consensus(-1.0, 10).is_err());
-        
-        // Zero participants
-        assert!(calculate_uncertainty_consensus(1.0, 0).is_err());
+/// Calculate classical and robust uncertainties from multiple expert results
+/// 
+/// The classical value is `calculate_uncertainty_expert_from_results`. The
+/// robust value applies the consensus formula with the MADe as robust
+/// standard deviation, u = 1.25 * MADe / sqrt(p), where MADe = 1.4826 * MAD,
+/// so both can be reported side by side.
+/// 
+/// # Arguments
+/// * `expert_results` - Array of results from expert laboratories
+/// * `mad_consistency` - Factor converting the MAD to the MADe (MAD_TO_SIGMA for normal data)
+/// 
+/// # Returns
+/// * `Ok(ExpertUncertainty)` - Robust and classical uncertainties and whether they could be estimated
+/// * `Err(CalculationError)` - If there are no results, a result is invalid or the constant is not positive
+pub fn calculate_uncertainty_expert_robust(
+    expert_results: &[f64],
+    mad_consistency: f64,
+) -> Result<ExpertUncertainty, CalculationError> {
+    validate_mad_consistency(mad_consistency)?;
+    let classical = calculate_uncertainty_expert_from_results(expert_results)?;
+    let p = expert_results.len();
+    
+    if p == 1 {
+        return Ok(ExpertUncertainty {
+            robust: 0.0,
+            classical,
+            sufficient: false,
+        });
     }
+    
+    let made = mad_of(expert_results)? * mad_consistency;
+    
+    Ok(ExpertUncertainty {
+        robust: UNCERTAINTY_FACTOR * made / (p as f64).sqrt(),
+        classical,
+        sufficient: true,
+    })
+}
 
-    #[test]
-    fn test_uncertainty_crm() {
-        let crm_unc = 0.15;
-        let result = calculate_uncertainty_crm(crm_unc).unwrap();
-        assert_eq!(result, 0.15);
-        
-        // Invalid uncertainty
+/// Calculate an expert consensus value weighted by the experts' own uncertainties
+/// 
+/// Implements the inverse-variance weighted mean x = Σ(x_i / u_i²) / Σ(1 / u_i²)
+/// with u(x) = 1 / sqrt(Σ 1 / u_i²). Unlike the standard error of
+/// `calculate_uncertainty_expert_from_results`, this uses the stated
+/// uncertainties rather than the scatter of the expert results.
+/// 
+/// # Arguments
+/// * `expert_results` - Array of results from expert laboratories
+/// * `expert_uncertainties` - Standard uncertainty stated with each result (> 0)
+/// 
+/// # Returns
+/// * `Ok((f64, f64))` - The weighted consensus value and its standard uncertainty
+/// * `Err(CalculationError)` - If the inputs are empty, differ in length or are invalid
+pub fn calculate_expert_consensus_weighted(
+    expert_results: &[f64],
+    expert_uncertainties: &[f64],
+) -> Result<(f64, f64), CalculationError> {
+    validate_array_dimensions(expert_results.len(), expert_uncertainties.len(), "expert_results", "expert_uncertainties")?;
+    
+    if expert_results.is_empty() {
+        return Err(CalculationError::InsufficientData {
+            required: 1,
+            actual: 0,
+        });
+    }
+    
+    validate_floats(expert_results, "expert results")?;
+    validate_floats(expert_uncertainties, "expert uncertainties")?;
+    
+    if let Some(i) = expert_uncertainties.iter().position(|&u| u <= 0.0) {
+        return Err(CalculationError::InvalidInput {
+            message: format!("Expert uncertainty at index {} must be positive: {}", i, expert_uncertainties[i]),
+        });
+    }
+    
+    let (sum_weights, sum_weighted_results) = expert_results.iter()
+        .zip(expert_uncertainties.iter())
+        .fold((0.0, 0.0), |(sum_w, sum_wx), (&x_i, &u_i)| {
+            let weight = 1.0 / (u_i * u_i);
+            (sum_w + weight, sum_wx + weight * x_i)
+        });
+    
+    Ok((sum_weighted_results / sum_weights, sum_weights.sqrt().recip()))
+}
+
+/// Combine independent standard uncertainty components
+/// 
+/// Implements the root-sum-of-squares used for the full uncertainty of the
+/// assigned value, e.g. u(x_pt) = sqrt(u_char^2 + u_hom^2 + u_stab^2 [+ u_trans^2]).
+/// 
+/// # Arguments
+/// * `components` - Standard uncertainty components
+/// 
+/// # Returns
+/// * `Ok(f64)` - The combined standard uncertainty
+/// * `Err(CalculationError)` - If there are no components or any is negative or not finite
+pub fn combine_uncertainty_components(components: &[f64]) -> Result<f64, CalculationError> {
+    if components.is_empty() {
+        return Err(CalculationError::InsufficientData {
+            required: 1,
+            actual: 0,
+        });
+    }
+    
+    validate_floats(components, "uncertainty components")?;
+    
+    if let Some((i, &u)) = components.iter().enumerate().find(|(_, &u)| u < 0.0) {
+        return Err(CalculationError::InvalidInput {
+            message: format!("Negative uncertainty component at index {}: {}", i, u),
+        });
+    }
+    
+    Ok(components.iter().map(|&u| u * u).sum::<f64>().sqrt())
+}
+
+/// Combine named uncertainty components and report each one's contribution
+/// 
+/// Same as `combine_uncertainty_components`, additionally returning the
+/// percentage of the combined variance contributed by each component,
+/// 100 * u_i^2 / u_c^2, in input order. If every component is zero all
+/// contributions are reported as 0.
+/// 
+/// # Arguments
+/// * `components` - (name, standard uncertainty) pairs with unique names
+/// 
+/// # Returns
+/// * `Ok((f64, Vec<(String, f64)>))` - Combined uncertainty and percentage contributions
+/// * `Err(CalculationError)` - If the components are invalid or a name is repeated
+pub fn combine_named_uncertainty_components(
+    components: &[(String, f64)],
+) -> Result<(f64, Vec<(String, f64)>), CalculationError> {
+    for (i, (name, _)) in components.iter().enumerate() {
+        if components[..i].iter().any(|(other, _)| other == name) {
+            return Err(CalculationError::InvalidInput {
+                message: format!("Duplicate uncertainty component name: {}", name),
+            });
+        }
+    }
+    
+    let values: Vec<f64> = components.iter().map(|(_, u)| *u).collect();
+    let combined = combine_uncertainty_components(&values)?;
+    let variance = combined * combined;
+    
+    let contributions = components.iter()
+        .map(|(name, u)| {
+            let percent = if variance > 0.0 { 100.0 * u * u / variance } else { 0.0 };
+            (name.clone(), percent)
+        })
+        .collect();
+    
+    Ok((combined, contributions))
+}
+
+/// Welch-Satterthwaite effective degrees of freedom and the matching coverage factor
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct EffectiveDegreesOfFreedom {
+    /// Effective degrees of freedom, infinite if every component has infinite dof
+    pub dof: f64,
+    /// Coverage factor for 95 % coverage from the t-distribution
+    pub coverage_factor: f64,
+}
+
+/// Calculate the Welch-Satterthwaite effective degrees of freedom of a combined uncertainty
+/// 
+/// Implements ν_eff = u_c⁴ / Σ(u_i⁴ / ν_i) with u_c² = Σ u_i² (GUM G.4.1).
+/// Components with infinite degrees of freedom (e.g. Type B evaluations) do
+/// not contribute to the denominator. The coverage factor is the 97.5 %
+/// t-quantile for ν_eff truncated to the next lower integer (at least 1), as
+/// GUM G.4.1 recommends, or the normal quantile when ν_eff is infinite.
+/// 
+/// # Arguments
+/// * `components` - Standard uncertainty contributions c_i·u(x_i) (>= 0)
+/// * `dofs` - Degrees of freedom of each component (> 0, may be infinite)
+/// 
+/// # Returns
+/// * `Ok(EffectiveDegreesOfFreedom)` - ν_eff and the 95 % coverage factor
+/// * `Err(CalculationError)` - If the lengths differ, an input is invalid or all components are zero
+pub fn effective_degrees_of_freedom(
+    components: &[f64],
+    dofs: &[f64],
+) -> Result<EffectiveDegreesOfFreedom, CalculationError> {
+    validate_array_dimensions(components.len(), dofs.len(), "components", "dofs")?;
+    
+    if components.is_empty() {
+        return Err(CalculationError::InsufficientData {
+            required: 1,
+            actual: 0,
+        });
+    }
+    
+    if let Some(&u) = components.iter().find(|&&u| !is_valid_float(u) || u < 0.0) {
+        return Err(CalculationError::InvalidInput {
+            message: format!("Invalid uncertainty component: {}", u),
+        });
+    }
+    
+    if let Some(&dof) = dofs.iter().find(|&&dof| dof.is_nan() || dof <= 0.0) {
+        return Err(CalculationError::InvalidInput {
+            message: format!("Degrees of freedom must be positive, got {}", dof),
+        });
+    }
+    
+    let combined_variance: f64 = components.iter().map(|&u| u * u).sum();
+    if combined_variance == 0.0 {
+        return Err(CalculationError::InvalidInput {
+            message: "Effective degrees of freedom are undefined when all components are zero".to_string(),
+        });
+    }
+    
+    let denominator: f64 = components.iter()
+        .zip(dofs.iter())
+        .filter(|(_, dof)| dof.is_finite())
+        .map(|(&u, &dof)| u.powi(4) / dof)
+        .sum();
+    
+    if denominator == 0.0 {
+        return Ok(EffectiveDegreesOfFreedom {
+            dof: f64::INFINITY,
+            coverage_factor: normal_quantile(0.975)?,
+        });
+    }
+    
+    let dof = combined_variance * combined_variance / denominator;
+    Ok(EffectiveDegreesOfFreedom {
+        dof,
+        coverage_factor: t_quantile(0.975, dof.floor().max(1.0))?,
+    })
+}
+
+/// Agreement between two independently assigned values
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct AssignedValueAgreement {
+    /// |x1 - x2| / sqrt(u1^2 + u2^2)
+    pub ratio: f64,
+    /// Whether the ratio is within the limit
+    pub agree: bool,
+}
+
+/// Check that two assigned values agree within their combined uncertainty
+/// 
+/// Compares e.g. a consensus value with a CRM value using the En-like ratio
+/// |x1 - x2| / sqrt(u1^2 + u2^2). With expanded uncertainties (k = 2) the
+/// usual limit is 1, as for En numbers; with standard uncertainties use 2.
+/// 
+/// # Arguments
+/// * `x1`, `u1` - First assigned value and its uncertainty
+/// * `x2`, `u2` - Second assigned value and its uncertainty
+/// * `limit` - Largest ratio accepted as agreement (boundary included)
+/// 
+/// # Returns
+/// * `Ok(AssignedValueAgreement)` - Ratio and agreement flag
+/// * `Err(CalculationError)` - If an input is invalid or both uncertainties are zero
+pub fn check_assigned_value_agreement(
+    x1: f64,
+    u1: f64,
+    x2: f64,
+    u2: f64,
+    limit: f64,
+) -> Result<AssignedValueAgreement, CalculationError> {
+    if !is_valid_float(x1) || !is_valid_float(x2) {
+        return Err(CalculationError::InvalidInput {
+            message: format!("Invalid assigned values: {}, {}", x1, x2),
+        });
+    }
+    
+    for u in [u1, u2] {
+        if !is_valid_float(u) || u < 0.0 {
+            return Err(CalculationError::InvalidInput {
+                message: format!("Invalid assigned value uncertainty: {}", u),
+            });
+        }
+    }
+    
+    if !is_valid_float(limit) || limit <= 0.0 {
+        return Err(CalculationError::InvalidInput {
+            message: format!("Invalid or non-positive agreement limit: {}", limit),
+        });
+    }
+    
+    let combined = u1.hypot(u2);
+    if combined == 0.0 {
+        return Err(CalculationError::DivisionByZero);
+    }
+    
+    let ratio = (x1 - x2).abs() / combined;
+    Ok(AssignedValueAgreement { ratio, agree: ratio <= limit })
+}
+
+/// Assigned value combined from a reference value and the participant consensus
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct CombinedAssignedValue {
+    pub x_pt: f64,
+    /// Combined standard uncertainty, inflated by the Birge ratio if the sources disagree
+    pub u_x_pt: f64,
+    /// |x_ref - x_cons| / sqrt(u_ref^2 + u_cons^2)
+    pub birge_ratio: f64,
+    /// Whether the two values agree within their standard uncertainties (ratio <= 1)
+    pub consistent: bool,
+}
+
+impl CombinedAssignedValue {
+    /// Coverage interval x_pt ± k·u(x_pt) at the given nominal confidence level
+    pub fn coverage_interval(
+        &self,
+        coverage_factor: f64,
+        confidence_level: f64,
+    ) -> Result<CoverageInterval, CalculationError> {
+        CoverageInterval::new(self.x_pt, self.u_x_pt, coverage_factor, confidence_level)
+    }
+}
+
+/// Combine a reference (e.g. CRM) value and the consensus value into one assigned value
+/// 
+/// x_pt is the inverse-variance weighted mean with u(x_pt) = 1 / sqrt(1/u_ref² +
+/// 1/u_cons²). For two values the Birge ratio R_B = sqrt(χ² / (n - 1)) reduces
+/// to |x_ref - x_cons| / sqrt(u_ref² + u_cons²). When R_B > 1 the scatter is
+/// larger than the stated uncertainties explain, so u(x_pt) is multiplied by
+/// R_B and the pair is flagged as inconsistent.
+/// 
+/// # Arguments
+/// * `x_ref`, `u_ref` - Reference value and its standard uncertainty (> 0)
+/// * `x_cons`, `u_cons` - Consensus value and its standard uncertainty (> 0)
+/// 
+/// # Returns
+/// * `Ok(CombinedAssignedValue)` - Combined value, uncertainty, Birge ratio and consistency flag
+/// * `Err(CalculationError)` - If a value is not finite or an uncertainty is not positive
+pub fn combine_reference_and_consensus(
+    x_ref: f64,
+    u_ref: f64,
+    x_cons: f64,
+    u_cons: f64,
+) -> Result<CombinedAssignedValue, CalculationError> {
+    if !is_valid_float(x_ref) || !is_valid_float(x_cons) {
+        return Err(CalculationError::InvalidInput {
+            message: format!("Invalid reference or consensus value: {}, {}", x_ref, x_cons),
+        });
+    }
+    
+    for u in [u_ref, u_cons] {
+        if !is_valid_float(u) || u <= 0.0 {
+            return Err(CalculationError::InvalidInput {
+                message: format!("Invalid or non-positive uncertainty: {}", u),
+            });
+        }
+    }
+    
+    let w_ref = 1.0 / (u_ref * u_ref);
+    let w_cons = 1.0 / (u_cons * u_cons);
+    let x_pt = (w_ref * x_ref + w_cons * x_cons) / (w_ref + w_cons);
+    let u_internal = (w_ref + w_cons).sqrt().recip();
+    
+    let birge_ratio = (x_ref - x_cons).abs() / u_ref.hypot(u_cons);
+    let consistent = birge_ratio <= 1.0;
+    
+    Ok(CombinedAssignedValue {
+        x_pt,
+        u_x_pt: if consistent { u_internal } else { u_internal * birge_ratio },
+        birge_ratio,
+        consistent,
+    })
+}
+
+/// Excess ("dark") between-laboratory uncertainty
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct DarkUncertainty {
+    /// Standard deviation of the excess variance term, zero for consistent data
+    pub dark_uncertainty: f64,
+    /// Reduced chi-square with the stated uncertainties only
+    pub reduced_chi_square_before: f64,
+    /// Reduced chi-square after adding the excess variance to every result
+    pub reduced_chi_square_after: f64,
+    /// Excess variance as a fraction of the total (mean stated variance plus excess)
+    pub fraction_of_variance: f64,
+    /// Whether the stated uncertainties already explain the scatter (chi-square <= dof)
+    pub consistent: bool,
+}
+
+/// Estimate the excess variance needed to explain the scatter of results
+/// 
+/// When zeta-scores are systematically too large, the stated uncertainties
+/// underestimate the real dispersion. This finds the variance τ² such that
+/// 
+///   Σ (x_i - x_pt)² / (u_i² + τ²) = p - 1
+/// 
+/// i.e. the reduced chi-square equals 1, with one degree of freedom taken by
+/// the assigned value. The left-hand side decreases monotonically in τ², so
+/// the root is found by bisection on [0, Σ (x_i - x_pt)² / (p - 1)].
+/// 
+/// # Arguments
+/// * `results` - Array view of participant results (x_i)
+/// * `uncertainties` - Standard uncertainties stated by the participants (u_i > 0)
+/// * `x_pt` - Assigned value
+/// 
+/// # Returns
+/// * `Ok(DarkUncertainty)` - τ, reduced chi-square before and after, and the variance fraction
+/// * `Err(CalculationError)` - If fewer than 2 results are given or an input is invalid
+pub fn dark_uncertainty(
+    results: ArrayView1<f64>,
+    uncertainties: ArrayView1<f64>,
+    x_pt: f64,
+) -> Result<DarkUncertainty, CalculationError> {
+    validate_array_dimensions(results.len(), uncertainties.len(), "results", "uncertainties")?;
+    validate_floats(results, "participant results")?;
+    validate_floats(uncertainties, "participant uncertainties")?;
+    
+    if results.len() < 2 {
+        return Err(CalculationError::InsufficientData {
+            required: 2,
+            actual: results.len(),
+        });
+    }
+    
+    if !is_valid_float(x_pt) {
+        return Err(CalculationError::InvalidInput {
+            message: format!("Invalid assigned value x_pt: {}", x_pt),
+        });
+    }
+    
+    if let Some(&u) = uncertainties.iter().find(|&&u| u <= 0.0) {
+        return Err(CalculationError::InvalidInput {
+            message: format!("Participant uncertainties must be positive, got {}", u),
+        });
+    }
+    
+    let dof = (results.len() - 1) as f64;
+    let squared_residuals: Vec<f64> = results.iter().map(|&x_i| (x_i - x_pt).powi(2)).collect();
+    let chi_square = |tau_squared: f64| -> f64 {
+        squared_residuals.iter()
+            .zip(uncertainties.iter())
+            .map(|(&r2, &u)| r2 / (u * u + tau_squared))
+            .sum()
+    };
+    
+    let reduced_chi_square_before = chi_square(0.0) / dof;
+    if reduced_chi_square_before <= 1.0 {
+        return Ok(DarkUncertainty {
+            dark_uncertainty: 0.0,
+            reduced_chi_square_before,
+            reduced_chi_square_after: reduced_chi_square_before,
+            fraction_of_variance: 0.0,
+            consistent: true,
+        });
+    }
+    
+    // chi_square(hi) <= Σ r² / hi = dof, so the root lies in [0, hi]
+    let mut lo = 0.0;
+    let mut hi = squared_residuals.iter().sum::<f64>() / dof;
+    for _ in 0..MAX_BISECTION_STEPS {
+        let mid = 0.5 * (lo + hi);
+        if mid <= lo || mid >= hi {
+            break;
+        }
+        if chi_square(mid) > dof {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+    let tau_squared = 0.5 * (lo + hi);
+    
+    let mean_stated_variance = uncertainties.iter().map(|&u| u * u).sum::<f64>() / results.len() as f64;
+    
+    Ok(DarkUncertainty {
+        dark_uncertainty: tau_squared.sqrt(),
+        reduced_chi_square_before,
+        reduced_chi_square_after: chi_square(tau_squared) / dof,
+        fraction_of_variance: tau_squared / (tau_squared + mean_stated_variance),
+        consistent: false,
+    })
+}
+
+/// Recommended action after checking u(x_pt) against sigma_pt
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum UncertaintyRecommendation {
+    /// u(x_pt) is negligible; plain z-scores can be used
+    UseZ,
+    /// u(x_pt) is not negligible; use z'-scores to include it
+    UseZPrime,
+    /// u(x_pt) is too large for z' to be meaningful; review sigma_pt or the assigned value
+    ReviewSigmaPt,
+}
+
+impl UncertaintyRecommendation {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            UncertaintyRecommendation::UseZ => "use_z",
+            UncertaintyRecommendation::UseZPrime => "use_z_prime",
+            UncertaintyRecommendation::ReviewSigmaPt => "review_sigma_pt",
+        }
+    }
+}
+
+/// Outcome of the u(x_pt) <= 0.3·sigma_pt criterion
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct UncertaintyCriterion {
+    /// u(x_pt) / sigma_pt
+    pub ratio: f64,
+    /// Whether u(x_pt) is negligible relative to sigma_pt
+    pub passed: bool,
+    pub recommendation: UncertaintyRecommendation,
+}
+
+/// Check whether u(x_pt) is negligible relative to sigma_pt (ISO 13528 §9.2.1)
+/// 
+/// The criterion passes when u(x_pt) <= 0.3·sigma_pt, boundary included; a
+/// relative slack of 1e-12 keeps ratios that are 0.3 in decimal from failing
+/// through binary rounding. Failing ratios up to 0.7 recommend z'-scores,
+/// larger ones a review of sigma_pt or the assigned value.
+/// 
+/// # Arguments
+/// * `u_x_pt` - Standard uncertainty of the assigned value
+/// * `sigma_pt` - Standard deviation for proficiency assessment
+/// 
+/// # Returns
+/// * `Ok(UncertaintyCriterion)` - Ratio, pass flag and recommendation
+/// * `Err(CalculationError)` - If u(x_pt) is negative or sigma_pt is not positive
+pub fn check_uncertainty_criterion(
+    u_x_pt: f64,
+    sigma_pt: f64,
+) -> Result<UncertaintyCriterion, CalculationError> {
+    if !is_valid_float(u_x_pt) || u_x_pt < 0.0 {
+        return Err(CalculationError::InvalidInput {
+            message: format!("Invalid assigned value uncertainty: {}", u_x_pt),
+        });
+    }
+    
+    if !is_valid_float(sigma_pt) || sigma_pt <= 0.0 {
+        return Err(CalculationError::InvalidInput {
+            message: format!("Invalid or non-positive sigma_pt: {}", sigma_pt),
+        });
+    }
+    
+    let ratio = u_x_pt / sigma_pt;
+    let within = |limit: f64| ratio <= limit * (1.0 + 1e-12);
+    
+    let passed = within(NEGLIGIBLE_UNCERTAINTY_RATIO);
+    let recommendation = if passed {
+        UncertaintyRecommendation::UseZ
+    } else if within(Z_PRIME_MAX_UNCERTAINTY_RATIO) {
+        UncertaintyRecommendation::UseZPrime
+    } else {
+        UncertaintyRecommendation::ReviewSigmaPt
+    };
+    
+    Ok(UncertaintyCriterion { ratio, passed, recommendation })
+}
+
+/// Plausibility of a participant's stated uncertainty relative to the cohort dispersion
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum UncertaintyPlausibility {
+    Ok,
+    /// u(x_i) below low_factor·s*, which inflates |zeta|
+    TooSmall,
+    /// u(x_i) above high_factor·s*, which hides poor performance behind small |zeta|
+    TooLarge,
+    /// No uncertainty reported (NaN)
+    Missing,
+}
+
+impl UncertaintyPlausibility {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            UncertaintyPlausibility::Ok => "ok",
+            UncertaintyPlausibility::TooSmall => "too_small",
+            UncertaintyPlausibility::TooLarge => "too_large",
+            UncertaintyPlausibility::Missing => "missing",
+        }
+    }
+}
+
+/// Per-participant plausibility flags of stated uncertainties with summary counts
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct UncertaintyScreening {
+    pub flags: Vec<UncertaintyPlausibility>,
+    pub n_ok: usize,
+    pub n_too_small: usize,
+    pub n_too_large: usize,
+    pub n_missing: usize,
+}
+
+/// Screen participant-stated uncertainties against the cohort dispersion
+/// 
+/// Flags u(x_i) < low_factor·s* as too small and u(x_i) > high_factor·s* as too
+/// large (the defaults 0.1 and 5 are `DEFAULT_UNCERTAINTY_LOW_FACTOR` and
+/// `DEFAULT_UNCERTAINTY_HIGH_FACTOR`); values on a limit are plausible. NaN
+/// marks a participant that reported no uncertainty. The flags are for review
+/// before zeta scoring and do not change any score.
+/// 
+/// # Arguments
+/// * `u_results` - Stated standard uncertainties u(x_i), NaN where missing
+/// * `s_star` - Robust standard deviation of the participant results
+/// * `low_factor` - Multiple of s* below which u(x_i) is too small
+/// * `high_factor` - Multiple of s* above which u(x_i) is too large
+/// 
+/// # Returns
+/// * `Ok(UncertaintyScreening)` - Flags aligned with the input and their counts
+/// * `Err(CalculationError)` - If an uncertainty is negative, s* is not positive or
+///   the factors are not 0 < low_factor < high_factor
+pub fn screen_participant_uncertainties(
+    u_results: ArrayView1<f64>,
+    s_star: f64,
+    low_factor: f64,
+    high_factor: f64,
+) -> Result<UncertaintyScreening, CalculationError> {
+    if !is_valid_float(s_star) || s_star <= 0.0 {
+        return Err(CalculationError::InvalidInput {
+            message: format!("Invalid or non-positive robust standard deviation: {}", s_star),
+        });
+    }
+    
+    if !is_valid_float(low_factor) || !is_valid_float(high_factor)
+        || low_factor <= 0.0 || high_factor <= low_factor
+    {
+        return Err(CalculationError::InvalidInput {
+            message: format!(
+                "Screening factors must satisfy 0 < low_factor < high_factor, got {} and {}",
+                low_factor, high_factor
+            ),
+        });
+    }
+    
+    if let Some((i, &u_i)) = u_results.iter().enumerate().find(|(_, &u_i)| u_i < 0.0) {
+        return Err(CalculationError::InvalidInput {
+            message: format!("Negative participant uncertainty at index {}: {}", i, u_i),
+        });
+    }
+    
+    let flags: Vec<UncertaintyPlausibility> = u_results.iter()
+        .map(|&u_i| {
+            if u_i.is_nan() {
+                UncertaintyPlausibility::Missing
+            } else if u_i < low_factor * s_star {
+                UncertaintyPlausibility::TooSmall
+            } else if u_i > high_factor * s_star {
+                UncertaintyPlausibility::TooLarge
+            } else {
+                UncertaintyPlausibility::Ok
+            }
+        })
+        .collect();
+    
+    let count = |flag: UncertaintyPlausibility| flags.iter().filter(|&&f| f == flag).count();
+    
+    Ok(UncertaintyScreening {
+        n_ok: count(UncertaintyPlausibility::Ok),
+        n_too_small: count(UncertaintyPlausibility::TooSmall),
+        n_too_large: count(UncertaintyPlausibility::TooLarge),
+        n_missing: count(UncertaintyPlausibility::Missing),
+        flags,
+    })
+}
+
+/// Combined uncertainty from the GUM law of propagation
+#[derive(Debug, Clone, PartialEq)]
+pub struct PropagatedUncertainty {
+    /// Combined standard uncertainty u_c(y)
+    pub combined: f64,
+    /// Contribution of each input quantity to u_c(y)^2, summing to u_c(y)^2
+    pub contributions: Vec<f64>,
+}
+
+/// Propagate input uncertainties with the GUM law of propagation of uncertainty
+/// 
+/// Implements u_c(y)^2 = Σ_i Σ_j c_i c_j u(x_i) u(x_j) r(x_i, x_j), which
+/// without correlations reduces to Σ (c_i u(x_i))^2. The contribution of input
+/// i is c_i u(x_i) Σ_j r(x_i, x_j) c_j u(x_j), i.e. its own term plus half of
+/// each covariance term it takes part in; it is negative when negative
+/// correlations reduce the combined uncertainty.
+/// 
+/// # Arguments
+/// * `values` - Estimates of the input quantities (validated only; the sensitivities carry the model)
+/// * `uncertainties` - Standard uncertainties u(x_i)
+/// * `sensitivities` - Sensitivity coefficients c_i = ∂f/∂x_i
+/// * `correlations` - Optional correlation matrix r(x_i, x_j); None means uncorrelated
+/// 
+/// # Returns
+/// * `Ok(PropagatedUncertainty)` - Combined standard uncertainty and per-input contributions
+/// * `Err(CalculationError)` - If dimensions differ, an input is invalid or the correlation
+///   matrix is not symmetric with unit diagonal and entries in [-1, 1]
+pub fn propagate_uncertainty(
+    values: &[f64],
+    uncertainties: &[f64],
+    sensitivities: &[f64],
+    correlations: Option<ArrayView2<f64>>,
+) -> Result<PropagatedUncertainty, CalculationError> {
+    let n = values.len();
+    if n == 0 {
+        return Err(CalculationError::InsufficientData {
+            required: 1,
+            actual: 0,
+        });
+    }
+    
+    validate_array_dimensions(n, uncertainties.len(), "values", "uncertainties")?;
+    validate_array_dimensions(n, sensitivities.len(), "values", "sensitivities")?;
+    validate_floats(values, "input values")?;
+    validate_floats(uncertainties, "input uncertainties")?;
+    validate_floats(sensitivities, "sensitivity coefficients")?;
+    
+    if let Some((i, &u)) = uncertainties.iter().enumerate().find(|(_, &u)| u < 0.0) {
+        return Err(CalculationError::InvalidInput {
+            message: format!("Negative input uncertainty at index {}: {}", i, u),
+        });
+    }
+    
+    if let Some(r) = correlations {
+        validate_array_dimensions(n, r.nrows(), "values", "correlation rows")?;
+        validate_array_dimensions(n, r.ncols(), "values", "correlation columns")?;
+        validate_floats(r.iter(), "correlation coefficients")?;
+        
+        for i in 0..n {
+            if (r[[i, i]] - 1.0).abs() > 1e-12 {
+                return Err(CalculationError::InvalidInput {
+                    message: format!("Correlation matrix diagonal must be 1, got {} at ({}, {})", r[[i, i]], i, i),
+                });
+            }
+            
+            for j in 0..i {
+                if (r[[i, j]] - r[[j, i]]).abs() > 1e-12 {
+                    return Err(CalculationError::InvalidInput {
+                        message: format!("Correlation matrix is not symmetric at ({}, {})", i, j),
+                    });
+                }
+                
+                if r[[i, j]].abs() > 1.0 {
+                    return Err(CalculationError::InvalidInput {
+                        message: format!("Correlation coefficient out of [-1, 1] at ({}, {}): {}", i, j, r[[i, j]]),
+                    });
+                }
+            }
+        }
+    }
+    
+    // Uncertainty components c_i u(x_i)
+    let terms: Vec<f64> = sensitivities.iter()
+        .zip(uncertainties.iter())
+        .map(|(&c, &u)| c * u)
+        .collect();
+    
+    let contributions: Vec<f64> = match correlations {
+        Some(r) => (0..n)
+            .map(|i| terms[i] * (0..n).map(|j| r[[i, j]] * terms[j]).sum::<f64>())
+            .collect(),
+        None => terms.iter().map(|&t| t * t).collect(),
+    };
+    
+    let variance = contributions.iter().sum::<f64>();
+    
+    // A valid correlation matrix is positive semi-definite, so this only fails for inconsistent r
+    if variance < -1e-12 * terms.iter().map(|t| t * t).sum::<f64>() {
+        return Err(CalculationError::MathematicalError {
+            message: format!("Negative combined variance {}: correlation matrix is not positive semi-definite", variance),
+        });
+    }
+    
+    Ok(PropagatedUncertainty {
+        combined: variance.max(0.0).sqrt(),
+        contributions,
+    })
+}
+
+/// How an uncertainty component was evaluated (GUM Type A or Type B)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum EvaluationType {
+    /// Statistical analysis of a series of observations
+    A,
+    /// Any other means, e.g. certificates or prior knowledge
+    B,
+}
+
+impl FromStr for EvaluationType {
+    type Err = CalculationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "A" | "a" => Ok(EvaluationType::A),
+            "B" | "b" => Ok(EvaluationType::B),
+            _ => Err(CalculationError::InvalidInput {
+                message: format!("Unknown evaluation type '{}': expected 'A' or 'B'", s),
+            }),
+        }
+    }
+}
+
+/// One named standard uncertainty component of a budget
+#[derive(Debug, Clone, PartialEq)]
+pub struct UncertaintyComponent {
+    pub name: String,
+    pub value: f64,
+    pub evaluation_type: EvaluationType,
+}
+
+/// Uncertainty budget of the assigned value
+/// 
+/// Keeps every standard uncertainty component with its evaluation type so the
+/// combined u(x_pt) can be traced back to its sources. Components are
+/// independent and combined by root-sum-of-squares.
+#[pyclass]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct UncertaintyBudget {
+    components: Vec<UncertaintyComponent>,
+}
+
+#[derive(Serialize)]
+struct BudgetComponentRecord<'a> {
+    name: &'a str,
+    value: f64,
+    #[serde(rename = "type")]
+    evaluation_type: EvaluationType,
+    contribution_percent: f64,
+}
+
+#[derive(Serialize)]
+struct BudgetRecord<'a> {
+    components: Vec<BudgetComponentRecord<'a>>,
+    combined_uncertainty: f64,
+}
+
+impl UncertaintyBudget {
+    /// Create an empty budget
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// Add a standard uncertainty component; names must be unique
+    /// 
+    /// # Returns
+    /// * `Ok(&mut Self)` - The budget, for chaining
+    /// * `Err(CalculationError)` - If the value is negative or not finite, or the name is taken
+    pub fn add_component(
+        &mut self,
+        name: &str,
+        value: f64,
+        evaluation_type: EvaluationType,
+    ) -> Result<&mut Self, CalculationError> {
+        if !is_valid_float(value) || value < 0.0 {
+            return Err(CalculationError::InvalidInput {
+                message: format!("Invalid uncertainty component '{}': {}", name, value),
+            });
+        }
+        
+        if self.components.iter().any(|c| c.name == name) {
+            return Err(CalculationError::InvalidInput {
+                message: format!("Duplicate uncertainty component name: {}", name),
+            });
+        }
+        
+        self.components.push(UncertaintyComponent {
+            name: name.to_string(),
+            value,
+            evaluation_type,
+        });
+        Ok(self)
+    }
+    
+    /// Combined standard uncertainty, the root-sum-of-squares of the components
+    pub fn combined(&self) -> Result<f64, CalculationError> {
+        let values: Vec<f64> = self.components.iter().map(|c| c.value).collect();
+        combine_uncertainty_components(&values)
+    }
+    
+    /// Expanded uncertainty U = k·u_c
+    pub fn expanded(&self, k: f64) -> Result<f64, CalculationError> {
+        if !is_valid_float(k) || k <= 0.0 {
+            return Err(CalculationError::InvalidInput {
+                message: format!("Invalid or non-positive coverage factor k: {}", k),
+            });
+        }
+        
+        Ok(k * self.combined()?)
+    }
+    
+    /// Percentage of the combined variance contributed by each component, in order
+    pub fn contributions(&self) -> Result<Vec<f64>, CalculationError> {
+        let named: Vec<(String, f64)> = self.components.iter()
+            .map(|c| (c.name.clone(), c.value))
+            .collect();
+        let (_, contributions) = combine_named_uncertainty_components(&named)?;
+        
+        Ok(contributions.into_iter().map(|(_, percent)| percent).collect())
+    }
+    
+    pub fn components(&self) -> &[UncertaintyComponent] {
+        &self.components
+    }
+    
+    fn record(&self) -> Result<BudgetRecord<'_>, CalculationError> {
+        let components = self.components.iter()
+            .zip(self.contributions()?)
+            .map(|(c, contribution_percent)| BudgetComponentRecord {
+                name: &c.name,
+                value: c.value,
+                evaluation_type: c.evaluation_type,
+                contribution_percent,
+            })
+            .collect();
+        
+        Ok(BudgetRecord {
+            components,
+            combined_uncertainty: self.combined()?,
+        })
+    }
+}
+
+/// Serializes to the same schema as the Python dict export:
+/// 
+/// ```text
+/// {
+///   "components": [
+///     {"name": string, "value": number, "type": "A" | "B", "contribution_percent": number}
+///   ],
+///   "combined_uncertainty": number
+/// }
+/// ```
+/// 
+/// An empty budget has no combined uncertainty and fails to serialize.
+impl Serialize for UncertaintyBudget {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.record()
+            .map_err(serde::ser::Error::custom)?
+            .serialize(serializer)
+    }
+}
+
+#[pymethods]
+impl UncertaintyBudget {
+    fn __len__(&self) -> usize {
+        self.components.len()
+    }
+    
+    /// Add a standard uncertainty component with evaluation type "A" or "B"
+    #[pyo3(name = "add_component")]
+    fn py_add_component(&mut self, name: &str, value: f64, type_: &str) -> PyResult<()> {
+        self.add_component(name, value, type_.parse()?)?;
+        Ok(())
+    }
+    
+    /// Combined standard uncertainty of all components
+    #[pyo3(name = "combined")]
+    fn py_combined(&self) -> PyResult<f64> {
+        Ok(self.combined()?)
+    }
+    
+    /// Expanded uncertainty with coverage factor `k` (default 2)
+    #[pyo3(name = "expanded")]
+    fn py_expanded(&self, k: Option<f64>) -> PyResult<f64> {
+        Ok(self.expanded(k.unwrap_or(2.0))?)
+    }
+    
+    /// Export as a dict with keys components and combined_uncertainty
+    /// 
+    /// Each component is a dict with keys name, value, type and contribution_percent.
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<&'py PyDict> {
+        let record = self.record()?;
+        
+        let components = PyList::empty(py);
+        for component in record.components {
+            let item = PyDict::new(py);
+            item.set_item("name", component.name)?;
+            item.set_item("value", component.value)?;
+            item.set_item("type", format!("{:?}", component.evaluation_type))?;
+            item.set_item("contribution_percent", component.contribution_percent)?;
+            components.append(item)?;
+        }
+        
+        let dict = PyDict::new(py);
+        dict.set_item("components", components)?;
+        dict.set_item("combined_uncertainty", record.combined_uncertainty)?;
+        Ok(dict)
+    }
+}
+
+/// Uncertainty budget for a consensus assigned value
+/// 
+/// A single Type A characterisation component computed by
+/// `calculate_uncertainty_consensus`.
+pub fn consensus_uncertainty_budget(
+    robust_std_dev: f64,
+    num_participants: usize,
+    small_sample_correction: bool,
+    scale_method: ScaleKind,
+) -> Result<UncertaintyBudget, CalculationError> {
+    let u_char = calculate_uncertainty_consensus(robust_std_dev, num_participants, small_sample_correction, scale_method)?;
+    
+    let mut budget = UncertaintyBudget::new();
+    budget.add_component("characterisation", u_char, EvaluationType::A)?;
+    Ok(budget)
+}
+
+/// Uncertainty budget for a CRM assigned value (one Type B certificate component)
+pub fn crm_uncertainty_budget(crm_uncertainty: f64) -> Result<UncertaintyBudget, CalculationError> {
+    let u_crm = calculate_uncertainty_crm(crm_uncertainty)?;
+    
+    let mut budget = UncertaintyBudget::new();
+    budget.add_component("crm_certificate", u_crm, EvaluationType::B)?;
+    Ok(budget)
+}
+
+/// Uncertainty budget for a formulation assigned value (one Type B component)
+pub fn formulation_uncertainty_budget(
+    formulation_uncertainty: f64,
+) -> Result<UncertaintyBudget, CalculationError> {
+    let u_form = calculate_uncertainty_formulation(formulation_uncertainty)?;
+    
+    let mut budget = UncertaintyBudget::new();
+    budget.add_component("formulation", u_form, EvaluationType::B)?;
+    Ok(budget)
+}
+
+/// Robust consensus assigned value and its uncertainty
+#[derive(Debug, Clone, Serialize)]
+pub struct RobustConsensus {
+    pub x_pt: f64,
+    pub u_x_pt: f64,
+    pub s_star: f64,
+    /// Number of participant results supplied
+    pub n_total: usize,
+    pub iterations: usize,
+    /// Whether s* was clamped to its minimum value (e.g. identical results)
+    pub scale_clamped: bool,
+    /// Non-fatal conditions noticed by Algorithm A
+    pub warnings: Vec<CalculationWarning>,
+}
+
+impl RobustConsensus {
+    /// Coverage interval x_pt ± k·u(x_pt) at the given nominal confidence level
+    pub fn coverage_interval(
+        &self,
+        coverage_factor: f64,
+        confidence_level: f64,
+    ) -> Result<CoverageInterval, CalculationError> {
+        CoverageInterval::new(self.x_pt, self.u_x_pt, coverage_factor, confidence_level)
+    }
+}
+
+/// Calculate the consensus assigned value and its uncertainty in one call
+/// 
+/// Runs Algorithm A and `calculate_uncertainty_consensus` on the result.
+/// 
+/// # Arguments
+/// * `results` - Array view of participant results
+/// * `tolerance` - Convergence tolerance for Algorithm A
+/// * `max_iterations` - Maximum number of Algorithm A iterations
+/// 
+/// # Returns
+/// * `Ok(RobustConsensus)` - x_pt, u(x_pt), s* and calculation details
+/// * `Err(CalculationError)` - If Algorithm A or the uncertainty calculation fails
+pub fn robust_consensus(
+    results: ArrayView1<f64>,
+    tolerance: f64,
+    max_iterations: usize,
+) -> Result<RobustConsensus, CalculationError> {
+    let result = calculate_algorithm_a(results, tolerance, max_iterations)?;
+    let u_x_pt = calculate_uncertainty_consensus(result.s_star, result.participants_used, false, ScaleKind::SStar)?;
+    
+    Ok(RobustConsensus {
+        x_pt: result.x_pt,
+        u_x_pt,
+        s_star: result.s_star,
+        n_total: results.len(),
+        iterations: result.iterations,
+        scale_clamped: result.scale_clamped,
+        warnings: result.warnings,
+    })
+}
+
+/// Analytic and jackknife estimates of the consensus uncertainty
+#[derive(Debug, Clone, Copy)]
+pub struct JackknifeUncertainty {
+    /// Jackknife standard error of x_pt
+    pub jackknife: f64,
+    /// Analytic u(x_pt) = 1.25 * s* / sqrt(p) from the full data set
+    pub analytic: f64,
+}
+
+/// Calculate the jackknife uncertainty of the Algorithm A assigned value
+/// 
+/// Each participant is left out in turn and Algorithm A is recomputed on the
+/// remaining results. The jackknife standard error is
+/// sqrt((n - 1) / n * Σ(x_pt(-i) - mean)^2). This is deterministic and cheaper
+/// than a bootstrap, and is returned together with the analytic consensus
+/// uncertainty for method comparison.
+/// 
+/// # Arguments
+/// * `results` - Array view of participant results
+/// 
+/// # Returns
+/// * `Ok(JackknifeUncertainty)` - Jackknife and analytic uncertainties
+/// * `Err(CalculationError)` - If there are too few results or Algorithm A fails
+pub fn jackknife_uncertainty(
+    results: ArrayView1<f64>,
+) -> Result<JackknifeUncertainty, CalculationError> {
+    let data = results.to_vec();
+    let n = data.len();
+    
+    // Every leave-one-out subset must still be large enough for Algorithm A
+    if n < MIN_PARTICIPANTS_ALGORITHM_A + 1 {
+        return Err(CalculationError::InsufficientData {
+            required: MIN_PARTICIPANTS_ALGORITHM_A + 1,
+            actual: n,
+        });
+    }
+    
+    validate_floats(&data, "participant results")?;
+    
+    let full = calculate_algorithm_a(results, DEFAULT_TOLERANCE, DEFAULT_MAX_ITERATIONS)?;
+    let analytic = calculate_uncertainty_consensus(full.s_star, full.participants_used, false, ScaleKind::SStar)?;
+    
+    let mut leave_one_out = Vec::with_capacity(n);
+    for i in 0..n {
+        let subset: Array1<f64> = data.iter()
+            .enumerate()
+            .filter(|&(j, _)| j != i)
+            .map(|(_, &value)| value)
+            .collect();
+        let result = calculate_algorithm_a(subset.view(), DEFAULT_TOLERANCE, DEFAULT_MAX_ITERATIONS)?;
+        leave_one_out.push(result.x_pt);
+    }
+    
+    let mean = leave_one_out.iter().sum::<f64>() / n as f64;
+    let sum_squares = leave_one_out.iter()
+        .map(|&x| (x - mean).powi(2))
+        .sum::<f64>();
+    let jackknife = ((n - 1) as f64 / n as f64 * sum_squares).sqrt();
+    
+    Ok(JackknifeUncertainty { jackknife, analytic })
+}
+
+/// Analytic and bootstrap estimates of the consensus uncertainty
+#[derive(Debug, Clone, Copy)]
+pub struct BootstrapUncertainty {
+    /// Standard deviation of the bootstrap distribution of x_pt
+    pub bootstrap: f64,
+    /// Analytic u(x_pt) = 1.25 * s* / sqrt(p) from the full data set
+    pub analytic: f64,
+    /// bootstrap / analytic
+    pub ratio: f64,
+}
+
+/// Calculate the bootstrap uncertainty of the Algorithm A assigned value
+/// 
+/// Draws `n_resamples` samples of the same size with replacement, reruns
+/// Algorithm A on each and returns the sample standard deviation of the
+/// resulting x_pt values together with the analytic consensus uncertainty,
+/// to validate the 1.25 * s* / sqrt(p) approximation. The same seed always
+/// gives the same result.
+/// 
+/// # Arguments
+/// * `results` - Array view of participant results
+/// * `n_resamples` - Number of bootstrap resamples (at least 2)
+/// * `seed` - Seed of the pseudo-random number generator
+/// 
+/// # Returns
+/// * `Ok(BootstrapUncertainty)` - Bootstrap and analytic uncertainties and their ratio
+/// * `Err(CalculationError)` - If the input is invalid or Algorithm A fails on a resample
+pub fn bootstrap_uncertainty_consensus(
+    results: ArrayView1<f64>,
+    n_resamples: usize,
+    seed: u64,
+) -> Result<BootstrapUncertainty, CalculationError> {
+    let full = calculate_algorithm_a(results, DEFAULT_TOLERANCE, DEFAULT_MAX_ITERATIONS)?;
+    let analytic = calculate_uncertainty_consensus(full.s_star, full.participants_used, false, ScaleKind::SStar)?;
+    
+    if analytic <= 0.0 {
+        return Err(CalculationError::DivisionByZero);
+    }
+    
+    let bootstrap = bootstrap_std_dev(&results.to_vec(), n_resamples, seed, |resample| {
+        let result = calculate_algorithm_a(ArrayView1::from(&*resample), DEFAULT_TOLERANCE, DEFAULT_MAX_ITERATIONS)?;
+        Ok(result.x_pt)
+    })?;
+    
+    Ok(BootstrapUncertainty {
+        bootstrap,
+        analytic,
+        ratio: bootstrap / analytic,
+    })
+}
+
+/// Distribution of a model output from Monte Carlo propagation
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct MonteCarloUncertainty {
+    pub mean: f64,
+    /// Standard deviation of the output, the standard uncertainty u(y)
+    pub std_dev: f64,
+    /// Lower end of the probabilistically symmetric 95 % coverage interval
+    pub lower: f64,
+    /// Upper end of the probabilistically symmetric 95 % coverage interval
+    pub upper: f64,
+}
+
+/// Propagate input uncertainties through a model by Monte Carlo sampling
+/// 
+/// Follows GUM Supplement 1: each input is drawn from an independent normal
+/// distribution N(x_i, u_i^2), the model is evaluated on the draws, and the
+/// output is summarised by its mean, standard deviation and the 2.5 and 97.5
+/// percentiles. Unlike first-order propagation this stays valid for
+/// non-linear models. The model is evaluated in batches of up to
+/// `MONTE_CARLO_BATCH_SIZE` draws: it receives one array of draws per input and
+/// must return one output per draw. The same seed always gives the same result.
+/// 
+/// # Arguments
+/// * `model_inputs` - (estimate, standard uncertainty) of each input quantity
+/// * `model` - Batch model evaluation
+/// * `n_draws` - Number of Monte Carlo draws (at least 2; GUM S1 suggests 10^6)
+/// * `seed` - Seed of the pseudo-random number generator
+/// 
+/// # Returns
+/// * `Ok(MonteCarloUncertainty)` - Mean, standard deviation and 95 % coverage interval
+/// * `Err(E)` - If an input is invalid, the model fails or returns the wrong
+///   number of outputs or a non-finite value
+pub fn monte_carlo_uncertainty<F, E>(
+    model_inputs: &[(f64, f64)],
+    mut model: F,
+    n_draws: usize,
+    seed: u64,
+) -> Result<MonteCarloUncertainty, E>
+where
+    F: FnMut(&[Array1<f64>]) -> Result<Array1<f64>, E>,
+    E: From<CalculationError>,
+{
+    if model_inputs.is_empty() {
+        return Err(CalculationError::InsufficientData {
+            required: 1,
+            actual: 0,
+        }.into());
+    }
+    
+    if n_draws < 2 {
+        return Err(CalculationError::InvalidInput {
+            message: format!("At least 2 Monte Carlo draws are required, got {}", n_draws),
+        }.into());
+    }
+    
+    for (i, &(x, u)) in model_inputs.iter().enumerate() {
+        if !is_valid_float(x) || !is_valid_float(u) || u < 0.0 {
+            return Err(CalculationError::InvalidInput {
+                message: format!("Invalid model input at index {}: ({}, {})", i, x, u),
+            }.into());
+        }
+    }
+    
+    let mut rng = SplitMix64::new(seed);
+    let mut outputs = Vec::with_capacity(n_draws);
+    
+    while outputs.len() < n_draws {
+        let batch_len = MONTE_CARLO_BATCH_SIZE.min(n_draws - outputs.len());
+        let draws: Vec<Array1<f64>> = model_inputs.iter()
+            .map(|&(x, u)| (0..batch_len).map(|_| x + u * rng.next_normal()).collect())
+            .collect();
+        
+        let batch = model(&draws)?;
+        validate_array_dimensions(batch_len, batch.len(), "draws", "model outputs")?;
+        validate_floats(batch.iter(), "model outputs")?;
+        outputs.extend(batch.iter());
+    }
+    
+    let n = n_draws as f64;
+    let mean = outputs.iter().sum::<f64>() / n;
+    let std_dev = (outputs.iter().map(|&y| (y - mean).powi(2)).sum::<f64>() / (n - 1.0)).sqrt();
+    
+    Ok(MonteCarloUncertainty {
+        mean,
+        std_dev,
+        lower: percentile(&outputs, 2.5)?,
+        upper: percentile(&outputs, 97.5)?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+    use ndarray::{array, Array2};
+
+    #[test]
+    fn test_uncertainty_consensus() {
+        let robust_std = 1.0;
+        let participants = 25;
+        let result = calculate_uncertainty_consensus(robust_std, participants, false, ScaleKind::SStar).unwrap();
+        
+        // u(x_pt) = 1.25 * 1.0 / sqrt(25) = 1.25 / 5 = 0.25
+        assert_abs_diff_eq!(result, 0.25, epsilon = 1e-10);
+    }
+
+    #[test]
+    fn test_uncertainty_consensus_invalid_inputs() {
+        // Invalid standard deviation
+        assert!(calculate_uncertainty_consensus(f64::NAN, 10, false, ScaleKind::SStar).is_err());
+        assert!(calculate_uncertainty_consensus(-1.0, 10, false, ScaleKind::SStar).is_err());
+        
+        // Zero participants
+        assert!(calculate_uncertainty_consensus(1.0, 0, false, ScaleKind::SStar).is_err());
+    }
+
+    #[test]
+    fn test_uncertainty_consensus_small_sample_correction() {
+        let uncorrected = calculate_uncertainty_consensus(1.0, 6, false, ScaleKind::SStar).unwrap();
+        let corrected = calculate_uncertainty_consensus(1.0, 6, true, ScaleKind::SStar).unwrap();
+        
+        // t-factor for 5 degrees of freedom at 68.27 % coverage is 1.11 (GUM Table G.2)
+        assert_abs_diff_eq!(uncorrected, 1.25 / 6.0f64.sqrt(), epsilon = 1e-12);
+        assert_abs_diff_eq!(corrected / uncorrected, 1.11, epsilon = 0.005);
+        
+        // The correction vanishes for large p
+        let ratio = calculate_uncertainty_consensus(1.0, 10_000, true, ScaleKind::SStar).unwrap()
+            / calculate_uncertainty_consensus(1.0, 10_000, false, ScaleKind::SStar).unwrap();
+        assert_abs_diff_eq!(ratio, 1.0, epsilon = 1e-3);
+        
+        assert!(calculate_uncertainty_consensus(1.0, 1, true, ScaleKind::SStar).is_err());
+        assert!(calculate_uncertainty_consensus(1.0, 1, false, ScaleKind::SStar).is_ok());
+    }
+
+    #[test]
+    fn test_uncertainty_crm() {
+        let crm_unc = 0.15;
+        let result = calculate_uncertainty_crm(crm_unc).unwrap();
+        assert_eq!(result, 0.15);
+        
+        // Invalid uncertainty
         assert!(calculate_uncertainty_crm(f64::NAN).is_err());
         assert!(calculate_uncertainty_crm(-0.1).is_err());
     }
@@ -230,6 +2121,29 @@ mod tests {
         assert_eq!(result, 0.0); // Single result has zero standard error
     }
 
+    #[test]
+    fn test_jackknife_uncertainty() {
+        let results = array![9.8, 10.0, 10.2, 9.9, 10.1, 9.7, 10.3, 10.05, 9.95, 10.15];
+        let estimate = jackknife_uncertainty(results.view()).unwrap();
+        
+        assert!(estimate.jackknife > 0.0);
+        assert!(estimate.analytic > 0.0);
+        // Both estimates should be of the same order for well-behaved data
+        let ratio = estimate.jackknife / estimate.analytic;
+        assert!(ratio > 0.3 && ratio < 3.0, "ratio = {}", ratio);
+        
+        // Deterministic
+        let again = jackknife_uncertainty(results.view()).unwrap();
+        assert_eq!(estimate.jackknife, again.jackknife);
+    }
+
+    #[test]
+    fn test_jackknife_uncertainty_insufficient_data() {
+        let results = array![1.0, 2.0, 3.0, 4.0, 5.0];
+        let result = jackknife_uncertainty(results.view());
+        assert!(matches!(result, Err(CalculationError::InsufficientData { required: 6, actual: 5 })));
+    }
+
     #[test]
     fn test_uncertainty_expert_from_results_empty() {
         let expert_results = vec![];
@@ -245,4 +2159,764 @@ mod tests {
         assert!(result.is_err());
         matches!(result.unwrap_err(), CalculationError::InvalidInput { .. });
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_robust_consensus() {
+        let data = array![9.8, 10.1, 10.0, 9.9, 10.2, 10.0, 10.3];
+        let consensus = robust_consensus(data.view(), DEFAULT_TOLERANCE, DEFAULT_MAX_ITERATIONS).unwrap();
+        let algorithm_a = calculate_algorithm_a(data.view(), DEFAULT_TOLERANCE, DEFAULT_MAX_ITERATIONS).unwrap();
+        
+        assert_eq!(consensus.x_pt, algorithm_a.x_pt);
+        assert_eq!(consensus.s_star, algorithm_a.s_star);
+        assert_eq!(consensus.n_total, 7);
+        assert_eq!(consensus.iterations, algorithm_a.iterations);
+        assert!(!consensus.scale_clamped);
+        assert_abs_diff_eq!(
+            consensus.u_x_pt,
+            calculate_uncertainty_consensus(algorithm_a.s_star, algorithm_a.participants_used, false, ScaleKind::SStar).unwrap(),
+            epsilon = 1e-15
+        );
+        
+        assert!(robust_consensus(array![1.0, 2.0].view(), DEFAULT_TOLERANCE, DEFAULT_MAX_ITERATIONS).is_err());
+    }
+
+    #[test]
+    fn test_combine_uncertainty_components() {
+        assert_abs_diff_eq!(combine_uncertainty_components(&[0.3]).unwrap(), 0.3, epsilon = 1e-15);
+        // sqrt(0.2^2 + 0.4^2 + 0.4^2) = sqrt(0.36)
+        assert_abs_diff_eq!(combine_uncertainty_components(&[0.2, 0.4, 0.4]).unwrap(), 0.6, epsilon = 1e-12);
+        
+        assert!(matches!(
+            combine_uncertainty_components(&[]),
+            Err(CalculationError::InsufficientData { required: 1, actual: 0 })
+        ));
+        assert!(combine_uncertainty_components(&[0.2, -0.1]).is_err());
+        assert!(combine_uncertainty_components(&[0.2, f64::NAN]).is_err());
+    }
+
+    #[test]
+    fn test_combine_named_uncertainty_components() {
+        let components = vec![
+            ("characterisation".to_string(), 0.2),
+            ("homogeneity".to_string(), 0.4),
+            ("stability".to_string(), 0.4),
+        ];
+        let (combined, contributions) = combine_named_uncertainty_components(&components).unwrap();
+        
+        assert_abs_diff_eq!(combined, 0.6, epsilon = 1e-12);
+        let names: Vec<&str> = contributions.iter().map(|(name, _)| name.as_str()).collect();
+        assert_eq!(names, vec!["characterisation", "homogeneity", "stability"]);
+        // 0.04 / 0.36, 0.16 / 0.36, 0.16 / 0.36
+        assert_abs_diff_eq!(contributions[0].1, 100.0 / 9.0, epsilon = 1e-10);
+        assert_abs_diff_eq!(contributions[1].1, 400.0 / 9.0, epsilon = 1e-10);
+        assert_abs_diff_eq!(contributions.iter().map(|(_, p)| p).sum::<f64>(), 100.0, epsilon = 1e-10);
+        
+        let (combined, contributions) = combine_named_uncertainty_components(&[("char".to_string(), 0.0)]).unwrap();
+        assert_eq!(combined, 0.0);
+        assert_eq!(contributions[0].1, 0.0);
+        
+        assert!(combine_named_uncertainty_components(&[
+            ("char".to_string(), 0.1),
+            ("char".to_string(), 0.2),
+        ]).is_err());
+    }
+
+    #[test]
+    fn test_uncertainty_budget_combined() {
+        let mut budget = UncertaintyBudget::new();
+        budget.add_component("characterisation", 0.3, EvaluationType::A).unwrap()
+            .add_component("homogeneity", 0.4, EvaluationType::A).unwrap()
+            .add_component("stability", 1.2, EvaluationType::B).unwrap();
+        
+        assert_abs_diff_eq!(budget.combined().unwrap(), 1.3, epsilon = 1e-12);
+        assert_abs_diff_eq!(
+            budget.combined().unwrap(),
+            combine_uncertainty_components(&[0.3, 0.4, 1.2]).unwrap(),
+            epsilon = 1e-15
+        );
+        assert_abs_diff_eq!(budget.expanded(2.0).unwrap(), 2.6, epsilon = 1e-12);
+        
+        let contributions = budget.contributions().unwrap();
+        assert_abs_diff_eq!(contributions.iter().sum::<f64>(), 100.0, epsilon = 1e-10);
+        assert_abs_diff_eq!(contributions[2], 100.0 * 1.44 / 1.69, epsilon = 1e-10);
+    }
+
+    #[test]
+    fn test_uncertainty_budget_invalid() {
+        let mut budget = UncertaintyBudget::new();
+        assert!(budget.combined().is_err());
+        assert!(budget.add_component("u", -0.1, EvaluationType::A).is_err());
+        assert!(budget.add_component("u", f64::NAN, EvaluationType::A).is_err());
+        
+        budget.add_component("u", 0.1, EvaluationType::B).unwrap();
+        assert!(budget.add_component("u", 0.2, EvaluationType::B).is_err());
+        assert!(budget.expanded(0.0).is_err());
+        
+        assert_eq!("a".parse::<EvaluationType>().unwrap(), EvaluationType::A);
+        assert!("C".parse::<EvaluationType>().is_err());
+    }
+
+    #[test]
+    fn test_uncertainty_budget_export_schema() {
+        let mut budget = UncertaintyBudget::new();
+        budget.add_component("crm_certificate", 0.3, EvaluationType::B).unwrap()
+            .add_component("homogeneity", 0.4, EvaluationType::A).unwrap();
+        
+        let json = serde_json::to_value(&budget).unwrap();
+        let keys: Vec<&String> = json.as_object().unwrap().keys().collect();
+        assert_eq!(keys, ["combined_uncertainty", "components"]);
+        assert_abs_diff_eq!(json["combined_uncertainty"].as_f64().unwrap(), 0.5, epsilon = 1e-12);
+        
+        let components = json["components"].as_array().unwrap();
+        assert_eq!(components.len(), 2);
+        let keys: Vec<&String> = components[0].as_object().unwrap().keys().collect();
+        assert_eq!(keys, ["contribution_percent", "name", "type", "value"]);
+        assert_eq!(components[0]["name"], "crm_certificate");
+        assert_eq!(components[0]["type"], "B");
+        assert_eq!(components[1]["type"], "A");
+        assert_abs_diff_eq!(components[0]["contribution_percent"].as_f64().unwrap(), 36.0, epsilon = 1e-10);
+        
+        assert!(serde_json::to_string(&UncertaintyBudget::new()).is_err());
+    }
+
+    #[test]
+    fn test_method_uncertainty_budgets() {
+        let budget = consensus_uncertainty_budget(1.0, 25, false, ScaleKind::SStar).unwrap();
+        assert_eq!(budget.components().len(), 1);
+        assert_eq!(budget.components()[0].evaluation_type, EvaluationType::A);
+        assert_abs_diff_eq!(
+            budget.combined().unwrap(),
+            calculate_uncertainty_consensus(1.0, 25, false, ScaleKind::SStar).unwrap(),
+            epsilon = 1e-15
+        );
+        
+        let budget = crm_uncertainty_budget(0.05).unwrap();
+        assert_eq!(budget.components()[0].name, "crm_certificate");
+        assert_eq!(budget.components()[0].evaluation_type, EvaluationType::B);
+        assert_abs_diff_eq!(budget.combined().unwrap(), 0.05, epsilon = 1e-15);
+        
+        assert!(formulation_uncertainty_budget(-0.1).is_err());
+        assert_abs_diff_eq!(formulation_uncertainty_budget(0.02).unwrap().combined().unwrap(), 0.02, epsilon = 1e-15);
+    }
+
+    #[test]
+    fn test_bootstrap_uncertainty_consensus() {
+        let mut rng = SplitMix64::new(42);
+        let results: Array1<f64> = (0..60).map(|_| 10.0 + 0.5 * rng.next_normal()).collect();
+        
+        let bootstrap = bootstrap_uncertainty_consensus(results.view(), 500, 7).unwrap();
+        assert!((bootstrap.ratio - 1.0).abs() < 0.3, "ratio {}", bootstrap.ratio);
+        assert_abs_diff_eq!(bootstrap.ratio, bootstrap.bootstrap / bootstrap.analytic, epsilon = 1e-15);
+        
+        // Same seed, same result
+        let again = bootstrap_uncertainty_consensus(results.view(), 500, 7).unwrap();
+        assert_eq!(again.bootstrap, bootstrap.bootstrap);
+        
+        let other_seed = bootstrap_uncertainty_consensus(results.view(), 500, 8).unwrap();
+        assert_ne!(other_seed.bootstrap, bootstrap.bootstrap);
+    }
+
+    #[test]
+    fn test_bootstrap_uncertainty_consensus_invalid() {
+        let results = array![9.8, 10.0, 10.2, 9.9, 10.1];
+        assert!(bootstrap_uncertainty_consensus(results.view(), 1, 0).is_err());
+        assert!(bootstrap_uncertainty_consensus(array![1.0, 2.0].view(), 100, 0).is_err());
+    }
+
+    #[test]
+    fn test_propagate_uncertainty_uncorrelated() {
+        let values = [10.0, 2.0, 0.5];
+        let uncertainties = [0.1, 0.02, 0.01];
+        let sensitivities = [0.5, -2.0, 4.0];
+        
+        let result = propagate_uncertainty(&values, &uncertainties, &sensitivities, None).unwrap();
+        let rss = combine_uncertainty_components(&[0.05, 0.04, 0.04]).unwrap();
+        assert_abs_diff_eq!(result.combined, rss, epsilon = 1e-15);
+        assert_abs_diff_eq!(result.contributions[0], 0.0025, epsilon = 1e-15);
+        
+        // An identity correlation matrix is the same as no correlations
+        let identity = Array2::eye(3);
+        let with_identity = propagate_uncertainty(&values, &uncertainties, &sensitivities, Some(identity.view())).unwrap();
+        assert_abs_diff_eq!(with_identity.combined, rss, epsilon = 1e-15);
+    }
+
+    #[test]
+    fn test_propagate_uncertainty_fully_correlated() {
+        let values = [1.0, 1.0, 1.0];
+        let uncertainties = [0.1, 0.2, 0.3];
+        let sensitivities = [1.0, 2.0, 0.5];
+        let ones = Array2::from_elem((3, 3), 1.0);
+        
+        let result = propagate_uncertainty(&values, &uncertainties, &sensitivities, Some(ones.view())).unwrap();
+        
+        // Linear-sum limit: u_c = Σ c_i u(x_i)
+        assert_abs_diff_eq!(result.combined, 0.1 + 0.4 + 0.15, epsilon = 1e-12);
+        assert_abs_diff_eq!(result.contributions.iter().sum::<f64>(), 0.65 * 0.65, epsilon = 1e-12);
+        
+        // Full anti-correlation of two equal components cancels them
+        let anti = array![[1.0, -1.0], [-1.0, 1.0]];
+        let result = propagate_uncertainty(&[1.0, 1.0], &[0.1, 0.1], &[1.0, 1.0], Some(anti.view())).unwrap();
+        assert_abs_diff_eq!(result.combined, 0.0, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_propagate_uncertainty_invalid() {
+        let values = [1.0, 1.0];
+        let u = [0.1, 0.1];
+        let c = [1.0, 1.0];
+        
+        assert!(matches!(
+            propagate_uncertainty(&values, &[0.1], &c, None),
+            Err(CalculationError::DimensionMismatch { .. })
+        ));
+        assert!(propagate_uncertainty(&values, &[0.1, -0.1], &c, None).is_err());
+        assert!(propagate_uncertainty(&[], &[], &[], None).is_err());
+        
+        let asymmetric = array![[1.0, 0.5], [0.4, 1.0]];
+        assert!(propagate_uncertainty(&values, &u, &c, Some(asymmetric.view())).is_err());
+        let bad_diagonal = array![[0.9, 0.5], [0.5, 1.0]];
+        assert!(propagate_uncertainty(&values, &u, &c, Some(bad_diagonal.view())).is_err());
+        let out_of_range = array![[1.0, 1.5], [1.5, 1.0]];
+        assert!(propagate_uncertainty(&values, &u, &c, Some(out_of_range.view())).is_err());
+        let wrong_shape = Array2::eye(3);
+        assert!(propagate_uncertainty(&values, &u, &c, Some(wrong_shape.view())).is_err());
+    }
+
+    #[test]
+    fn test_check_uncertainty_criterion() {
+        let below = check_uncertainty_criterion(0.29, 1.0).unwrap();
+        assert!(below.passed);
+        assert_eq!(below.recommendation, UncertaintyRecommendation::UseZ);
+        
+        // The boundary passes, including ratios that are 0.3 only in decimal
+        for (u_x_pt, sigma_pt) in [(0.3, 1.0), (0.03, 0.1), (0.09, 0.3), (0.6, 2.0)] {
+            let boundary = check_uncertainty_criterion(u_x_pt, sigma_pt).unwrap();
+            assert!(boundary.passed, "{} / {} = {}", u_x_pt, sigma_pt, boundary.ratio);
+            assert_eq!(boundary.recommendation, UncertaintyRecommendation::UseZ);
+        }
+        
+        let above = check_uncertainty_criterion(0.5, 1.0).unwrap();
+        assert_abs_diff_eq!(above.ratio, 0.5, epsilon = 1e-15);
+        assert!(!above.passed);
+        assert_eq!(above.recommendation, UncertaintyRecommendation::UseZPrime);
+        
+        let large = check_uncertainty_criterion(0.9, 1.0).unwrap();
+        assert_eq!(large.recommendation, UncertaintyRecommendation::ReviewSigmaPt);
+        assert_eq!(large.recommendation.as_str(), "review_sigma_pt");
+    }
+
+    #[test]
+    fn test_check_uncertainty_criterion_invalid() {
+        assert!(check_uncertainty_criterion(0.1, 0.0).is_err());
+        assert!(check_uncertainty_criterion(0.1, -1.0).is_err());
+        assert!(check_uncertainty_criterion(-0.1, 1.0).is_err());
+        assert!(check_uncertainty_criterion(f64::NAN, 1.0).is_err());
+    }
+
+    #[test]
+    fn test_uncertainty_expert_robust() {
+        let experts = [10.0, 10.1, 9.9, 10.05, 9.95, 15.0];
+        let result = calculate_uncertainty_expert_robust(&experts, MAD_TO_SIGMA).unwrap();
+        
+        assert!(result.sufficient);
+        assert_abs_diff_eq!(
+            result.classical,
+            calculate_uncertainty_expert_from_results(&experts).unwrap(),
+            epsilon = 1e-15
+        );
+        
+        // Median 10.025, MAD = median(0.025, 0.075, 0.125, 0.025, 0.075, 4.975) = 0.075
+        let expected = 1.25 * 0.075 * MAD_TO_SIGMA / 6.0_f64.sqrt();
+        assert_abs_diff_eq!(result.robust, expected, epsilon = 1e-12);
+        assert!(result.robust < result.classical / 10.0);
+    }
+
+    #[test]
+    fn test_uncertainty_expert_robust_single_expert() {
+        let result = calculate_uncertainty_expert_robust(&[10.0], MAD_TO_SIGMA).unwrap();
+        assert!(!result.sufficient);
+        assert_eq!(result.robust, 0.0);
+        assert_eq!(result.classical, 0.0);
+        
+        assert!(calculate_uncertainty_expert_robust(&[], MAD_TO_SIGMA).is_err());
+        assert!(calculate_uncertainty_expert_robust(&[1.0, f64::NAN], MAD_TO_SIGMA).is_err());
+        assert!(calculate_uncertainty_expert_robust(&[1.0, 2.0], 0.0).is_err());
+    }
+
+    #[test]
+    fn test_uncertainty_median_constants() {
+        let factor = (std::f64::consts::PI / 2.0).sqrt();
+        assert_abs_diff_eq!(MEDIAN_UNCERTAINTY_FACTOR, factor, epsilon = 1e-15);
+        
+        // nIQR and s*: sqrt(π/2) * scale / sqrt(p)
+        for kind in [ScaleKind::NIQR, ScaleKind::SStar] {
+            let u = calculate_uncertainty_median(2.0, 16, kind).unwrap();
+            assert_abs_diff_eq!(u, factor * 2.0 / 4.0, epsilon = 1e-12);
+        }
+        
+        // MADe: small-sample table for p <= 9, p / (p - 0.8) above
+        let u = calculate_uncertainty_median(1.0, 5, ScaleKind::MADe).unwrap();
+        assert_abs_diff_eq!(u, factor * 1.206 / 5.0_f64.sqrt(), epsilon = 1e-12);
+        let u = calculate_uncertainty_median(1.0, 16, ScaleKind::MADe).unwrap();
+        assert_abs_diff_eq!(u, factor * (16.0 / 15.2) / 4.0, epsilon = 1e-12);
+        let u = calculate_uncertainty_median(1.0, 1, ScaleKind::MADe).unwrap();
+        assert_abs_diff_eq!(u, factor, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_uncertainty_median_invalid() {
+        assert!(matches!(
+            calculate_uncertainty_median(1.0, 0, ScaleKind::MADe),
+            Err(CalculationError::InsufficientData { .. })
+        ));
+        assert!(calculate_uncertainty_median(-1.0, 10, ScaleKind::NIQR).is_err());
+        assert_eq!("s_star".parse::<ScaleKind>().unwrap(), ScaleKind::SStar);
+        assert!("sd".parse::<ScaleKind>().is_err());
+    }
+
+    #[test]
+    fn test_check_assigned_value_agreement() {
+        // |10.5 - 10.0| / sqrt(0.3^2 + 0.4^2) = 1
+        let boundary = check_assigned_value_agreement(10.5, 0.3, 10.0, 0.4, 1.0).unwrap();
+        assert_abs_diff_eq!(boundary.ratio, 1.0, epsilon = 1e-12);
+        assert!(boundary.agree);
+        
+        let drifted = check_assigned_value_agreement(9.0, 0.3, 10.0, 0.4, 1.0).unwrap();
+        assert_abs_diff_eq!(drifted.ratio, 2.0, epsilon = 1e-12);
+        assert!(!drifted.agree);
+        assert!(check_assigned_value_agreement(9.0, 0.3, 10.0, 0.4, 2.0).unwrap().agree);
+        
+        assert!(matches!(
+            check_assigned_value_agreement(1.0, 0.0, 1.0, 0.0, 1.0),
+            Err(CalculationError::DivisionByZero)
+        ));
+        assert!(check_assigned_value_agreement(1.0, -0.1, 1.0, 0.1, 1.0).is_err());
+        assert!(check_assigned_value_agreement(1.0, 0.1, 1.0, 0.1, 0.0).is_err());
+    }
+
+    #[test]
+    fn test_monte_carlo_uncertainty_additive_model() {
+        // GUM S1 §9.2.2: Y = X1 + X2 + X3 + X4 with X_i ~ N(0, 1), so u(y) = 2
+        // and the 95 % interval is [-3.92, 3.92]
+        let inputs = [(0.0, 1.0); 4];
+        let sum_model = |draws: &[Array1<f64>]| -> Result<Array1<f64>, CalculationError> {
+            Ok(draws.iter().fold(Array1::zeros(draws[0].len()), |acc, x| acc + x))
+        };
+        
+        let result = monte_carlo_uncertainty(&inputs, sum_model, 200_000, 1).unwrap();
+        assert_abs_diff_eq!(result.mean, 0.0, epsilon = 0.02);
+        assert_abs_diff_eq!(result.std_dev, 2.0, epsilon = 0.02);
+        assert_abs_diff_eq!(result.lower, -3.92, epsilon = 0.05);
+        assert_abs_diff_eq!(result.upper, 3.92, epsilon = 0.05);
+        
+        // Same seed, same result
+        let again = monte_carlo_uncertainty(&inputs, sum_model, 200_000, 1).unwrap();
+        assert_eq!(again, result);
+    }
+
+    #[test]
+    fn test_monte_carlo_uncertainty_batches() {
+        // More draws than one batch: every draw is passed to the model exactly once
+        let mut evaluated = 0;
+        let identity = |draws: &[Array1<f64>]| -> Result<Array1<f64>, CalculationError> {
+            evaluated += draws[0].len();
+            Ok(draws[0].clone())
+        };
+        
+        let result = monte_carlo_uncertainty(&[(5.0, 0.0)], identity, MONTE_CARLO_BATCH_SIZE + 7, 3).unwrap();
+        assert_eq!(evaluated, MONTE_CARLO_BATCH_SIZE + 7);
+        assert_eq!(result.std_dev, 0.0);
+        assert_eq!(result.lower, 5.0);
+    }
+
+    #[test]
+    fn test_monte_carlo_uncertainty_invalid() {
+        let identity = |draws: &[Array1<f64>]| -> Result<Array1<f64>, CalculationError> { Ok(draws[0].clone()) };
+        assert!(monte_carlo_uncertainty(&[], identity, 100, 0).is_err());
+        assert!(monte_carlo_uncertainty(&[(1.0, -0.1)], identity, 100, 0).is_err());
+        assert!(monte_carlo_uncertainty(&[(1.0, 0.1)], identity, 1, 0).is_err());
+        
+        let short = |_: &[Array1<f64>]| -> Result<Array1<f64>, CalculationError> { Ok(array![1.0]) };
+        assert!(matches!(
+            monte_carlo_uncertainty(&[(1.0, 0.1)], short, 100, 0),
+            Err(CalculationError::DimensionMismatch { .. })
+        ));
+        
+        let log = |draws: &[Array1<f64>]| -> Result<Array1<f64>, CalculationError> { Ok(draws[0].mapv(f64::ln)) };
+        assert!(monte_carlo_uncertainty(&[(0.0, 1.0)], log, 100, 0).is_err());
+    }
+
+    #[test]
+    fn test_coverage_interval_consensus() {
+        let small = coverage_interval_consensus(10.0, 1.0, 6, 0.95).unwrap();
+        let large = coverage_interval_consensus(10.0, 1.0, 60, 0.95).unwrap();
+        
+        assert_abs_diff_eq!(small.coverage_factor, 2.570581836, epsilon = 1e-8);
+        assert_abs_diff_eq!(large.coverage_factor, 2.000995378, epsilon = 1e-8);
+        
+        let u_small = 1.25 / 6.0_f64.sqrt();
+        assert_abs_diff_eq!(small.upper - 10.0, small.coverage_factor * u_small, epsilon = 1e-12);
+        assert_abs_diff_eq!(10.0 - small.lower, small.upper - 10.0, epsilon = 1e-12);
+        
+        // Few participants widen the interval beyond k = 2 relative to u(x_pt)
+        assert!(small.coverage_factor > large.coverage_factor);
+        assert!(small.upper - small.lower > 2.0 * 2.0 * u_small);
+    }
+
+    #[test]
+    fn test_coverage_interval_consensus_invalid() {
+        assert!(matches!(
+            coverage_interval_consensus(10.0, 1.0, 1, 0.95),
+            Err(CalculationError::InsufficientData { required: 2, actual: 1 })
+        ));
+        assert!(coverage_interval_consensus(10.0, 1.0, 6, 1.0).is_err());
+        assert!(coverage_interval_consensus(10.0, -1.0, 6, 0.95).is_err());
+    }
+
+    #[test]
+    fn test_dark_uncertainty_consistent() {
+        let results = array![10.1, 9.9, 10.05, 9.95, 10.0];
+        let uncertainties = array![0.2, 0.2, 0.2, 0.2, 0.2];
+        let dark = dark_uncertainty(results.view(), uncertainties.view(), 10.0).unwrap();
+        
+        // Σ r² / u² = (0.01 + 0.01 + 0.0025 + 0.0025) / 0.04 = 0.625 over 4 dof
+        assert!(dark.consistent);
+        assert_eq!(dark.dark_uncertainty, 0.0);
+        assert_eq!(dark.fraction_of_variance, 0.0);
+        assert_abs_diff_eq!(dark.reduced_chi_square_before, 0.625 / 4.0, epsilon = 1e-12);
+        assert_eq!(dark.reduced_chi_square_after, dark.reduced_chi_square_before);
+    }
+
+    #[test]
+    fn test_dark_uncertainty_inflated_scatter() {
+        let results = array![11.0, 9.0, 10.5, 9.5, 10.0];
+        let uncertainties = array![0.1, 0.1, 0.1, 0.1, 0.1];
+        let dark = dark_uncertainty(results.view(), uncertainties.view(), 10.0).unwrap();
+        
+        // Equal u_i: Σ r² / (u² + τ²) = 4 gives τ² = 2.5 / 4 - 0.01
+        assert!(!dark.consistent);
+        assert_abs_diff_eq!(dark.dark_uncertainty, 0.615_f64.sqrt(), epsilon = 1e-9);
+        assert_abs_diff_eq!(dark.reduced_chi_square_before, 2.5 / 0.01 / 4.0, epsilon = 1e-9);
+        assert_abs_diff_eq!(dark.reduced_chi_square_after, 1.0, epsilon = 1e-9);
+        assert_abs_diff_eq!(dark.fraction_of_variance, 0.615 / 0.625, epsilon = 1e-9);
+        
+        // Unequal uncertainties still bring the reduced chi-square to 1
+        let uncertainties = array![0.05, 0.3, 0.1, 0.2, 0.1];
+        let dark = dark_uncertainty(results.view(), uncertainties.view(), 10.0).unwrap();
+        assert_abs_diff_eq!(dark.reduced_chi_square_after, 1.0, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn test_dark_uncertainty_invalid() {
+        let results = array![10.0, 11.0];
+        assert!(dark_uncertainty(results.view(), array![0.1].view(), 10.0).is_err());
+        assert!(dark_uncertainty(results.view(), array![0.1, 0.0].view(), 10.0).is_err());
+        assert!(dark_uncertainty(results.view(), array![0.1, 0.1].view(), f64::NAN).is_err());
+        assert!(matches!(
+            dark_uncertainty(array![10.0].view(), array![0.1].view(), 10.0),
+            Err(CalculationError::InsufficientData { required: 2, actual: 1 })
+        ));
+    }
+
+    #[test]
+    fn test_uncertainty_of_s_star() {
+        let u_25 = uncertainty_of_s_star(2.0, 25, false).unwrap();
+        let u_100 = uncertainty_of_s_star(2.0, 100, false).unwrap();
+        
+        assert_abs_diff_eq!(u_25, S_STAR_UNCERTAINTY_FACTOR * 2.0 / 5.0, epsilon = 1e-12);
+        // Four times as many participants halves the uncertainty
+        assert_abs_diff_eq!(u_25 / u_100, 2.0, epsilon = 1e-12);
+        
+        // Classical: s / sqrt(2(p - 1)) = 2 / sqrt(48)
+        let classical = uncertainty_of_s_star(2.0, 25, true).unwrap();
+        assert_abs_diff_eq!(classical, 2.0 / 48.0_f64.sqrt(), epsilon = 1e-12);
+        
+        assert_eq!(uncertainty_of_s_star(0.0, 10, false).unwrap(), 0.0);
+    }
+
+    #[test]
+    fn test_uncertainty_of_s_star_invalid() {
+        assert!(uncertainty_of_s_star(-1.0, 10, false).is_err());
+        assert!(uncertainty_of_s_star(f64::NAN, 10, true).is_err());
+        assert!(matches!(
+            uncertainty_of_s_star(1.0, 1, true),
+            Err(CalculationError::InsufficientData { required: 2, actual: 1 })
+        ));
+    }
+
+    #[test]
+    fn test_combine_reference_and_consensus_agreeing() {
+        let combined = combine_reference_and_consensus(10.0, 0.1, 10.05, 0.2).unwrap();
+        
+        // Weights 100 and 25: x_pt = (1000 + 251.25) / 125
+        assert_abs_diff_eq!(combined.x_pt, 10.01, epsilon = 1e-12);
+        assert_abs_diff_eq!(combined.u_x_pt, 1.0 / 125.0_f64.sqrt(), epsilon = 1e-12);
+        assert_abs_diff_eq!(combined.birge_ratio, 0.05 / 0.05_f64.sqrt(), epsilon = 1e-12);
+        assert!(combined.consistent);
+    }
+
+    #[test]
+    fn test_combine_reference_and_consensus_discrepant() {
+        // 3 combined standard uncertainties apart
+        let combined = combine_reference_and_consensus(10.0, 0.3, 11.5, 0.4).unwrap();
+        
+        assert_abs_diff_eq!(combined.birge_ratio, 3.0, epsilon = 1e-12);
+        assert!(!combined.consistent);
+        assert_abs_diff_eq!(combined.u_x_pt, 3.0 * 0.24, epsilon = 1e-12);
+        assert!(combined.x_pt > 10.0 && combined.x_pt < 11.5);
+        
+        assert!(combine_reference_and_consensus(10.0, 0.0, 10.0, 0.1).is_err());
+        assert!(combine_reference_and_consensus(f64::NAN, 0.1, 10.0, 0.1).is_err());
+    }
+
+    #[test]
+    fn test_uncertainty_consensus_scale_method() {
+        let s_star = calculate_uncertainty_consensus(1.0, 25, false, ScaleKind::SStar).unwrap();
+        let qn = calculate_uncertainty_consensus(1.0, 25, false, ScaleKind::Qn).unwrap();
+        let niqr = calculate_uncertainty_consensus(1.0, 25, false, ScaleKind::NIQR).unwrap();
+        
+        assert_abs_diff_eq!(s_star, 0.25, epsilon = 1e-12);
+        assert_abs_diff_eq!(qn, 0.25, epsilon = 1e-12);
+        assert_abs_diff_eq!(niqr, MEDIAN_UNCERTAINTY_FACTOR / 5.0, epsilon = 1e-12);
+        
+        // The t-factor correction applies on top of the multiplier
+        let corrected = calculate_uncertainty_consensus(1.0, 25, true, ScaleKind::NIQR).unwrap();
+        let t_factor = t_quantile(ONE_SIGMA_UPPER_PROBABILITY, 24.0).unwrap();
+        assert_abs_diff_eq!(corrected, niqr * t_factor, epsilon = 1e-12);
+        
+        assert_eq!("qn".parse::<ScaleKind>().unwrap(), ScaleKind::Qn);
+    }
+
+    #[test]
+    fn test_relative_uncertainty() {
+        assert_abs_diff_eq!(relative_uncertainty(20.0, 0.5).unwrap(), 2.5, epsilon = 1e-12);
+        // Negative assigned values give the same positive percentage
+        assert_abs_diff_eq!(relative_uncertainty(-20.0, 0.5).unwrap(), 2.5, epsilon = 1e-12);
+        assert_eq!(relative_uncertainty(20.0, 0.0).unwrap(), 0.0);
+        
+        assert!(matches!(relative_uncertainty(0.0, 0.5), Err(CalculationError::DivisionByZero)));
+        assert!(relative_uncertainty(20.0, -0.5).is_err());
+        assert!(relative_uncertainty(f64::NAN, 0.5).is_err());
+    }
+
+    #[test]
+    fn test_expert_consensus_weighted() {
+        // Weights 100, 25 and 4
+        let (x_pt, u_x_pt) = calculate_expert_consensus_weighted(&[10.0, 10.5, 12.0], &[0.1, 0.2, 0.5]).unwrap();
+        assert_abs_diff_eq!(x_pt, (1000.0 + 262.5 + 48.0) / 129.0, epsilon = 1e-12);
+        assert_abs_diff_eq!(u_x_pt, 1.0 / 129.0_f64.sqrt(), epsilon = 1e-12);
+        
+        // Equal uncertainties give the plain mean and u / sqrt(p)
+        let (x_pt, u_x_pt) = calculate_expert_consensus_weighted(&[1.0, 2.0, 3.0, 4.0], &[0.2; 4]).unwrap();
+        assert_abs_diff_eq!(x_pt, 2.5, epsilon = 1e-12);
+        assert_abs_diff_eq!(u_x_pt, 0.1, epsilon = 1e-12);
+        
+        assert!(matches!(
+            calculate_expert_consensus_weighted(&[1.0, 2.0], &[0.1]),
+            Err(CalculationError::DimensionMismatch { .. })
+        ));
+        assert!(calculate_expert_consensus_weighted(&[1.0, 2.0], &[0.1, 0.0]).is_err());
+        assert!(calculate_expert_consensus_weighted(&[], &[]).is_err());
+    }
+
+    #[test]
+    fn test_effective_degrees_of_freedom_gum_h1() {
+        // GUM H.1 end-gauge calibration: contributions in nm with their dof
+        let components = [25.0, 9.7, 2.9, 16.6];
+        let dofs = [18.0, 25.6, 50.0, 2.0];
+        let result = effective_degrees_of_freedom(&components, &dofs).unwrap();
+        
+        // GUM H.1.6 quotes ν_eff = 16.7 (with u_c rounded to 32 nm) and uses ν = 16
+        let combined_variance: f64 = components.iter().map(|u| u * u).sum();
+        let expected = combined_variance.powi(2)
+            / (25.0_f64.powi(4) / 18.0 + 9.7_f64.powi(4) / 25.6 + 2.9_f64.powi(4) / 50.0 + 16.6_f64.powi(4) / 2.0);
+        assert_abs_diff_eq!(result.dof, expected, epsilon = 1e-9);
+        assert!(result.dof > 16.0 && result.dof < 17.0);
+        assert_abs_diff_eq!(result.coverage_factor, 2.119905299, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn test_effective_degrees_of_freedom_infinite() {
+        let result = effective_degrees_of_freedom(&[0.3, 0.4], &[f64::INFINITY, f64::INFINITY]).unwrap();
+        assert!(result.dof.is_infinite());
+        assert_abs_diff_eq!(result.coverage_factor, 1.959963985, epsilon = 1e-6);
+        
+        // A single component keeps its own dof
+        let result = effective_degrees_of_freedom(&[0.3, 0.4], &[9.0, f64::INFINITY]).unwrap();
+        assert_abs_diff_eq!(result.dof, 9.0 * 0.25_f64.powi(2) / 0.3_f64.powi(4), epsilon = 1e-9);
+        
+        assert!(effective_degrees_of_freedom(&[0.3], &[0.0]).is_err());
+        assert!(effective_degrees_of_freedom(&[0.3, 0.4], &[5.0]).is_err());
+        assert!(effective_degrees_of_freedom(&[0.0, 0.0], &[5.0, 5.0]).is_err());
+        assert!(effective_degrees_of_freedom(&[-0.3], &[5.0]).is_err());
+    }
+
+    #[test]
+    fn test_uncertainty_from_homogeneity() {
+        // Duplicate measurements: s_s² = 0.3² - 0.2² / 2 = 0.07
+        let result = uncertainty_from_homogeneity(0.3, 0.2, 2).unwrap();
+        assert_abs_diff_eq!(result.between_sample_variance, 0.07, epsilon = 1e-12);
+        assert_abs_diff_eq!(result.u_hom, 0.07_f64.sqrt(), epsilon = 1e-12);
+        assert!(!result.negative_variance);
+        
+        // More replicates remove more of the repeatability from s_x̄
+        let result = uncertainty_from_homogeneity(0.3, 0.2, 4).unwrap();
+        assert_abs_diff_eq!(result.u_hom, 0.08_f64.sqrt(), epsilon = 1e-12);
+        
+        // Annex B procedure on g = 10 duplicate pairs: s_x̄ is the standard deviation
+        // of the pair averages and s_w = sqrt(Σ w_t² / (2g)) with w_t the pair ranges.
+        // The one-way ANOVA mean squares give s_s² = (MS_between - MS_within) / m.
+        let pairs = [
+            (19.7, 19.5), (19.8, 19.9), (19.5, 19.6), (20.1, 19.9), (19.9, 20.0),
+            (19.6, 19.8), (20.0, 20.2), (19.8, 19.7), (19.9, 19.9), (20.2, 20.0),
+        ];
+        let g = pairs.len() as f64;
+        let averages: Vec<f64> = pairs.iter().map(|&(a, b)| 0.5 * (a + b)).collect();
+        let grand_mean = averages.iter().sum::<f64>() / g;
+        let s_x = (averages.iter().map(|&x| (x - grand_mean).powi(2)).sum::<f64>() / (g - 1.0)).sqrt();
+        let s_w = (pairs.iter().map(|&(a, b)| (a - b) * (a - b)).sum::<f64>() / (2.0 * g)).sqrt();
+        assert_abs_diff_eq!(s_x, 0.195_789_002_074_512, epsilon = 1e-12);
+        assert_abs_diff_eq!(s_w, 0.109_544_511_501_033, epsilon = 1e-12);
+        
+        let result = uncertainty_from_homogeneity(s_x, s_w, 2).unwrap();
+        assert_abs_diff_eq!(result.u_hom, 0.179_814_719_456_815, epsilon = 1e-12);
+        assert!(!result.negative_variance);
+    }
+
+    #[test]
+    fn test_uncertainty_from_homogeneity_negative_variance() {
+        let result = uncertainty_from_homogeneity(0.1, 0.2, 2).unwrap();
+        assert_abs_diff_eq!(result.between_sample_variance, -0.01, epsilon = 1e-12);
+        assert_eq!(result.u_hom, 0.0);
+        assert!(result.negative_variance);
+        
+        assert!(uncertainty_from_homogeneity(-0.1, 0.2, 2).is_err());
+        assert!(uncertainty_from_homogeneity(0.1, f64::NAN, 2).is_err());
+        assert!(matches!(
+            uncertainty_from_homogeneity(0.1, 0.2, 1),
+            Err(CalculationError::InsufficientData { required: 2, actual: 1 })
+        ));
+    }
+
+    #[test]
+    fn test_coverage_interval_construction() {
+        let interval = CoverageInterval::new(10.0, 0.25, 2.0, 0.95).unwrap();
+        assert_abs_diff_eq!(interval.lower, 9.5, epsilon = 1e-12);
+        assert_abs_diff_eq!(interval.upper, 10.5, epsilon = 1e-12);
+        assert_abs_diff_eq!(interval.expanded_uncertainty(), 0.5, epsilon = 1e-12);
+        
+        // Boundaries are inside the interval
+        assert!(interval.contains(10.0));
+        assert!(interval.contains(interval.lower));
+        assert!(interval.contains(interval.upper));
+        assert!(!interval.contains(10.5 + 1e-9));
+        assert!(!interval.contains(f64::NAN));
+        
+        assert!(CoverageInterval::new(10.0, 0.0, 1.0, 0.68).is_ok());
+        assert!(CoverageInterval::new(10.0, 0.25, 0.9, 0.95).is_err());
+        assert!(CoverageInterval::new(10.0, -0.25, 2.0, 0.95).is_err());
+        assert!(CoverageInterval::new(10.0, 0.25, 2.0, 1.0).is_err());
+        assert!(CoverageInterval::new(f64::INFINITY, 0.25, 2.0, 0.95).is_err());
+    }
+
+    #[test]
+    fn test_coverage_interval_serialization() {
+        let interval = CoverageInterval::new(10.0, 0.25, 2.0, 0.95).unwrap();
+        let json: serde_json::Value = serde_json::to_value(interval).unwrap();
+        
+        assert_eq!(json["value"], 10.0);
+        assert_eq!(json["standard_uncertainty"], 0.25);
+        assert_eq!(json["coverage_factor"], 2.0);
+        assert_eq!(json["lower"], 9.5);
+        assert_eq!(json["upper"], 10.5);
+        assert_eq!(json["confidence_level"], 0.95);
+        
+        let combined = combine_reference_and_consensus(10.0, 0.1, 10.05, 0.2).unwrap();
+        let interval = combined.coverage_interval(2.0, 0.95).unwrap();
+        assert_eq!(interval.value, combined.x_pt);
+        assert_eq!(interval.standard_uncertainty, combined.u_x_pt);
+    }
+
+    #[test]
+    fn test_screen_participant_uncertainties() {
+        let u_results = array![0.5, 0.0, 0.05, 0.1, 5.0, 5.01, 1e6, f64::NAN, f64::INFINITY];
+        let screening = screen_participant_uncertainties(
+            u_results.view(), 1.0, DEFAULT_UNCERTAINTY_LOW_FACTOR, DEFAULT_UNCERTAINTY_HIGH_FACTOR,
+        ).unwrap();
+        
+        use UncertaintyPlausibility::{Missing, TooLarge, TooSmall};
+        let ok = UncertaintyPlausibility::Ok;
+        assert_eq!(screening.flags, vec![ok, TooSmall, TooSmall, ok, ok, TooLarge, TooLarge, Missing, TooLarge]);
+        assert_eq!(
+            (screening.n_ok, screening.n_too_small, screening.n_too_large, screening.n_missing),
+            (3, 2, 3, 1)
+        );
+        
+        assert!(screen_participant_uncertainties(array![-0.1].view(), 1.0, 0.1, 5.0).is_err());
+        assert!(screen_participant_uncertainties(u_results.view(), 0.0, 0.1, 5.0).is_err());
+        assert!(screen_participant_uncertainties(u_results.view(), 1.0, 5.0, 0.1).is_err());
+        assert!(screen_participant_uncertainties(u_results.view(), 1.0, 0.0, 5.0).is_err());
+    }
+
+    #[test]
+    fn test_uncertainty_consensus_weighted() {
+        let weights = [1.0; 10];
+        let result = calculate_uncertainty_consensus_weighted(2.0, &weights).unwrap();
+        let expected = calculate_uncertainty_consensus(2.0, 10, false, ScaleKind::SStar).unwrap();
+        assert_abs_diff_eq!(result.classical, expected, epsilon = 1e-12);
+        assert_abs_diff_eq!(result.weighted, expected, epsilon = 1e-12);
+        assert_abs_diff_eq!(result.p_eff, 10.0, epsilon = 1e-12);
+        
+        // Half of the laboratories down-weighted to 0.1: p_eff = 5.5² / 5.05
+        let weights = [1.0, 1.0, 1.0, 1.0, 1.0, 0.1, 0.1, 0.1, 0.1, 0.1];
+        let result = calculate_uncertainty_consensus_weighted(2.0, &weights).unwrap();
+        assert_abs_diff_eq!(result.p_eff, 30.25 / 5.05, epsilon = 1e-12);
+        assert_abs_diff_eq!(result.classical, expected, epsilon = 1e-12);
+        assert!(result.weighted > 1.25 * result.classical);
+        
+        assert!(calculate_uncertainty_consensus_weighted(2.0, &[]).is_err());
+        assert!(calculate_uncertainty_consensus_weighted(-1.0, &weights).is_err());
+        assert!(calculate_uncertainty_consensus_weighted(2.0, &[1.0, -1.0]).is_err());
+    }
+
+    #[test]
+    fn test_apply_recovery_correction() {
+        // 2 % on the result and 4 % on a recovery of 0.8 combine to sqrt(0.02^2 + 0.05^2)
+        let corrected = apply_recovery_correction(40.0, 0.8, 0.8, 0.04).unwrap();
+        assert_abs_diff_eq!(corrected.value, 50.0, epsilon = 1e-12);
+        assert_abs_diff_eq!(corrected.relative_uncertainty, 0.0029_f64.sqrt(), epsilon = 1e-12);
+        assert_abs_diff_eq!(corrected.standard_uncertainty, 50.0 * 0.0029_f64.sqrt(), epsilon = 1e-12);
+        
+        // An exactly known recovery passes the relative uncertainty through
+        let corrected = apply_recovery_correction(-10.0, 0.5, 0.5, 0.0).unwrap();
+        assert_abs_diff_eq!(corrected.value, -20.0, epsilon = 1e-12);
+        assert_abs_diff_eq!(corrected.relative_uncertainty, 0.05, epsilon = 1e-12);
+        assert_abs_diff_eq!(corrected.standard_uncertainty, 1.0, epsilon = 1e-12);
+        
+        // A zero result keeps an absolute uncertainty of u(x) / R
+        let corrected = apply_recovery_correction(0.0, 0.1, 0.8, 0.01).unwrap();
+        assert_eq!(corrected.value, 0.0);
+        assert_abs_diff_eq!(corrected.standard_uncertainty, 0.125, epsilon = 1e-12);
+        assert!(corrected.relative_uncertainty.is_nan());
+        
+        assert!(apply_recovery_correction(10.0, 0.1, 0.0, 0.01).is_err());
+        assert!(apply_recovery_correction(10.0, 0.1, f64::INFINITY, 0.01).is_err());
+        assert!(apply_recovery_correction(10.0, -0.1, 0.9, 0.01).is_err());
+        assert!(apply_recovery_correction(10.0, 0.1, 0.9, f64::NAN).is_err());
+    }
+
+    #[test]
+    fn test_assigned_value_expanded() {
+        let (x_pt, expanded, lower, upper) = assigned_value_expanded(12.5, 0.3, 2.0).unwrap();
+        assert_eq!(x_pt, 12.5);
+        assert_abs_diff_eq!(expanded, 0.6, epsilon = 1e-12);
+        assert_abs_diff_eq!(lower, 11.9, epsilon = 1e-12);
+        assert_abs_diff_eq!(upper, 13.1, epsilon = 1e-12);
+        
+        assert_eq!(assigned_value_expanded(-1.0, 0.0, 2.0).unwrap(), (-1.0, 0.0, -1.0, -1.0));
+        assert!(assigned_value_expanded(12.5, 0.3, 0.5).is_ok());
+        assert!(assigned_value_expanded(12.5, 0.3, 0.0).is_err());
+        assert!(assigned_value_expanded(12.5, -0.3, 2.0).is_err());
+        assert!(assigned_value_expanded(f64::NAN, 0.3, 2.0).is_err());
+    }
+}
@@ -5,6 +5,9 @@
 
 use thiserror::Error;
 use pyo3::prelude::*;
+use serde::Serialize;
+use std::fmt;
+use std::str::FromStr;
 
 /// Custom error type for calculation failures in the Rust engine.
 #[derive(Error, Debug)]
@@ -21,8 +24,8 @@ pub enum CalculationError {
     #[error("Insufficient data: need at least {required} data points, got {actual}")]
     InsufficientData { required: usize, actual: usize },
     
-    #[error("Array dimension mismatch: expected {expected}, got {actual}")]
-    DimensionMismatch { expected: usize, actual: usize },
+    #[error("Array dimension mismatch: {name1} has {len1} elements but {name2} has {len2}")]
+    DimensionMismatch { name1: String, len1: usize, name2: String, len2: usize },
     
     #[error("Mathematical error: {message}")]
     MathematicalError { message: String },
@@ -59,46 +62,226 @@ impl From<CalculationError> for PyErr {
     }
 }
 
+/// Non-fatal condition noticed during a calculation
+/// 
+/// Attached to results where the calculation went ahead after adjusting
+/// something or where the result deserves a second look, so callers can
+/// report the condition alongside the values instead of it passing silently.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "code", rename_all = "snake_case")]
+pub enum CalculationWarning {
+    /// The robust scale fell below the minimum and was floored to it
+    ScaleFloored { minimum: f64 },
+    /// Many results equal the median, so the MAD-based initial scale is unreliable
+    HeavyTies { tied: usize, total: usize },
+    /// The iteration converged using most of its iteration budget
+    SlowConvergence { iterations: usize, max_iterations: usize },
+    /// An unusually large share of results was down-weighted
+    ManyDownWeighted { down_weighted: usize, total: usize },
+}
+
+impl CalculationWarning {
+    /// Stable snake_case code identifying the kind of warning
+    pub fn code(&self) -> &'static str {
+        match self {
+            CalculationWarning::ScaleFloored { .. } => "scale_floored",
+            CalculationWarning::HeavyTies { .. } => "heavy_ties",
+            CalculationWarning::SlowConvergence { .. } => "slow_convergence",
+            CalculationWarning::ManyDownWeighted { .. } => "many_down_weighted",
+        }
+    }
+}
+
+impl fmt::Display for CalculationWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CalculationWarning::ScaleFloored { minimum } => {
+                write!(f, "Robust scale fell below {:e} and was floored to it", minimum)
+            }
+            CalculationWarning::HeavyTies { tied, total } => {
+                write!(f, "{} of {} results equal the median", tied, total)
+            }
+            CalculationWarning::SlowConvergence { iterations, max_iterations } => {
+                write!(f, "Converged after {} of at most {} iterations", iterations, max_iterations)
+            }
+            CalculationWarning::ManyDownWeighted { down_weighted, total } => {
+                write!(f, "{} of {} results were down-weighted", down_weighted, total)
+            }
+        }
+    }
+}
+
 /// Mathematical constants used in robust statistics calculations
 pub mod constants {
     /// Scaling factor for converting MAD (Median Absolute Deviation) to standard deviation estimate
     /// This is approximately 1.4826 = 1/Φ^(-1)(3/4) where Φ^(-1) is the inverse normal CDF
     pub const MAD_TO_SIGMA: f64 = 1.4826;
     
+    /// Consistency constant of the Rousseeuw-Croux Sn estimator for the normal distribution
+    pub const SN_CONSISTENCY: f64 = 1.1926;
+    
     /// Default tolerance for iterative Algorithm A convergence
     pub const DEFAULT_TOLERANCE: f64 = 1e-6;
     
     /// Default maximum iterations for Algorithm A
     pub const DEFAULT_MAX_ITERATIONS: usize = 100;
     
+    /// Maximum number of bisection steps when solving for a root in a bracket
+    /// (bisection also stops early once the bracket cannot be halved further)
+    pub const MAX_BISECTION_STEPS: usize = 200;
+    
     /// Minimum number of participants required for Algorithm A
     pub const MIN_PARTICIPANTS_ALGORITHM_A: usize = 5;
     
     /// Factor for calculating uncertainty from robust standard deviation
     /// u(x_pt) = 1.25 * s* / sqrt(p) for consensus values
     pub const UNCERTAINTY_FACTOR: f64 = 1.25;
+    
+    /// Ratio sqrt(π/2) of the standard error of the median to σ/sqrt(p) for normal data
+    pub const MEDIAN_UNCERTAINTY_FACTOR: f64 = 1.253_314_137_315_500_3;
+    
+    /// Asymptotic standard deviation of s* relative to s*/sqrt(p) for normal data
+    /// (Huber proposal 2 scale with c = 1.5, about 73 % efficient against the
+    /// classical standard deviation, whose factor is 1/sqrt(2) ≈ 0.707)
+    pub const S_STAR_UNCERTAINTY_FACTOR: f64 = 0.83;
+    
+    /// Upper-tail probability Φ(1) giving 68.27 % two-sided coverage
+    pub const ONE_SIGMA_UPPER_PROBABILITY: f64 = 0.841_344_746_068_543;
+    
+    /// Number of Monte Carlo draws passed to the model in one evaluation
+    pub const MONTE_CARLO_BATCH_SIZE: usize = 10_000;
+    
+    /// Array length from which element-wise scoring runs in parallel
+    pub const PARALLEL_THRESHOLD: usize = 100_000;
+    
+    /// Default multiplier of the interquartile range for Tukey fences
+    pub const DEFAULT_TUKEY_K: f64 = 1.5;
+    
+    /// Default distance from a whole decade (log10 scale) for magnitude error screening
+    pub const DEFAULT_MAGNITUDE_TOLERANCE: f64 = 0.1;
+    
+    /// Default largest acceptable |zeta - k·En| when cross-checking scores
+    pub const DEFAULT_CROSS_CHECK_TOLERANCE: f64 = 0.1;
+    
+    /// Largest u(x_pt) / sigma_pt for which u(x_pt) is negligible (ISO 13528 §9.2.1)
+    pub const NEGLIGIBLE_UNCERTAINTY_RATIO: f64 = 0.3;
+    
+    /// Largest u(x_pt) / sigma_pt for which z'-scores are still recommended
+    pub const Z_PRIME_MAX_UNCERTAINTY_RATIO: f64 = 0.7;
+    
+    /// Multiple of the reporting resolution below which s* is resolution-limited
+    pub const RESOLUTION_LIMITED_RATIO: f64 = 2.0;
+    
+    /// Default multiple of s* below which a stated u(x_i) is implausibly small
+    pub const DEFAULT_UNCERTAINTY_LOW_FACTOR: f64 = 0.1;
+    
+    /// Default multiple of s* above which a stated u(x_i) is implausibly large
+    pub const DEFAULT_UNCERTAINTY_HIGH_FACTOR: f64 = 5.0;
+    
+    /// Number of invalid entries listed in a validation error before truncating
+    pub const MAX_REPORTED_INVALID_VALUES: usize = 10;
+    
+    /// Minimum robust scale; smaller estimates are floored to this value
+    pub const MIN_ROBUST_SCALE: f64 = 1e-10;
+    
+    /// Share of identical results above which a heavy-ties warning is raised
+    pub const HEAVY_TIES_FRACTION: f64 = 0.25;
+    
+    /// Share of the iteration budget above which a slow-convergence warning is raised
+    pub const SLOW_CONVERGENCE_FRACTION: f64 = 0.8;
+    
+    /// Share of down-weighted results above which a warning is raised
+    /// (about twice the 13 % expected for normal data with Huber c = 1.5)
+    pub const MANY_DOWN_WEIGHTED_FRACTION: f64 = 0.25;
 }
 
 /// Helper function to calculate the median of a slice of f64 values
 /// Returns None if the slice is empty
+/// 
+/// Callers are expected to validate the data first. The middle element is found
+/// by selection (O(n) on average) instead of a full sort, so the slice is left
+/// partially reordered. Ordering uses `f64::total_cmp`, so any NaN that slips
+/// through is placed deterministically (positive NaN after +inf, negative NaN
+/// before -inf) instead of corrupting the selection. Use `median_of` to keep
+/// the input intact.
 pub fn median(data: &mut [f64]) -> Option<f64> {
     if data.is_empty() {
         return None;
     }
     
-    data.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
     let len = data.len();
+    let (lower_half, &mut upper_middle, _) = data.select_nth_unstable_by(len / 2, |a, b| a.total_cmp(b));
     
-    if len % 2 == 0 {
-        Some((data[len / 2 - 1] + data[len / 2]) / 2.0)
+    if len.is_multiple_of(2) {
+        // The lower middle element is the largest of the lower half
+        let lower_middle = *lower_half.iter().max_by(|a, b| a.total_cmp(b)).unwrap();
+        Some((lower_middle + upper_middle) / 2.0)
     } else {
-        Some(data[len / 2])
+        Some(upper_middle)
+    }
+}
+
+/// Median of `data` without modifying it, using `scratch` as working buffer
+/// 
+/// `scratch` is cleared and refilled, so repeated calls reuse its allocation.
+pub fn median_with_scratch(data: &[f64], scratch: &mut Vec<f64>) -> Option<f64> {
+    scratch.clear();
+    scratch.extend_from_slice(data);
+    median(scratch)
+}
+
+/// Median of `data` without modifying it
+/// 
+/// Prefer this over `median` unless the slice is a scratch buffer whose order
+/// does not matter.
+pub fn median_of(data: &[f64]) -> Option<f64> {
+    median_with_scratch(data, &mut Vec::with_capacity(data.len()))
+}
+
+/// Median and MAD of the values in `buffer`, which is overwritten
+/// 
+/// The buffer is reused for the absolute deviations, which works because the
+/// median does not depend on the order of the values.
+pub(crate) fn median_and_mad_in_place(buffer: &mut [f64]) -> Option<(f64, f64)> {
+    let median_value = median(buffer)?;
+    for value in buffer.iter_mut() {
+        *value = (*value - median_value).abs();
+    }
+    Some((median_value, median(buffer)?))
+}
+
+/// Median absolute deviation of `data` about its own median, without modifying `data`
+/// 
+/// Returns the raw MAD; scale it by MAD_TO_SIGMA for a standard deviation estimate.
+pub fn mad_of(data: &[f64]) -> Result<f64, CalculationError> {
+    if data.is_empty() {
+        return Err(CalculationError::InsufficientData {
+            required: 1,
+            actual: 0,
+        });
+    }
+    
+    Ok(median_and_mad_in_place(&mut data.to_vec()).unwrap().1)
+}
+
+/// Check a MAD-to-sigma consistency constant (MAD_TO_SIGMA for normal data)
+pub fn validate_mad_consistency(mad_consistency: f64) -> Result<(), CalculationError> {
+    if !is_valid_float(mad_consistency) || mad_consistency <= 0.0 {
+        return Err(CalculationError::InvalidInput {
+            message: format!("Invalid or non-positive MAD consistency constant: {}", mad_consistency),
+        });
     }
+    Ok(())
 }
 
 /// Helper function to calculate the Median Absolute Deviation (MAD)
 /// Returns the MAD value, which needs to be scaled by MAD_TO_SIGMA to get a standard deviation estimate
 pub fn mad(data: &[f64], median_value: f64) -> Result<f64, CalculationError> {
+    mad_with_scratch(data, median_value, &mut Vec::with_capacity(data.len()))
+}
+
+/// Same as `mad`, holding the absolute deviations in `scratch` instead of a new vector
+pub fn mad_with_scratch(data: &[f64], median_value: f64, scratch: &mut Vec<f64>) -> Result<f64, CalculationError> {
     if data.is_empty() {
         return Err(CalculationError::InsufficientData {
             required: 1,
@@ -106,15 +289,475 @@ pub fn mad(data: &[f64], median_value: f64) -> Result<f64, CalculationError> {
         });
     }
     
-    let mut abs_deviations: Vec<f64> = data.iter()
-        .map(|&x| (x - median_value).abs())
-        .collect();
+    scratch.clear();
+    scratch.extend(data.iter().map(|&x| (x - median_value).abs()));
     
-    median(&mut abs_deviations).ok_or_else(|| CalculationError::InternalError {
+    median(scratch).ok_or_else(|| CalculationError::InternalError {
         message: "Failed to calculate median of absolute deviations".to_string(),
     })
 }
 
+/// Frequencies of the terminal digits of reported results
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DigitDistribution {
+    /// Number of results ending in each digit 0 to 9
+    pub counts: [usize; 10],
+    /// Pearson chi-squared statistic against equal frequencies (9 degrees of freedom)
+    pub chi_square: f64,
+    /// Probability of a statistic at least this large if all digits were equally likely
+    pub p_value: f64,
+}
+
+/// Count the terminal digits of results reported to `decimals` decimal places
+/// 
+/// The terminal digit is the last digit of round(|x| · 10^decimals), so 12.35
+/// with `decimals` = 2 ends in 5. Participants who round to 0 and 5 by
+/// preference give a large chi-squared statistic against uniform digits and a
+/// small p-value. The test needs enough results to be meaningful (expected
+/// counts of at least 5, i.e. 50 results).
+/// 
+/// # Returns
+/// * `Ok(DigitDistribution)` - Digit counts, chi-squared statistic and p-value
+/// * `Err(CalculationError)` - If there are no results, a result is not finite,
+///   `decimals` exceeds 15 or |x| · 10^decimals exceeds 2^53, where the terminal
+///   digit is no longer represented exactly
+pub fn terminal_digit_distribution(data: &[f64], decimals: u32) -> Result<DigitDistribution, CalculationError> {
+    if data.is_empty() {
+        return Err(CalculationError::InsufficientData {
+            required: 1,
+            actual: 0,
+        });
+    }
+    
+    validate_floats(data, "results")?;
+    
+    // Beyond 15 decimals f64 cannot represent the digit reliably
+    if decimals > 15 {
+        return Err(CalculationError::InvalidInput {
+            message: format!("Number of decimals must be at most 15, got {}", decimals),
+        });
+    }
+    
+    // Largest integer up to which every f64 integer is exact (2^53)
+    const MAX_EXACT_INTEGER: f64 = 9_007_199_254_740_992.0;
+    
+    let scale = 10f64.powi(decimals as i32);
+    let mut counts = [0usize; 10];
+    for &x in data {
+        let scaled = (x.abs() * scale).round();
+        if scaled > MAX_EXACT_INTEGER {
+            return Err(CalculationError::InvalidInput {
+                message: format!("Terminal digit of {} is not representable at {} decimals", x, decimals),
+            });
+        }
+        counts[(scaled % 10.0) as usize] += 1;
+    }
+    
+    let expected = data.len() as f64 / 10.0;
+    let chi_square = counts.iter()
+        .map(|&observed| (observed as f64 - expected).powi(2) / expected)
+        .sum::<f64>();
+    
+    Ok(DigitDistribution {
+        counts,
+        chi_square,
+        p_value: 1.0 - chi_squared_cdf(chi_square, 9.0),
+    })
+}
+
+/// Central moments m2, m3 and m4 (divided by n) of data with at least `min_len` values
+fn central_moments(data: &[f64], min_len: usize) -> Result<(f64, f64, f64), CalculationError> {
+    if data.len() < min_len {
+        return Err(CalculationError::InsufficientData {
+            required: min_len,
+            actual: data.len(),
+        });
+    }
+    
+    validate_floats(data, "data")?;
+    
+    let n = data.len() as f64;
+    let mean = data.iter().sum::<f64>() / n;
+    let (m2, m3, m4) = data.iter().fold((0.0, 0.0, 0.0), |(m2, m3, m4), &x| {
+        let d = x - mean;
+        let d2 = d * d;
+        (m2 + d2, m3 + d2 * d, m4 + d2 * d2)
+    });
+    
+    if m2 == 0.0 {
+        return Err(CalculationError::DivisionByZero);
+    }
+    
+    Ok((m2 / n, m3 / n, m4 / n))
+}
+
+/// Sample skewness with the small-sample bias correction
+/// 
+/// G1 = sqrt(n(n - 1)) / (n - 2) · m3 / m2^(3/2), as in Excel's SKEW and
+/// `scipy.stats.skew(bias=False)`.
+/// 
+/// # Returns
+/// * `Ok(f64)` - The adjusted skewness
+/// * `Err(CalculationError)` - If there are fewer than 3 values, a value is not finite
+///   or all values are equal (`DivisionByZero`)
+pub fn skewness(data: &[f64]) -> Result<f64, CalculationError> {
+    let (m2, m3, _) = central_moments(data, 3)?;
+    let n = data.len() as f64;
+    Ok((n * (n - 1.0)).sqrt() / (n - 2.0) * m3 / m2.powf(1.5))
+}
+
+/// Sample excess kurtosis with the small-sample bias correction
+/// 
+/// G2 = (n - 1) / ((n - 2)(n - 3)) · ((n + 1) · g2 + 6) with g2 = m4 / m2² - 3,
+/// as in Excel's KURT and `scipy.stats.kurtosis(bias=False)`; 0 for normal data.
+/// 
+/// # Returns
+/// * `Ok(f64)` - The adjusted excess kurtosis
+/// * `Err(CalculationError)` - If there are fewer than 4 values, a value is not finite
+///   or all values are equal (`DivisionByZero`)
+pub fn kurtosis_excess(data: &[f64]) -> Result<f64, CalculationError> {
+    let (m2, _, m4) = central_moments(data, 4)?;
+    let n = data.len() as f64;
+    let g2 = m4 / (m2 * m2) - 3.0;
+    Ok((n - 1.0) / ((n - 2.0) * (n - 3.0)) * ((n + 1.0) * g2 + 6.0))
+}
+
+/// Medcouple, a robust measure of skewness in [-1, 1]
+/// 
+/// The median of h(x_i, x_j) = ((x_j - m) - (m - x_i)) / (x_j - x_i) over all
+/// pairs x_i <= m <= x_j, with m the median. Pairs of values tied at the median
+/// take -1, 0 or +1 as in Brys, Hubert and Struyf (2004). Its breakdown point
+/// is 25 %, so unlike the classical skewness it is not driven by one outlier.
+/// This evaluates the O(n²) definition; the O(n log n) algorithm of the same
+/// paper is only worthwhile for far larger data sets than a round produces.
+/// 
+/// # Returns
+/// * `Ok(f64)` - The medcouple
+/// * `Err(CalculationError)` - If there are fewer than 3 values or a value is not finite
+pub fn medcouple(data: &[f64]) -> Result<f64, CalculationError> {
+    if data.len() < 3 {
+        return Err(CalculationError::InsufficientData {
+            required: 3,
+            actual: data.len(),
+        });
+    }
+    
+    validate_floats(data, "data")?;
+    
+    let median_value = median_of(data).unwrap();
+    let lower: Vec<f64> = data.iter().copied().filter(|&x| x < median_value).collect();
+    let upper: Vec<f64> = data.iter().copied().filter(|&x| x > median_value).collect();
+    let tied = data.len() - lower.len() - upper.len();
+    
+    let mut kernel = Vec::with_capacity((lower.len() + tied) * (upper.len() + tied));
+    for &x_j in &upper {
+        for &x_i in &lower {
+            kernel.push(((x_j - median_value) - (median_value - x_i)) / (x_j - x_i));
+        }
+        // x_i = m < x_j
+        kernel.extend(std::iter::repeat_n(1.0, tied));
+    }
+    // x_i < m = x_j
+    kernel.extend(std::iter::repeat_n(-1.0, tied * lower.len()));
+    // Both tied at the median: sign(tied - 1 - i - j)
+    for i in 0..tied {
+        for j in 0..tied {
+            kernel.push(match (i + j + 1).cmp(&tied) {
+                std::cmp::Ordering::Less => 1.0,
+                std::cmp::Ordering::Equal => 0.0,
+                std::cmp::Ordering::Greater => -1.0,
+            });
+        }
+    }
+    
+    Ok(median(&mut kernel).unwrap())
+}
+
+/// Rousseeuw-Croux Sn scale estimator
+/// 
+/// Sn = c_n · 1.1926 · lomed_i himed_j |x_i - x_j|, where himed and lomed are
+/// the high and low medians. Like Qn it needs no location estimate, and it has
+/// a 50 % breakdown point: up to half of the data can be replaced by arbitrary
+/// values without Sn becoming unbounded. The small-sample factor c_n makes it
+/// unbiased at the normal distribution (the published table for n <= 9,
+/// n / (n - 0.9) for larger odd n, 1 for larger even n). The straightforward
+/// O(n² log n) evaluation is used, which is fast enough for proficiency test rounds.
+/// 
+/// # Returns
+/// * `Ok(f64)` - The Sn estimate of the standard deviation
+/// * `Err(CalculationError)` - If there are fewer than 2 values or a value is not finite
+pub fn sn_scale(data: &[f64]) -> Result<f64, CalculationError> {
+    let n = data.len();
+    if n < 2 {
+        return Err(CalculationError::InsufficientData {
+            required: 2,
+            actual: n,
+        });
+    }
+    
+    validate_floats(data, "data")?;
+    
+    let mut distances = vec![0.0; n];
+    let mut inner: Vec<f64> = data.iter()
+        .map(|&x_i| {
+            for (distance, &x_j) in distances.iter_mut().zip(data.iter()) {
+                *distance = (x_i - x_j).abs();
+            }
+            // High median: the (n / 2 + 1)-th smallest, 1-based
+            *distances.select_nth_unstable_by(n / 2, |a, b| a.total_cmp(b)).1
+        })
+        .collect();
+    
+    // Low median: the ((n + 1) / 2)-th smallest, 1-based
+    let outer = *inner.select_nth_unstable_by(n.div_ceil(2) - 1, |a, b| a.total_cmp(b)).1;
+    
+    let correction = match n {
+        2 => 0.743,
+        3 => 1.851,
+        4 => 0.954,
+        5 => 1.351,
+        6 => 0.993,
+        7 => 1.198,
+        8 => 1.005,
+        9 => 1.131,
+        _ if n % 2 == 1 => n as f64 / (n as f64 - 0.9),
+        _ => 1.0,
+    };
+    
+    Ok(correction * constants::SN_CONSISTENCY * outer)
+}
+
+/// Interpolation between the two order statistics around a quantile position
+/// 
+/// The names and results match the corresponding NumPy `method=` options.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum QuantileMethod {
+    /// Linear interpolation between closest ranks (Hyndman-Fan type 7, the NumPy default)
+    #[default]
+    Linear,
+    /// The lower of the two order statistics
+    Lower,
+    /// The higher of the two order statistics
+    Higher,
+    /// The average of the two order statistics
+    Midpoint,
+}
+
+impl FromStr for QuantileMethod {
+    type Err = CalculationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "linear" => Ok(QuantileMethod::Linear),
+            "lower" => Ok(QuantileMethod::Lower),
+            "higher" => Ok(QuantileMethod::Higher),
+            "midpoint" => Ok(QuantileMethod::Midpoint),
+            _ => Err(CalculationError::InvalidInput {
+                message: format!(
+                    "Unknown quantile method '{}': expected 'linear', 'lower', 'higher' or 'midpoint'",
+                    s
+                ),
+            }),
+        }
+    }
+}
+
+/// Quantile of already sorted data at probability q, which must be in [0, 1]
+fn sorted_quantile(sorted: &[f64], q: f64, method: QuantileMethod) -> f64 {
+    let position = q * (sorted.len() - 1) as f64;
+    let lower = position.floor() as usize;
+    let upper = position.ceil() as usize;
+    
+    match method {
+        QuantileMethod::Linear => {
+            let fraction = position - lower as f64;
+            sorted[lower] + fraction * (sorted[upper] - sorted[lower])
+        }
+        QuantileMethod::Lower => sorted[lower],
+        QuantileMethod::Higher => sorted[upper],
+        QuantileMethod::Midpoint => (sorted[lower] + sorted[upper]) / 2.0,
+    }
+}
+
+/// Calculate the quantile of a slice of f64 values at probability q
+/// 
+/// The quantile lies at position q · (n - 1) of the sorted data (0-based) and
+/// `method` chooses how to interpolate between the order statistics around it.
+/// The input slice is not modified.
+/// 
+/// # Returns
+/// * `Ok(f64)` - The quantile
+/// * `Err(CalculationError)` - If the data is empty, a value is not finite or q is not in [0, 1]
+pub fn quantile(data: &[f64], q: f64, method: QuantileMethod) -> Result<f64, CalculationError> {
+    Ok(quantiles(data, &[q], method)?[0])
+}
+
+/// Calculate several quantiles of a slice of f64 values, sorting only once
+/// 
+/// # Returns
+/// * `Ok(Vec<f64>)` - The quantiles in the order of `qs`
+/// * `Err(CalculationError)` - If the data is empty, a value is not finite or a q is not in [0, 1]
+pub fn quantiles(data: &[f64], qs: &[f64], method: QuantileMethod) -> Result<Vec<f64>, CalculationError> {
+    if data.is_empty() {
+        return Err(CalculationError::InsufficientData {
+            required: 1,
+            actual: 0,
+        });
+    }
+    
+    if let Some(&q) = qs.iter().find(|&&q| !(0.0..=1.0).contains(&q)) {
+        return Err(CalculationError::InvalidInput {
+            message: format!("Quantile probability must be between 0 and 1, got {}", q),
+        });
+    }
+    
+    validate_floats(data, "data")?;
+    
+    let mut sorted = data.to_vec();
+    sorted.sort_by(|a, b| a.total_cmp(b));
+    
+    Ok(qs.iter().map(|&q| sorted_quantile(&sorted, q, method)).collect())
+}
+
+/// Helper function to calculate a percentile of a slice of f64 values
+/// 
+/// Uses linear interpolation between closest ranks (the NumPy default), with
+/// `p` given in percent (0 to 100). The input slice is not modified.
+pub fn percentile(data: &[f64], p: f64) -> Result<f64, CalculationError> {
+    if !is_valid_float(p) || !(0.0..=100.0).contains(&p) {
+        return Err(CalculationError::InvalidInput {
+            message: format!("Percentile must be between 0 and 100, got {}", p),
+        });
+    }
+    
+    quantile(data, p / 100.0, QuantileMethod::Linear)
+}
+
+/// Calculate Tukey's fences Q1 - k·IQR and Q3 + k·IQR
+/// 
+/// This is a quick screening aid (e.g. for box-plot whiskers), not a formal
+/// outlier test. The conventional multiplier is k = 1.5.
+/// 
+/// # Returns
+/// * `Ok((lower, upper))` - The lower and upper fence values
+/// * `Err(CalculationError)` - If the data or multiplier is invalid
+pub fn tukey_fences(data: &[f64], k: f64) -> Result<(f64, f64), CalculationError> {
+    if !is_valid_float(k) || k < 0.0 {
+        return Err(CalculationError::InvalidInput {
+            message: format!("Invalid or negative fence multiplier k: {}", k),
+        });
+    }
+    
+    let q1 = percentile(data, 25.0)?;
+    let q3 = percentile(data, 75.0)?;
+    let iqr = q3 - q1;
+    
+    Ok((q1 - k * iqr, q3 + k * iqr))
+}
+
+/// Return the indices of values lying strictly outside Tukey's fences
+/// 
+/// Like `tukey_fences`, this is intended for screening only.
+pub fn flag_tukey_outliers(data: &[f64], k: f64) -> Result<Vec<usize>, CalculationError> {
+    let (lower, upper) = tukey_fences(data, k)?;
+    
+    Ok(data.iter()
+        .enumerate()
+        .filter(|(_, &value)| value < lower || value > upper)
+        .map(|(i, _)| i)
+        .collect())
+}
+
+/// Kish's effective sample size n_eff = (Σw)² / Σw² of a set of weights
+/// 
+/// With the final Algorithm A weights this reflects how much information the
+/// down-weighted results still carry, unlike a hard count of weights above a
+/// cut-off. Equal weights give n_eff = n; a single non-zero weight gives 1.
+/// 
+/// # Returns
+/// * `Ok(f64)` - The effective number of observations, between 1 and n
+/// * `Err(CalculationError)` - If the weights are empty, negative, not finite or all zero
+pub fn effective_sample_size(weights: &[f64]) -> Result<f64, CalculationError> {
+    if weights.is_empty() {
+        return Err(CalculationError::InsufficientData {
+            required: 1,
+            actual: 0,
+        });
+    }
+    
+    validate_floats(weights, "weights")?;
+    
+    if let Some(i) = weights.iter().position(|&w| w < 0.0) {
+        return Err(CalculationError::InvalidInput {
+            message: format!("Negative weight at index {}: {}", i, weights[i]),
+        });
+    }
+    
+    let sum: f64 = weights.iter().sum();
+    if sum == 0.0 {
+        return Err(CalculationError::InvalidInput {
+            message: "At least one weight must be positive".to_string(),
+        });
+    }
+    
+    let sum_squares: f64 = weights.iter().map(|&w| w * w).sum();
+    Ok(sum * sum / sum_squares)
+}
+
+/// Weighted median of a set of values
+/// 
+/// The values are sorted and the weighted median is the first value at which
+/// the cumulative weight reaches half the total. When the cumulative weight
+/// equals exactly half the total at a value, the midpoint between it and the
+/// next value with positive weight is returned, so equal weights reproduce
+/// `median`. Values with zero weight are ignored.
+/// 
+/// # Returns
+/// * `Ok(f64)` - The weighted median
+/// * `Err(CalculationError)` - If the lengths differ, a value or weight is not finite,
+///   a weight is negative or all weights are zero
+pub fn weighted_median(values: &[f64], weights: &[f64]) -> Result<f64, CalculationError> {
+    validate_array_dimensions(values.len(), weights.len(), "values", "weights")?;
+    validate_floats(values, "values")?;
+    validate_floats(weights, "weights")?;
+    
+    if let Some(i) = weights.iter().position(|&w| w < 0.0) {
+        return Err(CalculationError::InvalidInput {
+            message: format!("Negative weight at index {}: {}", i, weights[i]),
+        });
+    }
+    
+    let mut pairs: Vec<(f64, f64)> = values.iter()
+        .zip(weights.iter())
+        .filter(|(_, &w)| w > 0.0)
+        .map(|(&x, &w)| (x, w))
+        .collect();
+    
+    if pairs.is_empty() {
+        return Err(CalculationError::InvalidInput {
+            message: "At least one weight must be positive".to_string(),
+        });
+    }
+    
+    pairs.sort_by(|a, b| a.0.total_cmp(&b.0));
+    
+    let half = pairs.iter().map(|&(_, w)| w).sum::<f64>() / 2.0;
+    let tolerance = 1e-12 * half;
+    let mut cumulative = 0.0;
+    
+    for (i, &(x, w)) in pairs.iter().enumerate() {
+        cumulative += w;
+        if (cumulative - half).abs() <= tolerance && i + 1 < pairs.len() {
+            return Ok((x + pairs[i + 1].0) / 2.0);
+        }
+        if cumulative > half {
+            return Ok(x);
+        }
+    }
+    
+    Ok(pairs[pairs.len() - 1].0)
+}
+
 /// Huber's psi function for robust estimation
 /// This implements the weighting function used in Algorithm A
 pub fn huber_psi(x: f64, c: f64) -> f64 {
@@ -125,7 +768,53 @@ pub fn huber_psi(x: f64, c: f64) -> f64 {
     }
 }
 
+/// Huber weight psi(x) / x: 1 for |x| <= c and c / |x| beyond
+/// 
+/// Uses the closed form rather than dividing psi(x) by x, so a tiny x needs no
+/// special case and the weight is exact on both sides of ±c.
+pub fn huber_weight(x: f64, c: f64) -> f64 {
+    if x.abs() <= c {
+        1.0
+    } else {
+        c / x.abs()
+    }
+}
+
+/// Tukey's bisquare (biweight) psi function
+/// psi(x) = x (1 - (x/c)²)² for |x| <= c and 0 beyond, so distant values are rejected
+pub fn bisquare_psi(x: f64, c: f64) -> f64 {
+    x * bisquare_weight(x, c)
+}
+
+/// Tukey's bisquare weight psi(x) / x = (1 - (x/c)²)² for |x| <= c, 0 beyond
+pub fn bisquare_weight(x: f64, c: f64) -> f64 {
+    if x.abs() <= c {
+        (1.0 - (x / c).powi(2)).powi(2)
+    } else {
+        0.0
+    }
+}
+
+/// Hampel's three-part redescending psi function with knots 0 < a <= b < c
+/// 
+/// psi(x) is x up to a, constant at ±a up to b, decays linearly to 0 at c and
+/// is 0 beyond c.
+pub fn hampel_psi(x: f64, a: f64, b: f64, c: f64) -> f64 {
+    let abs_x = x.abs();
+    
+    if abs_x <= a {
+        x
+    } else if abs_x <= b {
+        a * x.signum()
+    } else if abs_x <= c {
+        a * x.signum() * (c - abs_x) / (c - b)
+    } else {
+        0.0
+    }
+}
+
 /// Validate that input arrays have compatible dimensions
+/// The names are reported in the error so callers can tell which arrays disagree
 pub fn validate_array_dimensions(
     arr1_len: usize,
     arr2_len: usize,
@@ -134,8 +823,10 @@ pub fn validate_array_dimensions(
 ) -> Result<(), CalculationError> {
     if arr1_len != arr2_len {
         return Err(CalculationError::DimensionMismatch {
-            expected: arr1_len,
-            actual: arr2_len,
+            name1: name1.to_string(),
+            len1: arr1_len,
+            name2: name2.to_string(),
+            len2: arr2_len,
         });
     }
     Ok(())
@@ -146,47 +837,1268 @@ pub fn is_valid_float(value: f64) -> bool {
     value.is_finite()
 }
 
-/// Validate that all values in a slice are valid floats
-pub fn validate_floats(data: &[f64], name: &str) -> Result<(), CalculationError> {
-    for (i, &value) in data.iter().enumerate() {
-        if !is_valid_float(value) {
-            return Err(CalculationError::InvalidInput {
-                message: format!("{} contains invalid value at index {}: {}", name, i, value),
-            });
+/// Index and value of every NaN or infinite entry, in index order
+pub fn find_invalid_values<'a>(data: impl IntoIterator<Item = &'a f64>) -> Vec<(usize, f64)> {
+    data.into_iter()
+        .enumerate()
+        .filter(|(_, &value)| !is_valid_float(value))
+        .map(|(i, &value)| (i, value))
+        .collect()
+}
+
+/// Validate that all values in a slice (or array view) are valid floats
+/// 
+/// The error lists the first MAX_REPORTED_INVALID_VALUES offending entries and
+/// the total count, so all bad rows can be fixed in one pass.
+pub fn validate_floats<'a>(
+    data: impl IntoIterator<Item = &'a f64>,
+    name: &str,
+) -> Result<(), CalculationError> {
+    let invalid = find_invalid_values(data);
+    
+    match invalid.as_slice() {
+        [] => Ok(()),
+        [(i, value)] => Err(CalculationError::InvalidInput {
+            message: format!("{} contains invalid value at index {}: {}", name, i, value),
+        }),
+        _ => {
+            let shown: Vec<String> = invalid.iter()
+                .take(constants::MAX_REPORTED_INVALID_VALUES)
+                .map(|(i, value)| format!("{} at index {}", value, i))
+                .collect();
+            let remaining = invalid.len().saturating_sub(constants::MAX_REPORTED_INVALID_VALUES);
+            let more = if remaining > 0 { format!(" and {} more", remaining) } else { String::new() };
+            
+            Err(CalculationError::InvalidInput {
+                message: format!(
+                    "{} contains {} invalid values: {}{}",
+                    name, invalid.len(), shown.join(", "), more
+                ),
+            })
         }
     }
-    Ok(())
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use approx::assert_abs_diff_eq;
-
-    #[test]
-    fn test_median_odd_length() {
-        let mut data = vec![1.0, 3.0, 2.0];
-        assert_eq!(median(&mut data), Some(2.0));
+/// Natural logarithm of the gamma function (Lanczos approximation, g = 7)
+pub fn ln_gamma(x: f64) -> f64 {
+    const COEFFICIENTS: [f64; 9] = [
+        0.999_999_999_999_809_9,
+        676.520_368_121_885_1,
+        -1_259.139_216_722_402_8,
+        771.323_428_777_653_1,
+        -176.615_029_162_140_6,
+        12.507_343_278_686_905,
+        -0.138_571_095_265_720_12,
+        9.984_369_578_019_572e-6,
+        1.505_632_735_149_311_6e-7,
+    ];
+    
+    if x < 0.5 {
+        // Reflection formula
+        let pi = std::f64::consts::PI;
+        return (pi / (pi * x).sin()).ln() - ln_gamma(1.0 - x);
     }
+    
+    let x = x - 1.0;
+    let t = x + 7.5;
+    let series = COEFFICIENTS.iter()
+        .enumerate()
+        .skip(1)
+        .fold(COEFFICIENTS[0], |sum, (i, &c)| sum + c / (x + i as f64));
+    
+    0.5 * (2.0 * std::f64::consts::PI).ln() + (x + 0.5) * t.ln() - t + series.ln()
+}
 
-    #[test]
-    fn test_median_even_length() {
-        let mut data = vec![1.0, 2.0, 3.0, 4.0];
-        assert_eq!(median(&mut data), Some(2.5));
+/// Continued fraction for the regularized incomplete beta function (modified Lentz)
+fn beta_continued_fraction(a: f64, b: f64, x: f64) -> f64 {
+    const MAX_ITERATIONS: usize = 300;
+    const EPSILON: f64 = 1e-15;
+    const TINY: f64 = 1e-300;
+    
+    let qab = a + b;
+    let qap = a + 1.0;
+    let qam = a - 1.0;
+    let mut c = 1.0;
+    let mut d = 1.0 - qab * x / qap;
+    if d.abs() < TINY {
+        d = TINY;
     }
-
-    #[test]
-    fn test_median_empty() {
-        let mut data = vec![];
-        assert_eq!(median(&mut data), None);
+    d = 1.0 / d;
+    let mut h = d;
+    
+    for m in 1..=MAX_ITERATIONS {
+        let m = m as f64;
+        let m2 = 2.0 * m;
+        
+        let aa = m * (b - m) * x / ((qam + m2) * (a + m2));
+        d = 1.0 + aa * d;
+        if d.abs() < TINY {
+            d = TINY;
+        }
+        c = 1.0 + aa / c;
+        if c.abs() < TINY {
+            c = TINY;
+        }
+        d = 1.0 / d;
+        h *= d * c;
+        
+        let aa = -(a + m) * (qab + m) * x / ((a + m2) * (qap + m2));
+        d = 1.0 + aa * d;
+        if d.abs() < TINY {
+            d = TINY;
+        }
+        c = 1.0 + aa / c;
+        if c.abs() < TINY {
+            c = TINY;
+        }
+        d = 1.0 / d;
+        let delta = d * c;
+        h *= delta;
+        
+        if (delta - 1.0).abs() < EPSILON {
+            break;
+        }
     }
+    
+    h
+}
 
-    #[test]
-    fn test_mad_calculation() {
-        let data = vec![1.0, 2.0, 3.0, 4.0, 5.0];
-        let median_val = 3.0;
-        let mad_val = mad(&data, median_val).unwrap();
-        assert_abs_diff_eq!(mad_val, 1.0, epsilon = 1e-10);
+/// Regularized incomplete beta function I_x(a, b)
+pub fn regularized_incomplete_beta(x: f64, a: f64, b: f64) -> f64 {
+    if x <= 0.0 {
+        return 0.0;
+    }
+    if x >= 1.0 {
+        return 1.0;
+    }
+    
+    let ln_front = ln_gamma(a + b) - ln_gamma(a) - ln_gamma(b) + a * x.ln() + b * (1.0 - x).ln();
+    let front = ln_front.exp();
+    
+    // Use the symmetry relation where the continued fraction converges faster
+    if x < (a + 1.0) / (a + b + 2.0) {
+        front * beta_continued_fraction(a, b, x) / a
+    } else {
+        1.0 - front * beta_continued_fraction(b, a, 1.0 - x) / b
+    }
+}
+
+/// Cumulative distribution function of Student's t-distribution
+pub fn t_cdf(t: f64, df: f64) -> f64 {
+    let tail = 0.5 * regularized_incomplete_beta(df / (df + t * t), 0.5 * df, 0.5);
+    if t > 0.0 {
+        1.0 - tail
+    } else {
+        tail
+    }
+}
+
+/// Regularized lower incomplete gamma function P(a, x)
+/// 
+/// Uses the series expansion for x < a + 1 and the continued fraction
+/// (modified Lentz) for the upper tail otherwise.
+pub fn regularized_lower_gamma(a: f64, x: f64) -> f64 {
+    regularized_gamma_pair(a, x).0
+}
+
+/// Regularized incomplete gamma functions (P(a, x), Q(a, x)) with P + Q = 1
+/// 
+/// Each is computed directly on its own branch, so the smaller of the two keeps
+/// full relative precision instead of being lost to 1 - P.
+fn regularized_gamma_pair(a: f64, x: f64) -> (f64, f64) {
+    const MAX_ITERATIONS: usize = 500;
+    const EPSILON: f64 = 1e-15;
+    const TINY: f64 = 1e-300;
+    
+    if x <= 0.0 {
+        return (0.0, 1.0);
+    }
+    
+    let ln_front = a * x.ln() - x - ln_gamma(a);
+    
+    if x < a + 1.0 {
+        let mut term = 1.0 / a;
+        let mut sum = term;
+        let mut ap = a;
+        for _ in 0..MAX_ITERATIONS {
+            ap += 1.0;
+            term *= x / ap;
+            sum += term;
+            if term.abs() < sum.abs() * EPSILON {
+                break;
+            }
+        }
+        let p = (sum * ln_front.exp()).min(1.0);
+        return (p, 1.0 - p);
+    }
+    
+    let mut b = x + 1.0 - a;
+    let mut c = 1.0 / TINY;
+    let mut d = 1.0 / b;
+    let mut h = d;
+    for i in 1..=MAX_ITERATIONS {
+        let an = -(i as f64) * (i as f64 - a);
+        b += 2.0;
+        d = an * d + b;
+        if d.abs() < TINY {
+            d = TINY;
+        }
+        c = b + an / c;
+        if c.abs() < TINY {
+            c = TINY;
+        }
+        d = 1.0 / d;
+        let delta = d * c;
+        h *= delta;
+        if (delta - 1.0).abs() < EPSILON {
+            break;
+        }
+    }
+    
+    let q = (ln_front.exp() * h).min(1.0);
+    (1.0 - q, q)
+}
+
+/// Cumulative distribution function of the chi-squared distribution
+pub fn chi_squared_cdf(x: f64, df: f64) -> f64 {
+    regularized_lower_gamma(0.5 * df, 0.5 * x)
+}
+
+/// Quantile function of Student's t-distribution
+/// Returns t such that P(T <= t) = p for `df` degrees of freedom
+pub fn t_quantile(p: f64, df: f64) -> Result<f64, CalculationError> {
+    if !(p > 0.0 && p < 1.0) {
+        return Err(CalculationError::InvalidInput {
+            message: format!("Probability must be in (0, 1), got {}", p),
+        });
+    }
+    
+    if !is_valid_float(df) || df <= 0.0 {
+        return Err(CalculationError::InvalidInput {
+            message: format!("Degrees of freedom must be positive, got {}", df),
+        });
+    }
+    
+    // Bracket the quantile, then bisect on the monotonic CDF
+    let mut lower = -1.0;
+    let mut upper = 1.0;
+    while t_cdf(lower, df) > p {
+        lower *= 2.0;
+    }
+    while t_cdf(upper, df) < p {
+        upper *= 2.0;
+    }
+    
+    for _ in 0..constants::MAX_BISECTION_STEPS {
+        let mid = 0.5 * (lower + upper);
+        if t_cdf(mid, df) < p {
+            lower = mid;
+        } else {
+            upper = mid;
+        }
+        if upper - lower <= 1e-12 * mid.abs().max(1.0) {
+            break;
+        }
+    }
+    
+    Ok(0.5 * (lower + upper))
+}
+
+/// Cumulative distribution function of the standard normal distribution
+/// 
+/// Φ(z) = P(1/2, z²/2) / 2 + 1/2 for z ≥ 0; the lower tail is taken from the
+/// upper incomplete gamma function so that Φ(z) keeps its relative precision
+/// far below the mean (needed for ln Φ in goodness-of-fit statistics).
+pub fn normal_cdf(z: f64) -> f64 {
+    if z.is_nan() {
+        return f64::NAN;
+    }
+    
+    let tail = 0.5 * regularized_gamma_pair(0.5, 0.5 * z * z).1;
+    if z < 0.0 {
+        tail
+    } else {
+        1.0 - tail
+    }
+}
+
+/// Quantile function of the standard normal distribution
+/// Returns z such that Φ(z) = p (Acklam's rational approximation, relative error < 1.2e-9)
+pub fn normal_quantile(p: f64) -> Result<f64, CalculationError> {
+    const A: [f64; 6] = [
+        -3.969_683_028_665_376e1, 2.209_460_984_245_205e2, -2.759_285_104_469_687e2,
+        1.383_577_518_672_69e2, -3.066_479_806_614_716e1, 2.506_628_277_459_239,
+    ];
+    const B: [f64; 5] = [
+        -5.447_609_879_822_406e1, 1.615_858_368_580_409e2, -1.556_989_798_598_866e2,
+        6.680_131_188_771_972e1, -1.328_068_155_288_572e1,
+    ];
+    const C: [f64; 6] = [
+        -7.784_894_002_430_293e-3, -3.223_964_580_411_365e-1, -2.400_758_277_161_838,
+        -2.549_732_539_343_734, 4.374_664_141_464_968, 2.938_163_982_698_783,
+    ];
+    const D: [f64; 4] = [
+        7.784_695_709_041_462e-3, 3.224_671_290_700_398e-1, 2.445_134_137_142_996,
+        3.754_408_661_907_416,
+    ];
+    const P_LOW: f64 = 0.024_25;
+    
+    if !(p > 0.0 && p < 1.0) {
+        return Err(CalculationError::InvalidInput {
+            message: format!("Probability must be in (0, 1), got {}", p),
+        });
+    }
+    
+    // Lower tail; the upper tail follows by symmetry
+    let tail = |q: f64| {
+        let r = (-2.0 * q.ln()).sqrt();
+        (((((C[0] * r + C[1]) * r + C[2]) * r + C[3]) * r + C[4]) * r + C[5])
+            / ((((D[0] * r + D[1]) * r + D[2]) * r + D[3]) * r + 1.0)
+    };
+    
+    if p < P_LOW {
+        return Ok(tail(p));
+    }
+    if p > 1.0 - P_LOW {
+        return Ok(-tail(1.0 - p));
+    }
+    
+    let q = p - 0.5;
+    let r = q * q;
+    Ok((((((A[0] * r + A[1]) * r + A[2]) * r + A[3]) * r + A[4]) * r + A[5]) * q
+        / (((((B[0] * r + B[1]) * r + B[2]) * r + B[3]) * r + B[4]) * r + 1.0))
+}
+
+/// Alternative hypothesis of the Grubbs outlier test
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GrubbsAlternative {
+    /// The largest value is an outlier (one-sided)
+    Max,
+    /// The smallest value is an outlier (one-sided)
+    Min,
+    /// The value furthest from the mean is an outlier
+    #[default]
+    TwoSided,
+}
+
+impl FromStr for GrubbsAlternative {
+    type Err = CalculationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "max" => Ok(GrubbsAlternative::Max),
+            "min" => Ok(GrubbsAlternative::Min),
+            "two_sided" => Ok(GrubbsAlternative::TwoSided),
+            _ => Err(CalculationError::InvalidInput {
+                message: format!(
+                    "Unknown Grubbs alternative '{}': expected 'max', 'min' or 'two_sided'",
+                    s
+                ),
+            }),
+        }
+    }
+}
+
+/// Result of a single Grubbs outlier test
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GrubbsResult {
+    /// Index of the suspected outlier
+    pub index: usize,
+    /// Grubbs statistic G, e.g. max|x_i - mean| / s for the two-sided test
+    pub statistic: f64,
+    /// Critical value of G at the chosen significance level
+    pub critical_value: f64,
+    /// Whether the tested value is an outlier
+    pub is_outlier: bool,
+}
+
+/// Critical value of the Grubbs statistic for `n` values
+/// The two-sided test uses alpha / (2n), the one-sided tests alpha / n
+pub fn grubbs_critical_value(n: usize, alpha: f64, alternative: GrubbsAlternative) -> Result<f64, CalculationError> {
+    if n < 3 {
+        return Err(CalculationError::InsufficientData {
+            required: 3,
+            actual: n,
+        });
+    }
+    
+    if !(alpha > 0.0 && alpha < 1.0) {
+        return Err(CalculationError::InvalidInput {
+            message: format!("Significance level must be in (0, 1), got {}", alpha),
+        });
+    }
+    
+    let n_f = n as f64;
+    let tail = match alternative {
+        GrubbsAlternative::TwoSided => alpha / (2.0 * n_f),
+        GrubbsAlternative::Max | GrubbsAlternative::Min => alpha / n_f,
+    };
+    let t = t_quantile(1.0 - tail, n_f - 2.0)?;
+    
+    Ok((n_f - 1.0) / n_f.sqrt() * (t * t / (n_f - 2.0 + t * t)).sqrt())
+}
+
+/// Grubbs test for a single outlier
+/// 
+/// Tests the largest value with G = (max - mean) / s, the smallest with
+/// G = (mean - min) / s, or the value furthest from the mean with
+/// G = max|x_i - mean| / s, depending on `alternative`.
+/// 
+/// # Arguments
+/// * `data` - Values to test (at least 3)
+/// * `alpha` - Significance level in (0, 1)
+/// * `alternative` - Which extreme value is tested
+/// 
+/// # Returns
+/// * `Ok(GrubbsResult)` - Index of the tested value, G, critical value and decision
+/// * `Err(CalculationError)` - If there are fewer than 3 values or any input is invalid
+pub fn grubbs_test(data: &[f64], alpha: f64, alternative: GrubbsAlternative) -> Result<GrubbsResult, CalculationError> {
+    validate_floats(data, "data")?;
+    let critical_value = grubbs_critical_value(data.len(), alpha, alternative)?;
+    
+    let stats = running_stats(data);
+    let mean = stats.mean().unwrap();
+    let sd = stats.std_dev().unwrap();
+    
+    let deviation = |x: f64| match alternative {
+        GrubbsAlternative::Max => x - mean,
+        GrubbsAlternative::Min => mean - x,
+        GrubbsAlternative::TwoSided => (x - mean).abs(),
+    };
+    let (index, max_deviation) = data.iter()
+        .map(|&x| deviation(x))
+        .enumerate()
+        .fold((0, f64::NEG_INFINITY), |best, (i, d)| if d > best.1 { (i, d) } else { best });
+    
+    // Identical values have no outlier
+    let statistic = if sd > 0.0 { max_deviation / sd } else { 0.0 };
+    
+    Ok(GrubbsResult {
+        index,
+        statistic,
+        critical_value,
+        is_outlier: statistic > critical_value,
+    })
+}
+
+/// Repeated Grubbs test, removing one outlier per pass
+/// 
+/// Each pass tests the remaining values and removes the suspected outlier if
+/// it is significant. Iteration stops at the first non-significant pass, after
+/// `max_removals` removals, or when fewer than 3 values remain.
+/// 
+/// # Arguments
+/// * `data` - Values to test (at least 3)
+/// * `alpha` - Significance level of each pass
+/// * `alternative` - Which extreme value is tested in each pass
+/// * `max_removals` - Largest number of values to remove
+/// 
+/// # Returns
+/// * `Ok(Vec<usize>)` - Indices into `data` of the removed values, in removal order
+/// * `Err(CalculationError)` - If there are fewer than 3 values or any input is invalid
+pub fn grubbs_iterative(
+    data: &[f64],
+    alpha: f64,
+    alternative: GrubbsAlternative,
+    max_removals: usize,
+) -> Result<Vec<usize>, CalculationError> {
+    let mut remaining: Vec<usize> = (0..data.len()).collect();
+    let mut values = data.to_vec();
+    let mut removed = Vec::new();
+    
+    // The first test validates the data and parameters
+    let mut test = grubbs_test(&values, alpha, alternative)?;
+    
+    while test.is_outlier && removed.len() < max_removals {
+        removed.push(remaining.remove(test.index));
+        values.remove(test.index);
+        
+        if values.len() < 3 {
+            break;
+        }
+        test = grubbs_test(&values, alpha, alternative)?;
+    }
+    
+    Ok(removed)
+}
+
+/// Result of Cochran's test for an outlying variance
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CochranResult {
+    /// Index of the largest variance
+    pub index: usize,
+    /// Cochran statistic C = max s² / Σ s²
+    pub statistic: f64,
+    /// Critical value of C at the chosen significance level
+    pub critical_value: f64,
+    /// Whether the largest variance is outlying
+    pub is_outlier: bool,
+}
+
+/// Cochran's C test for the largest of several within-group variances
+/// 
+/// Each variance is computed from the same number of replicates n, as in the
+/// precision experiments of ISO 5725-2 or a homogeneity study. A single
+/// s²_i / Σ s² follows Beta((n - 1)/2, (p - 1)(n - 1)/2) for normal data, and the
+/// critical value is its upper alpha / p quantile, the Bonferroni bound that
+/// gives the published Cochran tables for p >= 3. With two variances this
+/// bound is conservative.
+/// 
+/// # Arguments
+/// * `variances` - Within-group variances s²_i (at least 2, non-negative)
+/// * `replicates` - Number of replicates n behind each variance (at least 2)
+/// * `alpha` - Significance level in (0, 1), e.g. 0.05 for stragglers and 0.01 for outliers
+/// 
+/// # Returns
+/// * `Ok(CochranResult)` - Index of the largest variance, C, critical value and decision
+/// * `Err(CalculationError)` - If there are fewer than 2 variances or any input is invalid
+pub fn cochran_test(variances: &[f64], replicates: usize, alpha: f64) -> Result<CochranResult, CalculationError> {
+    validate_floats(variances, "variances")?;
+    
+    let p = variances.len();
+    if p < 2 {
+        return Err(CalculationError::InsufficientData {
+            required: 2,
+            actual: p,
+        });
+    }
+    
+    if let Some((i, &variance)) = variances.iter().enumerate().find(|(_, &v)| v < 0.0) {
+        return Err(CalculationError::InvalidInput {
+            message: format!("Negative variance {} at index {}", variance, i),
+        });
+    }
+    
+    if replicates < 2 {
+        return Err(CalculationError::InvalidInput {
+            message: format!("Cochran's test needs at least 2 replicates per variance, got {}", replicates),
+        });
+    }
+    
+    if !(alpha > 0.0 && alpha < 1.0) {
+        return Err(CalculationError::InvalidInput {
+            message: format!("Significance level must be in (0, 1), got {}", alpha),
+        });
+    }
+    
+    // Upper alpha / p quantile of Beta(ν/2, (p - 1)ν/2) by bisection
+    let nu = (replicates - 1) as f64;
+    let (a, b) = (0.5 * nu, 0.5 * (p - 1) as f64 * nu);
+    let target = 1.0 - alpha / p as f64;
+    let (mut lower, mut upper) = (0.0, 1.0);
+    for _ in 0..constants::MAX_BISECTION_STEPS {
+        let mid = 0.5 * (lower + upper);
+        if regularized_incomplete_beta(mid, a, b) < target {
+            lower = mid;
+        } else {
+            upper = mid;
+        }
+        if upper - lower <= 1e-14 {
+            break;
+        }
+    }
+    let critical_value = 0.5 * (lower + upper);
+    
+    let (index, max_variance) = variances.iter()
+        .enumerate()
+        .fold((0, f64::NEG_INFINITY), |best, (i, &v)| if v > best.1 { (i, v) } else { best });
+    let total: f64 = variances.iter().sum();
+    
+    // All-zero variances have no outlier
+    let statistic = if total > 0.0 { max_variance / total } else { 0.0 };
+    
+    Ok(CochranResult {
+        index,
+        statistic,
+        critical_value,
+        is_outlier: statistic > critical_value,
+    })
+}
+
+/// Critical values of the adjusted Anderson-Darling statistic A*² for normality
+/// with mean and variance estimated from the data (Stephens, case 3), as
+/// (significance level, critical value)
+pub const ANDERSON_DARLING_CRITICAL_VALUES: [(f64, f64); 5] = [
+    (0.15, 0.576),
+    (0.10, 0.656),
+    (0.05, 0.787),
+    (0.025, 0.918),
+    (0.01, 1.092),
+];
+
+/// Minimum number of values for the Anderson-Darling normality test
+pub const MIN_ANDERSON_DARLING: usize = 8;
+
+/// Result of the Anderson-Darling normality test
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AndersonDarlingResult {
+    /// Adjusted statistic A*² = A² (1 + 0.75/n + 2.25/n²)
+    pub statistic: f64,
+    /// Critical value of A*² at the chosen significance level
+    pub critical_value: f64,
+    /// Significance level of the test
+    pub alpha: f64,
+    /// Whether normality is not rejected (A*² <= critical value)
+    pub is_normal: bool,
+}
+
+/// Anderson-Darling test of normality with estimated mean and variance
+/// 
+/// Standardizes the sorted data with the sample mean and standard deviation
+/// (n - 1 denominator), computes
+/// A² = -n - (1/n) Σ (2i - 1) [ln Φ(z_i) + ln(1 - Φ(z_(n+1-i)))]
+/// and applies the small-sample adjustment for the estimated parameters before
+/// comparing with the tabulated critical value.
+/// 
+/// # Arguments
+/// * `data` - Values to test
+/// * `alpha` - Significance level; one of 0.15, 0.10, 0.05, 0.025 or 0.01
+/// 
+/// # Returns
+/// * `Ok(AndersonDarlingResult)` - Adjusted statistic, critical value and decision
+/// * `Err(CalculationError)` - If there are fewer than 8 values, a value is not
+///   finite, the values are identical or `alpha` is not in the table
+pub fn anderson_darling_normal(data: &[f64], alpha: f64) -> Result<AndersonDarlingResult, CalculationError> {
+    validate_floats(data, "data")?;
+    
+    let n = data.len();
+    if n < MIN_ANDERSON_DARLING {
+        return Err(CalculationError::InsufficientData {
+            required: MIN_ANDERSON_DARLING,
+            actual: n,
+        });
+    }
+    
+    let critical_value = ANDERSON_DARLING_CRITICAL_VALUES.iter()
+        .find(|&&(level, _)| (level - alpha).abs() < 1e-12)
+        .map(|&(_, critical)| critical)
+        .ok_or_else(|| CalculationError::InvalidInput {
+            message: format!(
+                "Unsupported Anderson-Darling significance level {}: expected 0.15, 0.10, 0.05, 0.025 or 0.01",
+                alpha
+            ),
+        })?;
+    
+    let stats = running_stats(data);
+    let mean = stats.mean().unwrap();
+    let sd = stats.std_dev().unwrap();
+    if sd <= 0.0 {
+        return Err(CalculationError::InvalidInput {
+            message: "Cannot test normality of identical values".to_string(),
+        });
+    }
+    
+    let mut z: Vec<f64> = data.iter().map(|&x| (x - mean) / sd).collect();
+    z.sort_by(|a, b| a.total_cmp(b));
+    
+    // ln(1 - Φ(z)) is evaluated as ln Φ(-z) to keep the upper tail precise
+    let n_f = n as f64;
+    let sum: f64 = (0..n)
+        .map(|i| (2 * i + 1) as f64 * (normal_cdf(z[i]).ln() + normal_cdf(-z[n - 1 - i]).ln()))
+        .sum();
+    let a_squared = -n_f - sum / n_f;
+    let statistic = a_squared * (1.0 + 0.75 / n_f + 2.25 / (n_f * n_f));
+    
+    Ok(AndersonDarlingResult {
+        statistic,
+        critical_value,
+        alpha,
+        is_normal: statistic <= critical_value,
+    })
+}
+
+/// Largest sample size supported by the Shapiro-Wilk approximation (AS R94)
+pub const MAX_SHAPIRO_WILK: usize = 5000;
+
+/// Result of the Shapiro-Wilk normality test
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ShapiroWilkResult {
+    /// W statistic in (0, 1]; values near 1 are consistent with normality
+    pub statistic: f64,
+    /// P-value of the test
+    pub p_value: f64,
+}
+
+/// Evaluate c[0] + c[1] x + c[2] x² + ... by Horner's rule
+fn polynomial(coefficients: &[f64], x: f64) -> f64 {
+    coefficients.iter().rev().fold(0.0, |acc, &c| acc * x + c)
+}
+
+/// Shapiro-Wilk test of normality (Royston, algorithm AS R94)
+/// 
+/// The coefficients a_i are Royston's approximation built from the normal
+/// scores m_i = Φ⁻¹((i - 3/8) / (n + 1/4)) with polynomial corrections of
+/// the two most extreme coefficients, and W = (Σ a_i x_(i))² / Σ (x_i - x̄)².
+/// The p-value uses Royston's normalizing transformations of W (separate
+/// forms for n <= 11 and larger n) and is exact for n = 3. This is the
+/// algorithm behind scipy.stats.shapiro and R's shapiro.test.
+/// 
+/// # Arguments
+/// * `data` - Values to test (3 to 5000 values)
+/// 
+/// # Returns
+/// * `Ok(ShapiroWilkResult)` - W statistic and p-value
+/// * `Err(CalculationError)` - If there are fewer than 3 or more than 5000
+///   values, a value is not finite or the values are identical
+pub fn shapiro_wilk(data: &[f64]) -> Result<ShapiroWilkResult, CalculationError> {
+    const C1: [f64; 6] = [0.0, 0.221_157, -0.147_981, -2.071_19, 4.434_685, -2.706_056];
+    const C2: [f64; 6] = [0.0, 0.042_981, -0.293_762, -1.752_461, 5.682_633, -3.582_633];
+    const C3: [f64; 4] = [0.544, -0.399_78, 0.025_054, -6.714e-4];
+    const C4: [f64; 4] = [1.3822, -0.778_57, 0.062_767, -0.002_032_2];
+    const C5: [f64; 4] = [-1.5861, -0.310_82, -0.083_751, 0.003_891_5];
+    const C6: [f64; 3] = [-0.4803, -0.082_676, 0.003_030_2];
+    const G: [f64; 2] = [-2.273, 0.459];
+    const SMALL: f64 = 1e-19;
+    
+    validate_floats(data, "data")?;
+    
+    let n = data.len();
+    if n < 3 {
+        return Err(CalculationError::InsufficientData {
+            required: 3,
+            actual: n,
+        });
+    }
+    
+    if n > MAX_SHAPIRO_WILK {
+        return Err(CalculationError::InvalidInput {
+            message: format!(
+                "Shapiro-Wilk test supports at most {} values, got {}",
+                MAX_SHAPIRO_WILK, n
+            ),
+        });
+    }
+    
+    let mut sorted = data.to_vec();
+    sorted.sort_by(|a, b| a.total_cmp(b));
+    
+    let range = sorted[n - 1] - sorted[0];
+    if range < SMALL {
+        return Err(CalculationError::InvalidInput {
+            message: "Cannot test normality of identical values".to_string(),
+        });
+    }
+    
+    // Coefficients of the lower half; the upper half is antisymmetric
+    let n_f = n as f64;
+    let half = n / 2;
+    let mut a = vec![0.0; half];
+    if n == 3 {
+        a[0] = 0.5_f64.sqrt();
+    } else {
+        let m: Vec<f64> = (1..=half)
+            .map(|i| normal_quantile((i as f64 - 0.375) / (n_f + 0.25)))
+            .collect::<Result<_, _>>()?;
+        let sum_m2 = 2.0 * m.iter().map(|m_i| m_i * m_i).sum::<f64>();
+        let sqrt_sum_m2 = sum_m2.sqrt();
+        let rsn = 1.0 / n_f.sqrt();
+        
+        let a1 = polynomial(&C1, rsn) - m[0] / sqrt_sum_m2;
+        a[0] = a1;
+        let (first_scaled, scale) = if n > 5 {
+            let a2 = polynomial(&C2, rsn) - m[1] / sqrt_sum_m2;
+            a[1] = a2;
+            let scale = ((sum_m2 - 2.0 * m[0] * m[0] - 2.0 * m[1] * m[1])
+                / (1.0 - 2.0 * a1 * a1 - 2.0 * a2 * a2)).sqrt();
+            (2, scale)
+        } else {
+            let scale = ((sum_m2 - 2.0 * m[0] * m[0]) / (1.0 - 2.0 * a1 * a1)).sqrt();
+            (1, scale)
+        };
+        for i in first_scaled..half {
+            a[i] = -m[i] / scale;
+        }
+    }
+    
+    // W = 1 - w1 with w1 = 1 - (Σ a x)² / (Σ a² Σ (x - x̄)²), on data scaled by the range
+    let mean = sorted.iter().sum::<f64>() / n_f;
+    let (mut ssa, mut ssx, mut sax) = (0.0, 0.0, 0.0);
+    for (i, &x) in sorted.iter().enumerate() {
+        let j = n - 1 - i;
+        let a_i = match i.cmp(&j) {
+            std::cmp::Ordering::Less => -a[i],
+            std::cmp::Ordering::Greater => a[j],
+            std::cmp::Ordering::Equal => 0.0,
+        };
+        let x_centered = (x - mean) / range;
+        ssa += a_i * a_i;
+        ssx += x_centered * x_centered;
+        sax += a_i * x_centered;
+    }
+    let ssa_ssx = (ssa * ssx).sqrt();
+    let w1 = (ssa_ssx - sax) * (ssa_ssx + sax) / (ssa * ssx);
+    let statistic = 1.0 - w1;
+    
+    if n == 3 {
+        // Exact distribution: p = (6/π) (asin √W - asin √(3/4))
+        let p_value = 6.0 / std::f64::consts::PI
+            * (statistic.sqrt().asin() - std::f64::consts::FRAC_PI_3);
+        return Ok(ShapiroWilkResult {
+            statistic,
+            p_value: p_value.clamp(0.0, 1.0),
+        });
+    }
+    
+    let mut y = w1.ln();
+    let (mu, sigma) = if n <= 11 {
+        let gamma = polynomial(&G, n_f);
+        if y >= gamma {
+            return Ok(ShapiroWilkResult {
+                statistic,
+                p_value: 1e-99,
+            });
+        }
+        y = -(gamma - y).ln();
+        (polynomial(&C3, n_f), polynomial(&C4, n_f).exp())
+    } else {
+        let ln_n = n_f.ln();
+        (polynomial(&C5, ln_n), polynomial(&C6, ln_n).exp())
+    };
+    
+    Ok(ShapiroWilkResult {
+        statistic,
+        p_value: normal_cdf(-(y - mu) / sigma),
+    })
+}
+
+/// Single-pass accumulator of count, mean, variance, minimum and maximum
+/// 
+/// Uses Welford's update, which stays accurate when the values share a large
+/// offset (where Σx² - n·mean² cancels catastrophically). Accumulators of
+/// separate chunks can be combined with `merge`, e.g. after a parallel pass.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RunningStats {
+    count: usize,
+    mean: f64,
+    /// Sum of squared deviations from the current mean
+    m2: f64,
+    min: f64,
+    max: f64,
+}
+
+impl Default for RunningStats {
+    fn default() -> Self {
+        RunningStats {
+            count: 0,
+            mean: 0.0,
+            m2: 0.0,
+            min: f64::INFINITY,
+            max: f64::NEG_INFINITY,
+        }
+    }
+}
+
+impl RunningStats {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// Add one value
+    pub fn push(&mut self, x: f64) {
+        self.count += 1;
+        let delta = x - self.mean;
+        self.mean += delta / self.count as f64;
+        self.m2 += delta * (x - self.mean);
+        self.min = self.min.min(x);
+        self.max = self.max.max(x);
+    }
+    
+    /// Combine with the statistics of another set of values (Chan et al.)
+    pub fn merge(&mut self, other: &RunningStats) {
+        if other.count == 0 {
+            return;
+        }
+        if self.count == 0 {
+            *self = *other;
+            return;
+        }
+        
+        let count = self.count + other.count;
+        let delta = other.mean - self.mean;
+        let weight = other.count as f64 / count as f64;
+        
+        self.m2 += other.m2 + delta * delta * self.count as f64 * weight;
+        self.mean += delta * weight;
+        self.count = count;
+        self.min = self.min.min(other.min);
+        self.max = self.max.max(other.max);
+    }
+    
+    pub fn count(&self) -> usize {
+        self.count
+    }
+    
+    /// Arithmetic mean, None without values
+    pub fn mean(&self) -> Option<f64> {
+        (self.count > 0).then_some(self.mean)
+    }
+    
+    /// Sample variance with n - 1 in the denominator, None for fewer than 2 values
+    pub fn variance(&self) -> Option<f64> {
+        (self.count > 1).then(|| self.m2 / (self.count - 1) as f64)
+    }
+    
+    /// Sample standard deviation, None for fewer than 2 values
+    pub fn std_dev(&self) -> Option<f64> {
+        self.variance().map(f64::sqrt)
+    }
+    
+    pub fn min(&self) -> Option<f64> {
+        (self.count > 0).then_some(self.min)
+    }
+    
+    pub fn max(&self) -> Option<f64> {
+        (self.count > 0).then_some(self.max)
+    }
+}
+
+/// Accumulate `RunningStats` over a slice; callers are expected to validate the data first
+pub fn running_stats(data: &[f64]) -> RunningStats {
+    let mut stats = RunningStats::new();
+    for &x in data {
+        stats.push(x);
+    }
+    stats
+}
+
+/// Small seeded pseudo-random number generator (SplitMix64) for reproducible simulations
+#[derive(Debug, Clone)]
+pub struct SplitMix64 {
+    state: u64,
+}
+
+impl SplitMix64 {
+    pub fn new(seed: u64) -> Self {
+        SplitMix64 { state: seed }
+    }
+    
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+    
+    /// Uniform value in [0, 1)
+    pub fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+    
+    /// Standard normal value (Box-Muller transform)
+    pub fn next_normal(&mut self) -> f64 {
+        let u1 = 1.0 - self.next_f64();
+        let u2 = self.next_f64();
+        (-2.0 * u1.ln()).sqrt() * (2.0 * std::f64::consts::PI * u2).cos()
+    }
+}
+
+/// Bootstrap standard deviation of a statistic of `data`
+/// 
+/// Draws `n_resamples` samples of the same size with replacement, evaluates
+/// `statistic` on each (it may reorder the resample) and returns the sample
+/// standard deviation of the values. The same seed always gives the same result.
+pub(crate) fn bootstrap_std_dev<F>(
+    data: &[f64],
+    n_resamples: usize,
+    seed: u64,
+    mut statistic: F,
+) -> Result<f64, CalculationError>
+where
+    F: FnMut(&mut [f64]) -> Result<f64, CalculationError>,
+{
+    if n_resamples < 2 {
+        return Err(CalculationError::InvalidInput {
+            message: format!("At least 2 bootstrap resamples are required, got {}", n_resamples),
+        });
+    }
+    
+    let n = data.len();
+    let mut rng = SplitMix64::new(seed);
+    let mut resample = vec![0.0; n];
+    let mut estimates = Vec::with_capacity(n_resamples);
+    
+    for _ in 0..n_resamples {
+        for value in resample.iter_mut() {
+            *value = data[(rng.next_f64() * n as f64) as usize];
+        }
+        estimates.push(statistic(&mut resample)?);
+    }
+    
+    let mean = estimates.iter().sum::<f64>() / n_resamples as f64;
+    let variance = estimates.iter()
+        .map(|&x| (x - mean).powi(2))
+        .sum::<f64>() / (n_resamples - 1) as f64;
+    
+    Ok(variance.sqrt())
+}
+
+/// Results from several batches concatenated with their provenance
+#[derive(Debug, Clone, PartialEq)]
+pub struct MergedResults {
+    /// All values in batch order
+    pub values: Vec<f64>,
+    /// Batch name of each value
+    pub labels: Vec<String>,
+    /// Number of values contributed by each batch, in input order
+    pub batch_counts: Vec<(String, usize)>,
+}
+
+/// Merge result sets from multiple files or sub-rounds, keeping the batch of each value
+/// Batch names must be unique and every value must be a valid float
+pub fn merge_result_sets(sets: Vec<(String, Vec<f64>)>) -> Result<MergedResults, CalculationError> {
+    let total: usize = sets.iter().map(|(_, values)| values.len()).sum();
+    let mut merged = MergedResults {
+        values: Vec::with_capacity(total),
+        labels: Vec::with_capacity(total),
+        batch_counts: Vec::with_capacity(sets.len()),
+    };
+    
+    for (name, values) in sets {
+        if merged.batch_counts.iter().any(|(existing, _)| *existing == name) {
+            return Err(CalculationError::InvalidInput {
+                message: format!("Duplicate batch name: {}", name),
+            });
+        }
+        
+        validate_floats(&values, &format!("batch '{}'", name))?;
+        
+        merged.labels.extend(std::iter::repeat_n(name.clone(), values.len()));
+        merged.batch_counts.push((name, values.len()));
+        merged.values.extend(values);
+    }
+    
+    Ok(merged)
+}
+
+/// Infer the reporting resolution of a set of results
+/// 
+/// Each non-zero result is read as its shortest round-trip decimal string and
+/// its resolution is the place value of its last significant digit (12.3 gives
+/// 0.1, 1200 gives 100). The results share the finest of these grids, which is
+/// returned. Zero results carry no resolution and are skipped.
+/// 
+/// # Returns
+/// * `Ok(f64)` - The finest power of ten on which all results lie
+/// * `Err(CalculationError)` - If a result is not finite or all results are zero
+pub fn rounding_resolution(data: &[f64]) -> Result<f64, CalculationError> {
+    validate_floats(data, "results")?;
+    
+    data.iter()
+        .filter(|&&x| x != 0.0)
+        .map(|&x| {
+            let formatted = format!("{}", x.abs());
+            match formatted.split_once('.') {
+                Some((_, fraction)) => 10f64.powi(-(fraction.len() as i32)),
+                None => {
+                    let trailing_zeros = formatted.len() - formatted.trim_end_matches('0').len();
+                    10f64.powi(trailing_zeros as i32)
+                }
+            }
+        })
+        .min_by(|a, b| a.total_cmp(b))
+        .ok_or_else(|| CalculationError::InvalidInput {
+            message: "Cannot infer a reporting resolution from zero results".to_string(),
+        })
+}
+
+/// Check whether a robust scale is quantized by the reporting resolution
+/// 
+/// When s* is less than `RESOLUTION_LIMITED_RATIO` times the resolution the
+/// results take only a few distinct values and s* reflects the rounding rather
+/// than the spread between laboratories.
+/// 
+/// # Returns
+/// * `Ok((f64, bool))` - The ratio s* / resolution and whether s* is resolution-limited
+/// * `Err(CalculationError)` - If s* is negative or the resolution is not positive
+pub fn scale_vs_resolution(s_star: f64, resolution: f64) -> Result<(f64, bool), CalculationError> {
+    if !is_valid_float(s_star) || s_star < 0.0 {
+        return Err(CalculationError::InvalidInput {
+            message: format!("Invalid robust standard deviation: {}", s_star),
+        });
+    }
+    
+    if !is_valid_float(resolution) || resolution <= 0.0 {
+        return Err(CalculationError::InvalidInput {
+            message: format!("Invalid or non-positive reporting resolution: {}", resolution),
+        });
+    }
+    
+    let ratio = s_star / resolution;
+    Ok((ratio, ratio < constants::RESOLUTION_LIMITED_RATIO))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::{assert_abs_diff_eq, assert_relative_eq};
+
+    #[test]
+    fn test_median_odd_length() {
+        let mut data = vec![1.0, 3.0, 2.0];
+        assert_eq!(median(&mut data), Some(2.0));
+    }
+
+    #[test]
+    fn test_median_even_length() {
+        let mut data = vec![1.0, 2.0, 3.0, 4.0];
+        assert_eq!(median(&mut data), Some(2.5));
+    }
+
+    #[test]
+    fn test_median_nan_ordering_is_deterministic() {
+        let orderings = [
+            vec![3.0, f64::NAN, 1.0, 2.0],
+            vec![f64::NAN, 2.0, 3.0, 1.0],
+            vec![1.0, 2.0, 3.0, f64::NAN],
+            vec![2.0, 1.0, f64::NAN, 3.0],
+        ];
+        
+        // NaN orders after all numbers, so the median is always (2 + 3) / 2
+        for data in orderings {
+            let mut data = data;
+            assert_eq!(median(&mut data), Some(2.5));
+        }
+    }
+
+    #[test]
+    fn test_median_empty() {
+        let mut data = vec![];
+        assert_eq!(median(&mut data), None);
+    }
+
+    #[test]
+    fn test_mad_calculation() {
+        let data = vec![1.0, 2.0, 3.0, 4.0, 5.0];
+        let median_val = 3.0;
+        let mad_val = mad(&data, median_val).unwrap();
+        assert_abs_diff_eq!(mad_val, 1.0, epsilon = 1e-10);
+    }
+
+    #[test]
+    fn test_percentile() {
+        let data = vec![4.0, 1.0, 3.0, 2.0, 5.0];
+        assert_abs_diff_eq!(percentile(&data, 0.0).unwrap(), 1.0, epsilon = 1e-12);
+        assert_abs_diff_eq!(percentile(&data, 25.0).unwrap(), 2.0, epsilon = 1e-12);
+        assert_abs_diff_eq!(percentile(&data, 50.0).unwrap(), 3.0, epsilon = 1e-12);
+        assert_abs_diff_eq!(percentile(&data, 90.0).unwrap(), 4.6, epsilon = 1e-12);
+        assert_abs_diff_eq!(percentile(&data, 100.0).unwrap(), 5.0, epsilon = 1e-12);
+        
+        // Input is left untouched
+        assert_eq!(data, vec![4.0, 1.0, 3.0, 2.0, 5.0]);
+        
+        assert!(percentile(&data, 101.0).is_err());
+        assert!(percentile(&[], 50.0).is_err());
+    }
+
+    #[test]
+    fn test_tukey_fences() {
+        let data = vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0];
+        // Q1 = 3, Q3 = 7, IQR = 4
+        let (lower, upper) = tukey_fences(&data, constants::DEFAULT_TUKEY_K).unwrap();
+        assert_abs_diff_eq!(lower, -3.0, epsilon = 1e-12);
+        assert_abs_diff_eq!(upper, 13.0, epsilon = 1e-12);
+        
+        assert!(tukey_fences(&data, -1.0).is_err());
+    }
+
+    #[test]
+    fn test_flag_tukey_outliers() {
+        let data = vec![10.0, 10.1, 9.9, 10.2, 9.8, 10.0, 15.0, 4.0];
+        let flagged = flag_tukey_outliers(&data, 1.5).unwrap();
+        assert_eq!(flagged, vec![6, 7]);
+        
+        let clean = vec![1.0, 2.0, 3.0, 4.0, 5.0];
+        assert!(flag_tukey_outliers(&clean, 1.5).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_huber_weight_properties() {
+        let mut rng = SplitMix64::new(901);
+        for c in [0.5, 1.345, 1.5, 3.0] {
+            // Residuals spanning tiny to huge magnitudes of either sign
+            for _ in 0..10_000 {
+                let magnitude = 10f64.powf(-12.0 + 24.0 * rng.next_f64());
+                let x = if rng.next_f64() < 0.5 { -magnitude } else { magnitude };
+                let weight = huber_weight(x, c);
+                
+                assert!(weight > 0.0 && weight <= 1.0, "w({}, {}) = {}", x, c, weight);
+                assert_eq!(weight == 1.0, x.abs() <= c, "w({}, {}) = {}", x, c, weight);
+                assert_eq!(huber_weight(-x, c), weight);
+                // Consistent with huber_psi wherever psi(x) / x is well conditioned
+                if x.abs() > 1e-6 {
+                    assert_abs_diff_eq!(weight, huber_psi(x, c) / x, epsilon = 1e-15);
+                }
+            }
+            
+            assert_eq!(huber_weight(0.0, c), 1.0);
+            for knot in [-c, c] {
+                assert_eq!(huber_weight(knot, c), 1.0);
+                assert!(1.0 - huber_weight(knot * (1.0 + 1e-12), c) < 1e-11);
+            }
+        }
+    }
+
+    #[test]
+    fn test_bisquare_psi_and_weight() {
+        let c = 4.685;
+        
+        // Zero, knots and beyond
+        assert_eq!(bisquare_psi(0.0, c), 0.0);
+        assert_eq!(bisquare_weight(0.0, c), 1.0);
+        assert_eq!(bisquare_psi(c, c), 0.0);
+        assert_eq!(bisquare_psi(-c, c), 0.0);
+        assert_eq!(bisquare_weight(c, c), 0.0);
+        assert_eq!(bisquare_psi(c + 1e-9, c), 0.0);
+        assert_eq!(bisquare_weight(-10.0, c), 0.0);
+        
+        // Inside the support: psi(x) = x w(x), odd, with its maximum at c / √5
+        let x = 2.0;
+        let weight = (1.0 - (x / c).powi(2)).powi(2);
+        assert_abs_diff_eq!(bisquare_weight(x, c), weight, epsilon = 1e-15);
+        assert_abs_diff_eq!(bisquare_weight(-x, c), weight, epsilon = 1e-15);
+        assert_abs_diff_eq!(bisquare_psi(x, c), x * weight, epsilon = 1e-15);
+        assert_abs_diff_eq!(bisquare_psi(-x, c), -x * weight, epsilon = 1e-15);
+        
+        let peak = c / 5.0_f64.sqrt();
+        assert!(bisquare_psi(peak, c) > bisquare_psi(peak - 1e-3, c));
+        assert!(bisquare_psi(peak, c) > bisquare_psi(peak + 1e-3, c));
+        
+        // Continuous at the knot
+        assert!(bisquare_psi(c - 1e-9, c).abs() < 1e-15);
+    }
+
+    #[test]
+    fn test_hampel_psi() {
+        let (a, b, c) = (1.5, 3.0, 4.5);
+        
+        // Zero and the linear region
+        assert_eq!(hampel_psi(0.0, a, b, c), 0.0);
+        assert_eq!(hampel_psi(1.0, a, b, c), 1.0);
+        assert_eq!(hampel_psi(-1.0, a, b, c), -1.0);
+        
+        // Knots
+        assert_eq!(hampel_psi(a, a, b, c), a);
+        assert_eq!(hampel_psi(b, a, b, c), a);
+        assert_eq!(hampel_psi(-b, a, b, c), -a);
+        assert_eq!(hampel_psi(c, a, b, c), 0.0);
+        
+        // Constant region and linear decay
+        assert_eq!(hampel_psi(2.0, a, b, c), a);
+        assert_eq!(hampel_psi(-2.5, a, b, c), -a);
+        assert_abs_diff_eq!(hampel_psi(3.75, a, b, c), 0.75, epsilon = 1e-15);
+        assert_abs_diff_eq!(hampel_psi(-3.75, a, b, c), -0.75, epsilon = 1e-15);
+        
+        // Rejection beyond c
+        assert_eq!(hampel_psi(4.6, a, b, c), 0.0);
+        assert_eq!(hampel_psi(-100.0, a, b, c), 0.0);
+        
+        // Continuous just either side of each knot
+        for knot in [a, b, c] {
+            let below = hampel_psi(knot - 1e-9, a, b, c);
+            let above = hampel_psi(knot + 1e-9, a, b, c);
+            assert!((below - above).abs() < 1e-8, "knot {}", knot);
+        }
     }
 
     #[test]
@@ -205,9 +2117,702 @@ mod tests {
         assert!(validate_floats(&[1.0, f64::INFINITY, 3.0], "test").is_err());
     }
 
+    #[test]
+    fn test_validate_floats_reports_all_invalid() {
+        let data = [1.0, f64::NAN, 3.0, f64::INFINITY, 5.0, f64::NEG_INFINITY, f64::NAN];
+        let invalid = find_invalid_values(&data);
+        let indices: Vec<usize> = invalid.iter().map(|&(i, _)| i).collect();
+        assert_eq!(indices, vec![1, 3, 5, 6]);
+        assert_eq!(invalid[1].1, f64::INFINITY);
+        assert!(find_invalid_values(&[1.0, 2.0]).is_empty());
+        
+        let message = validate_floats(&data, "results").unwrap_err().to_string();
+        assert_eq!(
+            message,
+            "Invalid input: results contains 4 invalid values: NaN at index 1, inf at index 3, -inf at index 5, NaN at index 6"
+        );
+        
+        // A single invalid value keeps the short message
+        let message = validate_floats(&[1.0, f64::NAN], "results").unwrap_err().to_string();
+        assert_eq!(message, "Invalid input: results contains invalid value at index 1: NaN");
+    }
+    
+    #[test]
+    fn test_validate_floats_truncates_message() {
+        let data: Vec<f64> = (0..100).map(|i| if i % 5 == 0 { f64::NAN } else { i as f64 }).collect();
+        assert_eq!(find_invalid_values(&data).len(), 20);
+        
+        let message = validate_floats(&data, "results").unwrap_err().to_string();
+        assert!(message.contains("results contains 20 invalid values"), "{}", message);
+        assert!(message.contains("NaN at index 45"), "{}", message);
+        assert!(!message.contains("index 50"), "{}", message);
+        assert!(message.ends_with(" and 10 more"), "{}", message);
+    }
+
     #[test]
     fn test_array_dimension_validation() {
         assert!(validate_array_dimensions(3, 3, "arr1", "arr2").is_ok());
-        assert!(validate_array_dimensions(3, 4, "arr1", "arr2").is_err());
+        
+        let err = validate_array_dimensions(250, 248, "results", "uncertainties").unwrap_err();
+        assert!(matches!(
+            &err,
+            CalculationError::DimensionMismatch { name1, len1: 250, name2, len2: 248 }
+                if name1 == "results" && name2 == "uncertainties"
+        ));
+        assert_eq!(
+            err.to_string(),
+            "Array dimension mismatch: results has 250 elements but uncertainties has 248"
+        );
+    }
+
+    #[test]
+    fn test_merge_result_sets() {
+        let merged = merge_result_sets(vec![
+            ("round_a".to_string(), vec![1.0, 2.0]),
+            ("round_b".to_string(), vec![]),
+            ("round_c".to_string(), vec![3.0]),
+        ]).unwrap();
+        
+        assert_eq!(merged.values, vec![1.0, 2.0, 3.0]);
+        assert_eq!(merged.labels, vec!["round_a", "round_a", "round_c"]);
+        assert_eq!(merged.batch_counts, vec![
+            ("round_a".to_string(), 2),
+            ("round_b".to_string(), 0),
+            ("round_c".to_string(), 1),
+        ]);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_merge_result_sets_invalid() {
+        let invalid = merge_result_sets(vec![
+            ("round_a".to_string(), vec![1.0]),
+            ("round_b".to_string(), vec![2.0, f64::NAN]),
+        ]);
+        match invalid {
+            Err(CalculationError::InvalidInput { message }) => assert!(message.contains("round_b")),
+            other => panic!("Expected InvalidInput, got {:?}", other),
+        }
+        
+        assert!(merge_result_sets(vec![
+            ("round_a".to_string(), vec![1.0]),
+            ("round_a".to_string(), vec![2.0]),
+        ]).is_err());
+    }
+
+    #[test]
+    fn test_t_distribution() {
+        assert_abs_diff_eq!(t_cdf(0.0, 5.0), 0.5, epsilon = 1e-12);
+        assert_abs_diff_eq!(t_cdf(2.228138851986, 10.0), 0.975, epsilon = 1e-9);
+        assert_abs_diff_eq!(t_quantile(0.975, 10.0).unwrap(), 2.228138851986, epsilon = 1e-8);
+        assert_abs_diff_eq!(t_quantile(0.95, 1.0).unwrap(), 6.313751514675, epsilon = 1e-8);
+        assert_abs_diff_eq!(t_quantile(0.025, 30.0).unwrap(), -2.042272456301, epsilon = 1e-8);
+        assert!(t_quantile(1.0, 10.0).is_err());
+        assert!(t_quantile(0.5, 0.0).is_err());
+    }
+
+    #[test]
+    fn test_normal_quantile() {
+        assert_abs_diff_eq!(normal_quantile(0.5).unwrap(), 0.0, epsilon = 1e-12);
+        assert_abs_diff_eq!(normal_quantile(0.975).unwrap(), 1.959963984540, epsilon = 1e-8);
+        assert_abs_diff_eq!(normal_quantile(0.8).unwrap(), 0.841621233573, epsilon = 1e-8);
+        assert_abs_diff_eq!(normal_quantile(0.01).unwrap(), -2.326347874041, epsilon = 1e-8);
+        assert_abs_diff_eq!(normal_quantile(1e-10).unwrap(), -6.361340902405, epsilon = 1e-7);
+        assert_abs_diff_eq!(normal_quantile(0.9).unwrap(), -normal_quantile(0.1).unwrap(), epsilon = 1e-12);
+        assert!(normal_quantile(0.0).is_err());
+        assert!(normal_quantile(1.0).is_err());
+        assert!(normal_quantile(f64::NAN).is_err());
+    }
+
+    #[test]
+    fn test_normal_cdf() {
+        assert_eq!(normal_cdf(0.0), 0.5);
+        assert_abs_diff_eq!(normal_cdf(1.959_963_984_540_054), 0.975, epsilon = 1e-12);
+        assert_abs_diff_eq!(normal_cdf(-1.0), 0.158_655_253_931_457_05, epsilon = 1e-12);
+        
+        // Far tails keep their relative precision
+        assert_relative_eq!(normal_cdf(-10.0), 7.619_853_024_160_593e-24, max_relative = 1e-9);
+        assert_eq!(normal_cdf(10.0), 1.0);
+        
+        for z in [-3.0, -0.5, 0.7, 2.5] {
+            assert_abs_diff_eq!(normal_cdf(z) + normal_cdf(-z), 1.0, epsilon = 1e-14);
+        }
+    }
+    
+    #[test]
+    fn test_anderson_darling_normal() {
+        // Reference values computed independently from the A² definition with math.erfc
+        let data = [2.1, 3.4, 1.9, 5.6, 4.2, 3.3, 2.8, 4.9, 3.9, 3.1];
+        let result = anderson_darling_normal(&data, 0.05).unwrap();
+        assert_abs_diff_eq!(result.statistic, 0.179_663_453_363_575_14, epsilon = 1e-10);
+        assert_eq!(result.critical_value, 0.787);
+        assert!(result.is_normal);
+        
+        let skewed = [0.1, 0.2, 0.1, 0.3, 0.2, 0.1, 5.0, 0.4, 0.1, 0.2];
+        let result = anderson_darling_normal(&skewed, 0.01).unwrap();
+        assert_abs_diff_eq!(result.statistic, 3.055_146_216_985_348_5, epsilon = 1e-10);
+        assert!(!result.is_normal);
+    }
+    
+    #[test]
+    fn test_anderson_darling_simulated() {
+        let n_runs = 200;
+        let mut n_normal_pass = 0;
+        let mut n_exponential_fail = 0;
+        
+        for seed in 0..n_runs {
+            let mut rng = SplitMix64::new(seed);
+            let normal: Vec<f64> = (0..50).map(|_| 10.0 + 2.0 * rng.next_normal()).collect();
+            let exponential: Vec<f64> = (0..50).map(|_| -(1.0 - rng.next_f64()).ln()).collect();
+            
+            if anderson_darling_normal(&normal, 0.05).unwrap().is_normal {
+                n_normal_pass += 1;
+            }
+            if !anderson_darling_normal(&exponential, 0.05).unwrap().is_normal {
+                n_exponential_fail += 1;
+            }
+        }
+        
+        // Nominal 95 % acceptance for normal data; near-certain rejection of exponential data
+        assert!(n_normal_pass >= 180, "normal data passed {} of {}", n_normal_pass, n_runs);
+        assert!(n_exponential_fail >= 190, "exponential data failed {} of {}", n_exponential_fail, n_runs);
+    }
+    
+    #[test]
+    fn test_anderson_darling_invalid() {
+        let data = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0];
+        assert!(matches!(
+            anderson_darling_normal(&data, 0.05),
+            Err(CalculationError::InsufficientData { required: 8, actual: 7 })
+        ));
+        
+        let data = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0];
+        assert!(anderson_darling_normal(&data, 0.05).is_ok());
+        assert!(anderson_darling_normal(&data, 0.2).is_err());
+        assert!(anderson_darling_normal(&[3.0; 8], 0.05).is_err());
+        assert!(anderson_darling_normal(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, f64::NAN], 0.05).is_err());
+    }
+    
+    #[test]
+    fn test_shapiro_wilk_reference_values() {
+        // Shapiro and Wilk (1965) weights; shapiro.test gives W = 0.78881, p = 0.006704
+        let weights = [148.0, 154.0, 158.0, 160.0, 161.0, 162.0, 166.0, 170.0, 182.0, 195.0, 236.0];
+        let result = shapiro_wilk(&weights).unwrap();
+        assert_abs_diff_eq!(result.statistic, 0.78881, epsilon = 1e-4);
+        assert_abs_diff_eq!(result.p_value, 0.006704, epsilon = 1e-4);
+        
+        let result = shapiro_wilk(&[1.0, 2.0, 3.0, 4.0, 5.0]).unwrap();
+        assert_abs_diff_eq!(result.statistic, 0.98676, epsilon = 1e-4);
+        assert_abs_diff_eq!(result.p_value, 0.96717, epsilon = 1e-4);
+        
+        let evenly_spaced: Vec<f64> = (1..=20).map(|i| i as f64).collect();
+        let result = shapiro_wilk(&evenly_spaced).unwrap();
+        assert_abs_diff_eq!(result.statistic, 0.96038, epsilon = 1e-4);
+        assert_abs_diff_eq!(result.p_value, 0.55137, epsilon = 1e-4);
+        
+        // n = 3 is exact: W = 4.5 / (42 / 9), p = (6/π)(asin √W - π/3)
+        let result = shapiro_wilk(&[4.0, 1.0, 2.0]).unwrap();
+        let w = 4.5 / (42.0 / 9.0);
+        assert_abs_diff_eq!(result.statistic, w, epsilon = 1e-12);
+        assert_abs_diff_eq!(
+            result.p_value,
+            6.0 / std::f64::consts::PI * (w.sqrt().asin() - std::f64::consts::FRAC_PI_3),
+            epsilon = 1e-12
+        );
+        assert_abs_diff_eq!(shapiro_wilk(&[1.0, 2.0, 3.0]).unwrap().p_value, 1.0, epsilon = 1e-12);
+    }
+    
+    #[test]
+    fn test_shapiro_wilk_ties_and_invariance() {
+        // Results reported to whole units produce ties; AS R94, the algorithm
+        // behind scipy.stats.shapiro, gives W = 0.95200, p = 0.59212
+        let tied = [2.0, 3.0, 3.0, 3.0, 4.0, 4.0, 5.0, 5.0, 5.0, 5.0, 6.0, 7.0, 7.0, 8.0];
+        let result = shapiro_wilk(&tied).unwrap();
+        assert_abs_diff_eq!(result.statistic, 0.95200, epsilon = 1e-4);
+        assert_abs_diff_eq!(result.p_value, 0.59212, epsilon = 1e-4);
+        
+        // W depends on neither location, scale nor input order
+        let transformed: Vec<f64> = tied.iter().rev().map(|&x| 1000.0 + 0.01 * x).collect();
+        let shifted = shapiro_wilk(&transformed).unwrap();
+        assert_abs_diff_eq!(shifted.statistic, result.statistic, epsilon = 1e-10);
+        assert_abs_diff_eq!(shifted.p_value, result.p_value, epsilon = 1e-8);
+    }
+    
+    #[test]
+    fn test_shapiro_wilk_simulated() {
+        let n_runs = 200;
+        let mut n_normal_rejected = 0;
+        let mut n_exponential_rejected = 0;
+        
+        for seed in 0..n_runs {
+            let mut rng = SplitMix64::new(seed);
+            let normal: Vec<f64> = (0..30).map(|_| 5.0 + rng.next_normal()).collect();
+            let exponential: Vec<f64> = (0..30).map(|_| -(1.0 - rng.next_f64()).ln()).collect();
+            
+            if shapiro_wilk(&normal).unwrap().p_value < 0.05 {
+                n_normal_rejected += 1;
+            }
+            if shapiro_wilk(&exponential).unwrap().p_value < 0.05 {
+                n_exponential_rejected += 1;
+            }
+        }
+        
+        assert!(n_normal_rejected <= 20, "normal data rejected {} of {}", n_normal_rejected, n_runs);
+        assert!(n_exponential_rejected >= 180, "exponential data rejected {} of {}", n_exponential_rejected, n_runs);
+    }
+    
+    #[test]
+    fn test_shapiro_wilk_invalid() {
+        assert!(matches!(
+            shapiro_wilk(&[1.0, 2.0]),
+            Err(CalculationError::InsufficientData { required: 3, actual: 2 })
+        ));
+        assert!(shapiro_wilk(&vec![1.0; MAX_SHAPIRO_WILK + 1]).is_err());
+        assert!(shapiro_wilk(&[2.0, 2.0, 2.0, 2.0]).is_err());
+        assert!(shapiro_wilk(&[1.0, f64::INFINITY, 3.0]).is_err());
+    }
+    
+    #[test]
+    fn test_grubbs_critical_values() {
+        // Published critical values (e.g. ASTM E178)
+        assert_abs_diff_eq!(grubbs_critical_value(10, 0.05, GrubbsAlternative::TwoSided).unwrap(), 2.290, epsilon = 1e-3);
+        assert_abs_diff_eq!(grubbs_critical_value(20, 0.05, GrubbsAlternative::TwoSided).unwrap(), 2.709, epsilon = 1e-3);
+        assert_abs_diff_eq!(grubbs_critical_value(10, 0.05, GrubbsAlternative::Max).unwrap(), 2.176, epsilon = 1e-3);
+        assert_abs_diff_eq!(grubbs_critical_value(10, 0.05, GrubbsAlternative::Min).unwrap(), 2.176, epsilon = 1e-3);
+        assert!(grubbs_critical_value(2, 0.05, GrubbsAlternative::TwoSided).is_err());
+    }
+
+    #[test]
+    fn test_grubbs_test() {
+        let data = vec![10.1, 9.9, 10.0, 10.2, 9.8, 10.1, 9.9, 10.0, 15.0];
+        let result = grubbs_test(&data, 0.05, GrubbsAlternative::TwoSided).unwrap();
+        assert_eq!(result.index, 8);
+        assert!(result.is_outlier);
+        
+        let identical = vec![1.0; 5];
+        assert!(!grubbs_test(&identical, 0.05, GrubbsAlternative::TwoSided).unwrap().is_outlier);
+    }
+    
+    #[test]
+    fn test_grubbs_test_one_sided() {
+        let data = [10.1, 9.9, 10.0, 10.2, 9.8, 10.1, 9.9, 10.0, 15.0, 9.7];
+        
+        let max = grubbs_test(&data, 0.05, GrubbsAlternative::Max).unwrap();
+        assert_eq!(max.index, 8);
+        assert!(max.is_outlier);
+        
+        // The low side is tested even though the high outlier is further away
+        let min = grubbs_test(&data, 0.05, GrubbsAlternative::Min).unwrap();
+        assert_eq!(min.index, 9);
+        assert!(min.statistic > 0.0);
+        assert!(!min.is_outlier);
+        
+        assert_eq!("max".parse::<GrubbsAlternative>().unwrap(), GrubbsAlternative::Max);
+        assert_eq!("two_sided".parse::<GrubbsAlternative>().unwrap(), GrubbsAlternative::TwoSided);
+        assert!("both".parse::<GrubbsAlternative>().is_err());
+    }
+    
+    #[test]
+    fn test_grubbs_iterative() {
+        // 30.0 masks 13.0: only after it is removed does 13.0 become significant
+        let data = [10.1, 9.9, 10.0, 10.2, 9.8, 10.1, 30.0, 9.9, 10.0, 13.0, 10.05];
+        let single = grubbs_test(&data, 0.05, GrubbsAlternative::TwoSided).unwrap();
+        assert_eq!(single.index, 6);
+        
+        let without_first: Vec<f64> = data.iter().copied().filter(|&x| x != 30.0).collect();
+        assert!(grubbs_test(&without_first, 0.05, GrubbsAlternative::TwoSided).unwrap().is_outlier);
+        
+        assert_eq!(grubbs_iterative(&data, 0.05, GrubbsAlternative::TwoSided, 10).unwrap(), vec![6, 9]);
+        assert_eq!(grubbs_iterative(&data, 0.05, GrubbsAlternative::TwoSided, 1).unwrap(), vec![6]);
+        assert!(grubbs_iterative(&data, 0.05, GrubbsAlternative::TwoSided, 0).unwrap().is_empty());
+        
+        // Clean data loses nothing
+        let clean = [10.1, 9.9, 10.0, 10.2, 9.8];
+        assert!(grubbs_iterative(&clean, 0.05, GrubbsAlternative::TwoSided, 5).unwrap().is_empty());
+        
+        assert!(grubbs_iterative(&[1.0, 2.0], 0.05, GrubbsAlternative::TwoSided, 1).is_err());
+    }
+
+    #[test]
+    fn test_cochran_critical_values() {
+        // ISO 5725-2 Table 4 (p laboratories, n replicates)
+        let table = [
+            (3, 2, 0.05, 0.967), (4, 2, 0.05, 0.906), (5, 2, 0.05, 0.841), (6, 2, 0.05, 0.781),
+            (10, 2, 0.05, 0.602), (3, 3, 0.05, 0.871), (4, 3, 0.05, 0.768), (6, 3, 0.05, 0.616),
+            (4, 4, 0.05, 0.684), (3, 2, 0.01, 0.993), (5, 2, 0.01, 0.928), (10, 2, 0.01, 0.718),
+        ];
+        
+        for (p, n, alpha, expected) in table {
+            let critical = cochran_test(&vec![1.0; p], n, alpha).unwrap().critical_value;
+            assert_abs_diff_eq!(critical, expected, epsilon = 1e-3);
+        }
+    }
+    
+    #[test]
+    fn test_cochran_test() {
+        // Duplicate measurements; laboratory 3 is a straggler but not an outlier
+        let variances = [0.010, 0.012, 0.009, 0.300, 0.011];
+        let straggler = cochran_test(&variances, 2, 0.05).unwrap();
+        assert_eq!(straggler.index, 3);
+        assert_abs_diff_eq!(straggler.statistic, 0.3 / 0.342, epsilon = 1e-12);
+        assert!(straggler.is_outlier);
+        assert!(!cochran_test(&variances, 2, 0.01).unwrap().is_outlier);
+        
+        assert!(!cochran_test(&[0.0, 0.0, 0.0], 2, 0.05).unwrap().is_outlier);
+    }
+    
+    #[test]
+    fn test_cochran_test_invalid() {
+        assert!(matches!(
+            cochran_test(&[0.1], 2, 0.05),
+            Err(CalculationError::InsufficientData { required: 2, actual: 1 })
+        ));
+        assert!(cochran_test(&[0.1, -0.2, 0.3], 2, 0.05).is_err());
+        assert!(cochran_test(&[0.1, 0.2, 0.3], 1, 0.05).is_err());
+        assert!(cochran_test(&[0.1, 0.2, 0.3], 2, 1.0).is_err());
+        assert!(cochran_test(&[0.1, f64::NAN, 0.3], 2, 0.05).is_err());
+    }
+
+    #[test]
+    fn test_split_mix_normal_moments() {
+        let mut rng = SplitMix64::new(1234);
+        let values: Vec<f64> = (0..20_000).map(|_| rng.next_normal()).collect();
+        let mean = values.iter().sum::<f64>() / values.len() as f64;
+        let variance = values.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / values.len() as f64;
+        
+        assert_abs_diff_eq!(mean, 0.0, epsilon = 0.03);
+        assert_abs_diff_eq!(variance, 1.0, epsilon = 0.05);
+        
+        // Same seed, same sequence
+        assert_eq!(SplitMix64::new(7).next_u64(), SplitMix64::new(7).next_u64());
+    }
+
+    #[test]
+    fn test_effective_sample_size() {
+        assert_abs_diff_eq!(effective_sample_size(&[1.0; 8]).unwrap(), 8.0, epsilon = 1e-12);
+        assert_abs_diff_eq!(effective_sample_size(&[0.0, 0.7, 0.0]).unwrap(), 1.0, epsilon = 1e-12);
+        
+        // Two results down-weighted to 0.5: (5)² / (4 + 0.5)
+        let n_eff = effective_sample_size(&[1.0, 1.0, 1.0, 1.0, 0.5, 0.5]).unwrap();
+        assert_abs_diff_eq!(n_eff, 25.0 / 4.5, epsilon = 1e-12);
+        assert!(n_eff < 6.0);
+        
+        assert!(effective_sample_size(&[]).is_err());
+        assert!(effective_sample_size(&[0.0, 0.0]).is_err());
+        assert!(effective_sample_size(&[1.0, -0.5]).is_err());
+        assert!(effective_sample_size(&[1.0, f64::NAN]).is_err());
+    }
+
+    #[test]
+    fn test_rounding_resolution() {
+        assert_abs_diff_eq!(rounding_resolution(&[12.3, 12.5, 12.0, 0.0]).unwrap(), 0.1, epsilon = 1e-15);
+        assert_abs_diff_eq!(rounding_resolution(&[1200.0, 300.0]).unwrap(), 100.0, epsilon = 1e-12);
+        assert_abs_diff_eq!(rounding_resolution(&[-0.05, 0.125]).unwrap(), 0.001, epsilon = 1e-15);
+        
+        assert!(rounding_resolution(&[0.0, 0.0]).is_err());
+        assert!(rounding_resolution(&[]).is_err());
+        assert!(rounding_resolution(&[1.0, f64::NAN]).is_err());
+    }
+
+    #[test]
+    fn test_scale_vs_resolution() {
+        // Results reported to one significant figure
+        let resolution = rounding_resolution(&[3.0, 4.0, 3.0, 4.0, 4.0]).unwrap();
+        let (ratio, limited) = scale_vs_resolution(0.5, resolution).unwrap();
+        assert_abs_diff_eq!(ratio, 0.5, epsilon = 1e-12);
+        assert!(limited);
+        
+        // The limit itself is not resolution-limited
+        assert!(!scale_vs_resolution(0.2, 0.1).unwrap().1);
+        assert!(scale_vs_resolution(0.19, 0.1).unwrap().1);
+        
+        assert!(scale_vs_resolution(-1.0, 0.1).is_err());
+        assert!(scale_vs_resolution(1.0, 0.0).is_err());
+    }
+
+    #[test]
+    fn test_weighted_median() {
+        // Equal weights reproduce the median for odd and even lengths
+        for values in [vec![3.0, 1.0, 2.0], vec![4.0, 1.0, 3.0, 2.0], vec![5.5, -1.0, 2.0, 2.0, 7.0, 0.5]] {
+            let weights = vec![0.7; values.len()];
+            let expected = median(&mut values.clone()).unwrap();
+            assert_abs_diff_eq!(weighted_median(&values, &weights).unwrap(), expected, epsilon = 1e-12);
+        }
+        
+        // A single dominating weight
+        assert_eq!(weighted_median(&[1.0, 2.0, 3.0, 100.0], &[1.0, 1.0, 1.0, 10.0]).unwrap(), 100.0);
+        
+        // Cumulative weight exactly half at 2.0: midpoint with the next weighted value
+        assert_eq!(weighted_median(&[1.0, 2.0, 4.0, 9.0], &[1.0, 2.0, 3.0, 0.0]).unwrap(), 3.0);
+        
+        // Zero weights are ignored
+        assert_eq!(weighted_median(&[1.0, 50.0, 2.0, 3.0], &[1.0, 0.0, 1.0, 1.0]).unwrap(), 2.0);
+        
+        assert!(weighted_median(&[1.0, 2.0], &[1.0]).is_err());
+        assert!(weighted_median(&[1.0, 2.0], &[0.0, 0.0]).is_err());
+        assert!(weighted_median(&[1.0, 2.0], &[1.0, -1.0]).is_err());
+        assert!(weighted_median(&[1.0, f64::NAN], &[1.0, 1.0]).is_err());
+        assert!(weighted_median(&[], &[]).is_err());
+    }
+
+    #[test]
+    fn test_sn_scale() {
+        // Inner high medians are 2, 1, 1, 1, 2 and their low median is 1
+        assert_abs_diff_eq!(sn_scale(&[1.0, 2.0, 3.0, 4.0, 5.0]).unwrap(), 1.351 * 1.1926, epsilon = 1e-12);
+        assert_abs_diff_eq!(sn_scale(&[3.0, 1.0]).unwrap(), 0.743 * 1.1926 * 2.0, epsilon = 1e-12);
+        
+        // Location-free and scale-equivariant
+        let data = [0.3, -1.2, 2.5, 0.9, 1.7, -0.4, 0.0, 3.1, 1.1, 0.6, -2.2];
+        let shifted: Vec<f64> = data.iter().map(|x| 2.0 * x + 100.0).collect();
+        assert_abs_diff_eq!(sn_scale(&shifted).unwrap(), 2.0 * sn_scale(&data).unwrap(), epsilon = 1e-12);
+        
+        // Consistent for the normal standard deviation
+        let mut rng = SplitMix64::new(5);
+        let mut values: Vec<f64> = (0..1000).map(|_| 3.0 * rng.next_normal()).collect();
+        assert_abs_diff_eq!(sn_scale(&values).unwrap(), 3.0, epsilon = 0.25);
+        
+        // Stays bounded with 40 % gross outliers
+        for value in values.iter_mut().take(400) {
+            *value = 1e9;
+        }
+        assert!(sn_scale(&values).unwrap() < 20.0);
+        
+        assert!(sn_scale(&[1.0]).is_err());
+        assert!(sn_scale(&[1.0, f64::INFINITY]).is_err());
+    }
+
+    #[test]
+    fn test_quantile_methods_match_numpy() {
+        // np.quantile(data, qs, method=...) for each method
+        let data = [7.0, 1.0, 3.0, 10.0, 4.0, 2.5];
+        let qs = [0.0, 0.1, 0.25, 0.5, 0.8, 1.0];
+        let expected = [
+            (QuantileMethod::Linear, [1.0, 1.75, 2.625, 3.5, 7.0, 10.0]),
+            (QuantileMethod::Lower, [1.0, 1.0, 2.5, 3.0, 7.0, 10.0]),
+            (QuantileMethod::Higher, [1.0, 2.5, 3.0, 4.0, 7.0, 10.0]),
+            (QuantileMethod::Midpoint, [1.0, 1.75, 2.75, 3.5, 7.0, 10.0]),
+        ];
+        
+        for (method, values) in expected {
+            let result = quantiles(&data, &qs, method).unwrap();
+            for (&q, (&actual, &value)) in qs.iter().zip(result.iter().zip(values.iter())) {
+                assert_abs_diff_eq!(actual, value, epsilon = 1e-12);
+                assert_abs_diff_eq!(quantile(&data, q, method).unwrap(), value, epsilon = 1e-12);
+            }
+            assert_eq!(quantile(&[5.0], 0.3, method).unwrap(), 5.0);
+        }
+        
+        // The input is left unsorted
+        assert_eq!(data, [7.0, 1.0, 3.0, 10.0, 4.0, 2.5]);
+        assert_abs_diff_eq!(percentile(&data, 25.0).unwrap(), 2.625, epsilon = 1e-12);
+        
+        assert_eq!("midpoint".parse::<QuantileMethod>().unwrap(), QuantileMethod::Midpoint);
+        assert!("nearest".parse::<QuantileMethod>().is_err());
+        assert!(quantile(&data, 1.5, QuantileMethod::Linear).is_err());
+        assert!(quantile(&data, f64::NAN, QuantileMethod::Linear).is_err());
+        assert!(quantiles(&data, &[0.5, -0.1], QuantileMethod::Lower).is_err());
+        assert!(quantile(&[], 0.5, QuantileMethod::Linear).is_err());
+        assert!(quantile(&[1.0, f64::NAN], 0.5, QuantileMethod::Linear).is_err());
+    }
+
+    #[test]
+    fn test_running_stats_large_offset() {
+        let data = [1e9 + 4.0, 1e9 + 7.0, 1e9 + 13.0, 1e9 + 16.0];
+        let stats = running_stats(&data);
+        
+        assert_eq!(stats.count(), 4);
+        assert_abs_diff_eq!(stats.mean().unwrap(), 1e9 + 10.0, epsilon = 1e-6);
+        assert_abs_diff_eq!(stats.variance().unwrap(), 30.0, epsilon = 1e-6);
+        assert_eq!(stats.min(), Some(1e9 + 4.0));
+        assert_eq!(stats.max(), Some(1e9 + 16.0));
+        
+        // The sum-of-squares formula cancels catastrophically at this offset
+        let n = data.len() as f64;
+        let sum: f64 = data.iter().sum();
+        let sum_squares: f64 = data.iter().map(|x| x * x).sum();
+        let naive = (sum_squares - sum * sum / n) / (n - 1.0);
+        assert!((naive - 30.0).abs() > 1.0);
+        
+        let empty = RunningStats::new();
+        assert_eq!((empty.mean(), empty.variance(), empty.min()), (None, None, None));
+        assert_eq!(running_stats(&[2.0]).variance(), None);
+    }
+
+    #[test]
+    fn test_running_stats_merge() {
+        let mut rng = SplitMix64::new(17);
+        let data: Vec<f64> = (0..101).map(|_| 50.0 + 3.0 * rng.next_normal()).collect();
+        let whole = running_stats(&data);
+        
+        let mut merged = running_stats(&data[..37]);
+        merged.merge(&running_stats(&data[37..]));
+        
+        assert_eq!(merged.count(), whole.count());
+        assert_abs_diff_eq!(merged.mean().unwrap(), whole.mean().unwrap(), epsilon = 1e-12);
+        assert_abs_diff_eq!(merged.variance().unwrap(), whole.variance().unwrap(), epsilon = 1e-10);
+        assert_eq!((merged.min(), merged.max()), (whole.min(), whole.max()));
+        
+        // Merging with an empty accumulator changes nothing
+        let mut empty = RunningStats::new();
+        empty.merge(&whole);
+        assert_eq!(empty, whole);
+        merged.merge(&RunningStats::new());
+        assert_eq!(merged.count(), 101);
+    }
+
+    #[test]
+    fn test_median_matches_sort_based() {
+        fn sorted_median(data: &[f64]) -> f64 {
+            let mut sorted = data.to_vec();
+            sorted.sort_by(|a, b| a.total_cmp(b));
+            let len = sorted.len();
+            if len.is_multiple_of(2) {
+                (sorted[len / 2 - 1] + sorted[len / 2]) / 2.0
+            } else {
+                sorted[len / 2]
+            }
+        }
+        
+        let mut rng = SplitMix64::new(21);
+        let mut scratch = Vec::new();
+        for len in (1..40).chain([100, 101, 1000, 1001]) {
+            // Rounded values give ties around the middle
+            let data: Vec<f64> = (0..len).map(|_| (rng.next_normal() * 4.0).round()).collect();
+            let expected = sorted_median(&data);
+            
+            assert_eq!(median(&mut data.clone()), Some(expected), "length {}", len);
+            assert_eq!(median_with_scratch(&data, &mut scratch), Some(expected));
+        }
+        
+        // Even length averages the two middle values
+        assert_eq!(median(&mut [4.0, 1.0, 3.0, 2.0]), Some(2.5));
+        assert_eq!(median_with_scratch(&[], &mut scratch), None);
+    }
+
+    #[test]
+    fn test_mad_with_scratch() {
+        let data = [1.0, 2.0, 3.0, 4.0, 100.0];
+        let mut scratch = vec![9.0; 64];
+        assert_eq!(mad_with_scratch(&data, 3.0, &mut scratch).unwrap(), mad(&data, 3.0).unwrap());
+        assert_eq!(mad(&data, 3.0).unwrap(), 1.0);
+        assert_eq!(scratch.len(), data.len());
+        assert!(mad_with_scratch(&[], 0.0, &mut scratch).is_err());
+    }
+
+    #[test]
+    fn test_median_of_and_mad_of_leave_input_unchanged() {
+        let data: [f64; 8] = [7.5, -1.0, 3.25, 100.0, 0.0, 3.25, 2.0, -0.0];
+        let bits: Vec<u64> = data.iter().map(|x| x.to_bits()).collect();
+        
+        let mut copy = data.to_vec();
+        let expected_median = median(&mut copy).unwrap();
+        let expected_mad = mad(&data, expected_median).unwrap();
+        
+        assert_eq!(median_of(&data), Some(expected_median));
+        assert_eq!(mad_of(&data).unwrap(), expected_mad);
+        assert_eq!(data.iter().map(|x| x.to_bits()).collect::<Vec<_>>(), bits);
+        
+        // Odd length, and the even-length midpoint (2 + 3.25) / 2 above
+        assert_eq!(median_of(&[3.0, 1.0, 2.0]), Some(2.0));
+        assert_eq!(expected_median, 2.625);
+        
+        assert_eq!(median_of(&[]), None);
+        assert!(mad_of(&[]).is_err());
+    }
+
+    #[test]
+    fn test_chi_squared_cdf() {
+        // Two degrees of freedom: 1 - exp(-x / 2)
+        for x in [0.1, 1.0, 4.0, 20.0] {
+            assert_abs_diff_eq!(chi_squared_cdf(x, 2.0), 1.0 - (-x / 2.0).exp(), epsilon = 1e-12);
+        }
+        
+        // 95 % point of chi-squared with 9 degrees of freedom, and P(4.5, 4.5) by its series
+        assert_abs_diff_eq!(chi_squared_cdf(16.918_977_604_620_45, 9.0), 0.95, epsilon = 1e-10);
+        assert_abs_diff_eq!(chi_squared_cdf(9.0, 9.0), 0.562_725_811_086_133, epsilon = 1e-10);
+        assert_eq!(chi_squared_cdf(0.0, 9.0), 0.0);
+    }
+
+    #[test]
+    fn test_terminal_digit_distribution() {
+        // Every digit once: no preference
+        let uniform: Vec<f64> = (0..10).map(|d| 1.0 + d as f64 / 100.0).collect();
+        let distribution = terminal_digit_distribution(&uniform, 2).unwrap();
+        assert_eq!(distribution.counts, [1; 10]);
+        assert_abs_diff_eq!(distribution.chi_square, 0.0, epsilon = 1e-12);
+        assert_abs_diff_eq!(distribution.p_value, 1.0, epsilon = 1e-12);
+        
+        // Results rounded to 0 and 5
+        let mut preferred = vec![12.35, 12.40, 12.45, 12.50, -12.55, 12.60];
+        preferred.extend_from_slice(&[12.31, 12.42, 12.47, 12.58]);
+        let preferred: Vec<f64> = preferred.iter().cycle().take(60).copied().collect();
+        let distribution = terminal_digit_distribution(&preferred, 2).unwrap();
+        assert_eq!(distribution.counts, [18, 6, 6, 0, 0, 18, 0, 6, 6, 0]);
+        assert_abs_diff_eq!(distribution.chi_square, 72.0, epsilon = 1e-9);
+        assert!(distribution.p_value < 1e-10);
+        
+        assert!(terminal_digit_distribution(&[], 2).is_err());
+        assert!(terminal_digit_distribution(&[1.0, f64::NAN], 2).is_err());
+        assert!(terminal_digit_distribution(&[1.0], 16).is_err());
+        
+        // Too large for the terminal digit to be exact, or overflowing to infinity
+        assert!(matches!(terminal_digit_distribution(&[1e6], 15), Err(CalculationError::InvalidInput { .. })));
+        assert!(matches!(terminal_digit_distribution(&[1e300], 15), Err(CalculationError::InvalidInput { .. })));
+        assert!(terminal_digit_distribution(&[9e15], 0).is_ok());
+    }
+
+    #[test]
+    fn test_skewness_and_kurtosis() {
+        // Values of the bias-corrected formulas (scipy.stats skew / kurtosis with bias=False)
+        let data = [2.1, 3.4, 1.9, 5.6, 2.8, 9.7, 3.3, 2.5, 4.1, 3.0];
+        assert_abs_diff_eq!(skewness(&data).unwrap(), 2.115_733_890_708_945, epsilon = 1e-12);
+        assert_abs_diff_eq!(kurtosis_excess(&data).unwrap(), 4.859_871_061_816_572, epsilon = 1e-12);
+        
+        // Symmetric data has no skewness; mirrored data flips its sign
+        assert_abs_diff_eq!(skewness(&[1.0, 2.0, 3.0, 4.0, 5.0]).unwrap(), 0.0, epsilon = 1e-12);
+        let mirrored: Vec<f64> = data.iter().map(|x| -x).collect();
+        assert_abs_diff_eq!(skewness(&mirrored).unwrap(), -skewness(&data).unwrap(), epsilon = 1e-12);
+        
+        assert!(matches!(skewness(&[1.0, 2.0]), Err(CalculationError::InsufficientData { required: 3, actual: 2 })));
+        assert!(matches!(kurtosis_excess(&[1.0, 2.0, 3.0]), Err(CalculationError::InsufficientData { required: 4, actual: 3 })));
+        assert!(matches!(skewness(&[2.0, 2.0, 2.0]), Err(CalculationError::DivisionByZero)));
+        assert!(kurtosis_excess(&[1.0, 2.0, f64::NAN, 4.0]).is_err());
+    }
+
+    #[test]
+    fn test_medcouple() {
+        // Reference values of R robustbase::mc, the second with ties at the median
+        assert_abs_diff_eq!(medcouple(&[1.0, 2.0, 7.0, 9.0, 10.0]).unwrap(), -1.0 / 3.0, epsilon = 1e-12);
+        assert_abs_diff_eq!(medcouple(&[1.0, 2.0, 2.0, 3.0, 4.0]).unwrap(), 1.0 / 6.0, epsilon = 1e-12);
+        
+        // Values from the pairwise definition, including ties at the median
+        let data = [2.1, 3.4, 1.9, 5.6, 2.8, 9.7, 3.3, 2.5, 4.1, 3.0];
+        assert_abs_diff_eq!(medcouple(&data).unwrap(), 0.25, epsilon = 1e-12);
+        assert_abs_diff_eq!(medcouple(&[1.0, 2.0, 2.0, 2.0, 3.0, 10.0]).unwrap(), 7.0 / 18.0, epsilon = 1e-12);
+        assert_abs_diff_eq!(medcouple(&[1.0, 1.0, 2.0, 4.0, 8.0, 16.0, 32.0]).unwrap(), 0.6, epsilon = 1e-12);
+        
+        // Symmetric data, and a single outlier does not dominate it
+        let symmetric: Vec<f64> = (1..=10).map(f64::from).collect();
+        assert_abs_diff_eq!(medcouple(&symmetric).unwrap(), 0.0, epsilon = 1e-12);
+        assert_abs_diff_eq!(medcouple(&[1.0, 2.0, 3.0, 4.0, 100.0]).unwrap(), 0.0, epsilon = 1e-12);
+        
+        let mirrored: Vec<f64> = data.iter().map(|x| -x).collect();
+        assert_abs_diff_eq!(medcouple(&mirrored).unwrap(), -0.25, epsilon = 1e-12);
+        
+        assert!(medcouple(&[1.0, 2.0]).is_err());
+        assert!(medcouple(&[1.0, 2.0, f64::INFINITY]).is_err());
+    }
+
+    #[test]
+    fn test_calculation_warning_code_and_message() {
+        let warning = CalculationWarning::SlowConvergence { iterations: 95, max_iterations: 100 };
+        assert_eq!(warning.code(), "slow_convergence");
+        assert_eq!(warning.to_string(), "Converged after 95 of at most 100 iterations");
+        
+        let json = serde_json::to_value(CalculationWarning::HeavyTies { tied: 4, total: 10 }).unwrap();
+        assert_eq!(json, serde_json::json!({ "code": "heavy_ties", "tied": 4, "total": 10 }));
+        
+        let floored = CalculationWarning::ScaleFloored { minimum: constants::MIN_ROBUST_SCALE };
+        assert_eq!(floored.code(), "scale_floored");
+        assert_eq!(floored.to_string(), "Robust scale fell below 1e-10 and was floored to it");
+    }
+}
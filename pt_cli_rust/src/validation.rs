@@ -0,0 +1,121 @@
+//! Submission validation module
+//!
+//! This module implements checks on how participant results were reported,
+//! as opposed to the statistical screening of their values.
+
+use crate::utils::{CalculationError, validate_floats};
+use ndarray::ArrayView1;
+
+/// Apparent significant figures of each result and whether it meets the minimum
+#[derive(Debug, Clone, PartialEq)]
+pub struct SignificantFiguresCheck {
+    /// Apparent number of significant figures of each result
+    pub counts: Vec<usize>,
+    /// True for each result reported with fewer than the required significant figures
+    pub violations: Vec<bool>,
+}
+
+/// Infer the apparent number of significant figures of a reported value
+///
+/// The value is formatted as its shortest round-trip decimal string, which for
+/// f64 never uses exponent notation, so 1.23e-7 is read as 0.000000123 (3).
+/// Leading zeros are not significant. Trailing zeros of an integer are
+/// ambiguous (100 may have one, two or three significant figures), so the
+/// minimum defensible count is returned: 100 and 1200.0 count as 1 and 2.
+/// Zero has no significant digits and counts as 0.
+pub fn significant_figures(value: f64) -> usize {
+    let formatted = format!("{}", value.abs());
+    let (integer, fraction) = formatted.split_once('.').unwrap_or((&formatted, ""));
+
+    let digits = if fraction.is_empty() {
+        integer.trim_end_matches('0')
+    } else {
+        // The shortest representation has no trailing fractional zeros
+        &formatted
+    };
+
+    digits.chars()
+        .filter(|c| c.is_ascii_digit())
+        .skip_while(|&c| c == '0')
+        .count()
+}
+
+/// Check that participant results are reported with enough significant figures
+///
+/// Results reported too coarsely distort the consensus statistics. Zero results
+/// are never flagged, as their reporting precision cannot be judged.
+///
+/// # Arguments
+/// * `results` - Array view of participant results
+/// * `min_sig_figs` - Minimum number of significant figures required (at least 1)
+///
+/// # Returns
+/// * `Ok(SignificantFiguresCheck)` - Counts and violation flags aligned with the input
+/// * `Err(CalculationError)` - If a result is not finite or `min_sig_figs` is 0
+pub fn check_significant_figures(
+    results: ArrayView1<f64>,
+    min_sig_figs: usize,
+) -> Result<SignificantFiguresCheck, CalculationError> {
+    validate_floats(results, "participant results")?;
+
+    if min_sig_figs == 0 {
+        return Err(CalculationError::InvalidInput {
+            message: "Minimum number of significant figures must be at least 1".to_string(),
+        });
+    }
+
+    let counts: Vec<usize> = results.iter().map(|&x_i| significant_figures(x_i)).collect();
+    let violations = results.iter()
+        .zip(counts.iter())
+        .map(|(&x_i, &count)| x_i != 0.0 && count < min_sig_figs)
+        .collect();
+
+    Ok(SignificantFiguresCheck { counts, violations })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::array;
+
+    #[test]
+    fn test_significant_figures_table() {
+        let cases = [
+            (12.3, 3),
+            (-12.3, 3),
+            (0.00123, 3),
+            (1.230, 3),
+            (10.05, 4),
+            (100.0, 1),
+            (1200.0, 2),
+            (1205.0, 4),
+            (7.0, 1),
+            (0.5, 1),
+            (1.23e-7, 3),
+            (4.56e20, 3),
+            (6.0e22, 1),
+            (0.1 + 0.2, 17),
+            (0.0, 0),
+            (-0.0, 0),
+        ];
+
+        for (value, expected) in cases {
+            assert_eq!(significant_figures(value), expected, "value {}", value);
+        }
+    }
+
+    #[test]
+    fn test_check_significant_figures() {
+        let results = array![12.3, 12.0, 0.0, 1.05e-3, 150.0, 2.5];
+        let check = check_significant_figures(results.view(), 3).unwrap();
+
+        assert_eq!(check.counts, vec![3, 2, 0, 3, 2, 2]);
+        assert_eq!(check.violations, vec![false, true, false, false, true, true]);
+    }
+
+    #[test]
+    fn test_check_significant_figures_invalid() {
+        assert!(check_significant_figures(array![1.0, f64::NAN].view(), 3).is_err());
+        assert!(check_significant_figures(array![1.0].view(), 0).is_err());
+    }
+}
@@ -118,9 +118,74 @@ def test_scoring():
     
     assert len(z_prime_simple) == len(results), "z_prime_simple length should match results length"
     
+    # float32 output is cast from the f64 scores
+    z_scores_f32 = pt_cli_rust.py_calculate_z_scores(results, x_pt, sigma_pt, dtype="float32")
+    assert z_scores_f32.dtype == np.float32, z_scores_f32.dtype
+    assert np.allclose(z_scores_f32, z_scores, rtol=1e-6)
+    z_prime_f32, mask = pt_cli_rust.py_calculate_z_prime_scores(
+        results, uncertainties, x_pt, u_x_pt, return_mask=True, dtype="float32"
+    )
+    assert z_prime_f32.dtype == np.float32 and not mask.any()
+    try:
+        pt_cli_rust.py_calculate_z_scores(results, x_pt, sigma_pt, dtype="float16")
+        assert False, "Should have raised an error for an unsupported dtype"
+    except ValueError:
+        print("  ✓ Unsupported dtype rejected")
+    
     print("  ✓ Scoring test passed")
 
 
+def test_scoring_into():
+    """Test in-place scoring into preallocated arrays."""
+    print("Testing in-place scoring...")
+    results = np.array([9.8, 10.0, 10.2, 9.9, 10.1])
+    uncertainties = np.array([0.05, 0.06, 0.04, 0.07, 0.05])
+    x_pt = 10.0
+    u_x_pt = 0.05
+    sigma_pt = 0.15
+    
+    out = np.empty(len(results), dtype=np.float64)
+    assert pt_cli_rust.py_calculate_z_scores_into(results, x_pt, sigma_pt, out) is None
+    expected = pt_cli_rust.py_calculate_z_scores(results, x_pt, sigma_pt)
+    assert np.array_equal(out, expected), "in-place z-scores should equal allocating API"
+    
+    pt_cli_rust.py_calculate_z_prime_scores_into(results, uncertainties, x_pt, u_x_pt, out)
+    expected = pt_cli_rust.py_calculate_z_prime_scores(results, uncertainties, x_pt, u_x_pt)
+    assert np.array_equal(out, expected), "in-place zeta-scores should equal allocating API"
+    
+    # Wrong length
+    try:
+        pt_cli_rust.py_calculate_z_scores_into(results, x_pt, sigma_pt, np.empty(3))
+        assert False, "Should have raised an error for wrong output length"
+    except ValueError:
+        print("  ✓ Wrong output length rejected")
+    
+    # Wrong dtype
+    try:
+        pt_cli_rust.py_calculate_z_scores_into(results, x_pt, sigma_pt, np.empty(5, dtype=np.float32))
+        assert False, "Should have raised an error for wrong output dtype"
+    except TypeError:
+        print("  ✓ Wrong output dtype rejected")
+    
+    # Read-only output
+    readonly = np.empty(5)
+    readonly.flags.writeable = False
+    try:
+        pt_cli_rust.py_calculate_z_scores_into(results, x_pt, sigma_pt, readonly)
+        assert False, "Should have raised an error for read-only output"
+    except ValueError:
+        print("  ✓ Read-only output rejected")
+    
+    # Non-contiguous output
+    try:
+        pt_cli_rust.py_calculate_z_scores_into(results, x_pt, sigma_pt, np.empty(10)[::2])
+        assert False, "Should have raised an error for non-contiguous output"
+    except ValueError:
+        print("  ✓ Non-contiguous output rejected")
+    
+    print("  ✓ In-place scoring test passed")
+
+
 def test_error_handling():
     """Test error handling."""
     print("Testing error handling...")
@@ -148,10 +213,52 @@ def test_error_handling():
         assert False, "Should have raised an error for zero sigma_pt"
     except ValueError:
         print("  ✓ Invalid sigma_pt error caught correctly")
-    
+
+    # Test mismatched array lengths name both arrays
+    try:
+        results = np.array([9.8, 10.0, 10.2])
+        uncertainties = np.array([0.05, 0.05])
+        pt_cli_rust.py_calculate_z_prime_scores(results, uncertainties, 10.0, 0.03)
+        assert False, "Should have raised an error for mismatched lengths"
+    except ValueError as e:
+        message = str(e)
+        assert "results has 3 elements" in message, message
+        assert "uncertainties has 2" in message, message
+        print("  ✓ Dimension mismatch error names the arrays")
+
     print("  ✓ Error handling test passed")
 
 
+def test_calculation_warnings():
+    """Test that non-fatal Algorithm A warnings reach Python."""
+    print("Testing calculation warnings...")
+    import warnings
+    
+    identical = np.array([5.0, 5.0, 5.0, 5.0, 5.0])
+    *_, found = pt_cli_rust.py_calculate_algorithm_a(identical, return_warnings=True)
+    codes = [code for code, _ in found]
+    assert "scale_floored" in codes, codes
+    assert "heavy_ties" in codes, codes
+    
+    heavy_tailed = np.array([9.97, 9.98, 9.99, 10.01, 10.02, 10.03, 8.99, 9.01, 10.99, 11.01])
+    consensus = pt_cli_rust.py_robust_consensus(heavy_tailed)
+    codes = [code for code, _ in consensus["warnings"]]
+    assert "many_down_weighted" in codes, codes
+    
+    data = np.array([10.0 + ((i * 37) % 50) / 25.0 for i in range(50)] + [20.0, 25.0])
+    _, _, _, iterations = pt_cli_rust.py_calculate_algorithm_a(data, 1e-12)
+    with warnings.catch_warnings(record=True) as caught:
+        warnings.simplefilter("always")
+        pt_cli_rust.py_calculate_algorithm_a(data, 1e-12, iterations + 1, emit_warnings=True)
+    messages = [str(w.message) for w in caught if issubclass(w.category, UserWarning)]
+    assert any(m.startswith("slow_convergence:") for m in messages), messages
+    
+    # Default return shape is unchanged
+    assert len(pt_cli_rust.py_calculate_algorithm_a(identical)) == 4
+    
+    print("  ✓ Calculation warnings test passed")
+
+
 def main():
     """Run all tests."""
     print("Running PT-CLI Rust Engine Integration Tests")
@@ -168,8 +275,12 @@ def main():
         print()
         test_scoring()
         print()
+        test_scoring_into()
+        print()
         test_error_handling()
         print()
+        test_calculation_warnings()
+        print()
         
         print("=" * 50)
         print("✓ All tests passed successfully!")